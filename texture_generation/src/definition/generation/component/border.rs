@@ -23,6 +23,11 @@ pub enum BorderDefinition {
         border: u32,
         component: ComponentDefinition,
     },
+    ShrinkAxisByFraction {
+        is_horizontal: bool,
+        fraction: f32,
+        component: ComponentDefinition,
+    },
 }
 
 impl BorderDefinition {
@@ -63,6 +68,21 @@ impl BorderDefinition {
                     .context(format!("Failed to create '{}.ShrinkAxis'", parent))?;
                 Ok(BorderComponent::ShrinkAxis(border))
             }
+            BorderDefinition::ShrinkAxisByFraction {
+                is_horizontal,
+                fraction,
+                component,
+            } => {
+                let component = component.convert(
+                    &format!("{}.ShrinkAxisByFraction.component", parent),
+                    factor,
+                )?;
+                let border =
+                    ShrinkAxis::new_fraction(*is_horizontal, *fraction, component).context(
+                        format!("Failed to create '{}.ShrinkAxisByFraction'", parent),
+                    )?;
+                Ok(BorderComponent::ShrinkAxis(border))
+            }
         }
     }
 }
@@ -109,4 +129,17 @@ mod tests {
 
         assert_eq!(component, definition.convert("test", 2.0).unwrap())
     }
+
+    #[test]
+    fn test_shrink_axis_by_fraction() {
+        let definition = BorderDefinition::ShrinkAxisByFraction {
+            is_horizontal: true,
+            fraction: 0.8,
+            component: ComponentDefinition::Mock(42),
+        };
+        let shrink = ShrinkAxis::new_fraction(true, 0.8, Component::Mock(42));
+        let component = BorderComponent::ShrinkAxis(shrink.unwrap());
+
+        assert_eq!(component, definition.convert("test", 2.0).unwrap())
+    }
 }
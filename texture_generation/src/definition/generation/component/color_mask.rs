@@ -0,0 +1,86 @@
+use crate::definition::generation::component::ComponentDefinition;
+use crate::generation::component::color_mask::ColorMaskComponent;
+use crate::generation::io::load_color_image;
+use crate::math::color::Color;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The default of the largest difference of a color channel between the mask & a color of the mapping.
+const DEFAULT_TOLERANCE: u8 = 16;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ColorMaskDefinition {
+    path: String,
+    tolerance: Option<u8>,
+    mapping: Vec<(String, ComponentDefinition)>,
+}
+
+impl ColorMaskDefinition {
+    pub fn new(
+        path: String,
+        tolerance: Option<u8>,
+        mapping: Vec<(String, ComponentDefinition)>,
+    ) -> Self {
+        ColorMaskDefinition {
+            path,
+            tolerance,
+            mapping,
+        }
+    }
+
+    pub fn convert(&self, parent: &str, factor: f32) -> Result<ColorMaskComponent> {
+        let mut mapping = Vec::with_capacity(self.mapping.len());
+
+        for (i, (color, definition)) in self.mapping.iter().enumerate() {
+            let color = Color::convert(color).context(format!(
+                "Failed to convert the {}.color of '{}.ColorMask'",
+                i + 1,
+                parent
+            ))?;
+            let component = definition.convert(
+                &format!("{}.ColorMask.{}|{}.", parent, i + 1, self.mapping.len()),
+                factor,
+            )?;
+            mapping.push((color, component));
+        }
+
+        let (size, mask) = load_color_image(Path::new(&self.path))
+            .context(format!("Failed to load 'path' of '{}.ColorMask'", parent))?;
+
+        ColorMaskComponent::new(
+            size,
+            &mask,
+            mapping,
+            self.tolerance.unwrap_or(DEFAULT_TOLERANCE),
+        )
+        .context(format!("Failed to convert '{}.ColorMask'", parent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_with_invalid_color() {
+        let definition = ColorMaskDefinition::new(
+            "mask.png".to_string(),
+            None,
+            vec![("not a color".to_string(), ComponentDefinition::Mock(1))],
+        );
+
+        assert!(definition.convert("test", 1.0).is_err())
+    }
+
+    #[test]
+    fn test_convert_missing_mask() {
+        let definition = ColorMaskDefinition::new(
+            "missing_mask.png".to_string(),
+            None,
+            vec![("#FF0000".to_string(), ComponentDefinition::Mock(1))],
+        );
+
+        assert!(definition.convert("test", 1.0).is_err())
+    }
+}
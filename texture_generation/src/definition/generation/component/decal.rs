@@ -0,0 +1,60 @@
+use crate::definition::convert;
+use crate::definition::generation::TextureDefinition;
+use crate::generation::component::decal::DecalComponent;
+use crate::math::point::Point;
+use crate::utils::resource::ResourceDefinition;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecalDefinition {
+    texture: TextureDefinition,
+    position: Point,
+    size: u32,
+}
+
+impl DecalDefinition {
+    pub fn new(texture: TextureDefinition, position: Point, size: u32) -> Self {
+        DecalDefinition {
+            texture,
+            position,
+            size,
+        }
+    }
+
+    pub fn convert(&self, parent: &str, factor: f32) -> Result<DecalComponent> {
+        let name = format!("{}.Decal", parent);
+        let generator = self
+            .texture
+            .convert(&name, convert(self.size, factor))
+            .context(format!("Failed to convert 'texture' of '{}'", name))?;
+        let position = Point::new(
+            (self.position.x as f32 * factor) as i32,
+            (self.position.y as f32 * factor) as i32,
+        );
+
+        Ok(DecalComponent::new(generator, position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::definition::generation::component::ComponentDefinition;
+    use crate::generation::component::Component;
+    use crate::generation::TextureGenerator;
+    use crate::math::color::RED;
+    use crate::math::size::Size;
+
+    #[test]
+    fn test_convert() {
+        let texture =
+            TextureDefinition::new(10, "#FF0000".to_string(), ComponentDefinition::Mock(3));
+        let definition = DecalDefinition::new(texture, Point::new(2, -3), 5);
+        let generator =
+            TextureGenerator::new("test.Decal", Size::square(15), RED, Component::Mock(3));
+        let component = DecalComponent::new(generator, Point::new(6, -9));
+
+        assert_eq!(component, definition.convert("test", 3.0).unwrap())
+    }
+}
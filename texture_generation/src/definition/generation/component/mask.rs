@@ -0,0 +1,45 @@
+use crate::definition::generation::component::ComponentDefinition;
+use crate::definition::math::shape_factor::ShapeFactorDefinition;
+use crate::generation::component::mask::MaskComponent;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MaskDefinition {
+    shape: ShapeFactorDefinition,
+    component: ComponentDefinition,
+}
+
+impl MaskDefinition {
+    pub fn new(shape: ShapeFactorDefinition, component: ComponentDefinition) -> Self {
+        MaskDefinition { shape, component }
+    }
+
+    pub fn convert(&self, parent: &str, factor: f32) -> Result<MaskComponent> {
+        let shape_factory = self
+            .shape
+            .convert()
+            .context(format!("Failed to convert 'shape' of '{}.Mask'", parent))?;
+        let component = self
+            .component
+            .convert(&format!("{}.Mask.component", parent), factor)?;
+
+        Ok(MaskComponent::new(shape_factory, component))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::component::Component;
+    use crate::math::shape_factory::ShapeFactory;
+
+    #[test]
+    fn test_convert() {
+        let definition =
+            MaskDefinition::new(ShapeFactorDefinition::Circle, ComponentDefinition::Mock(42));
+        let component = MaskComponent::new(ShapeFactory::Circle, Component::Mock(42));
+
+        assert_eq!(component, definition.convert("test", 2.0).unwrap())
+    }
+}
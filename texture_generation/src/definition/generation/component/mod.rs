@@ -1,22 +1,47 @@
 use crate::definition::generation::component::border::BorderDefinition;
+use crate::definition::generation::component::color_mask::ColorMaskDefinition;
+use crate::definition::generation::component::decal::DecalDefinition;
+use crate::definition::generation::component::mask::MaskDefinition;
+use crate::definition::generation::component::outline::OutlineDefinition;
 use crate::definition::generation::component::rendering::RenderingDefinition;
+use crate::generation::component::blend::BlendMode;
+use crate::generation::component::heightmap::HeightmapComponent;
 use crate::generation::component::Component;
-use anyhow::Result;
+use crate::generation::io::load_grayscale_image;
+use anyhow::{bail, Context, Result};
 use layout::LayoutDefinition;
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 pub mod border;
+pub mod color_mask;
+pub mod decal;
 pub mod layout;
+pub mod mask;
+pub mod outline;
+pub mod reference;
 pub mod rendering;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ComponentDefinition {
     Border(Box<BorderDefinition>),
+    /// Selects a component for each pixel based on the color of a mask image.
+    ColorMask(Box<ColorMaskDefinition>),
+    Decal(Box<DecalDefinition>),
     Empty,
-    Layers(Vec<ComponentDefinition>),
+    /// Uses a grayscale image as depth.
+    Heightmap {
+        path: String,
+    },
+    Layers(Vec<LayerDefinition>),
     Layout(Box<LayoutDefinition>),
+    Mask(Box<MaskDefinition>),
     Mock(u8),
+    Outline(Box<OutlineDefinition>),
+    /// The component of another texture definition, which is replaced by [`reference::resolve_references`].
+    Reference(String),
     Rendering(Box<RenderingDefinition>),
+    SelectByGlobalId(Vec<ComponentDefinition>),
 }
 
 impl ComponentDefinition {
@@ -25,30 +50,94 @@ impl ComponentDefinition {
             ComponentDefinition::Border(definition) => Ok(Component::Border(Box::new(
                 definition.convert(parent, factor)?,
             ))),
+            ComponentDefinition::ColorMask(definition) => Ok(Component::ColorMask(Box::new(
+                definition.convert(parent, factor)?,
+            ))),
+            ComponentDefinition::Decal(definition) => Ok(Component::Decal(Box::new(
+                definition.convert(parent, factor)?,
+            ))),
             ComponentDefinition::Empty => Ok(Component::Empty),
+            ComponentDefinition::Heightmap { path } => {
+                let (size, depth) = load_grayscale_image(Path::new(path))
+                    .context(format!("Failed to load 'path' of '{}.Heightmap'", parent))?;
+                let component = HeightmapComponent::new(size, depth)
+                    .context(format!("Failed to convert '{}.Heightmap'", parent))?;
+                Ok(Component::Heightmap(Box::new(component)))
+            }
             ComponentDefinition::Layers(layers) => {
                 let mut converted_layers = Vec::with_capacity(layers.len());
 
-                for (i, definition) in layers.iter().enumerate() {
+                for (i, layer) in layers.iter().enumerate() {
+                    let (mode, definition) = match layer {
+                        LayerDefinition::Blended {
+                            blend_mode,
+                            component,
+                        } => (*blend_mode, component),
+                        LayerDefinition::Plain(component) => (BlendMode::Depth, component),
+                    };
                     let component = definition.convert(
                         &format!("{}.Layers.{}|{}.", parent, i + 1, layers.len()),
                         factor,
                     )?;
-                    converted_layers.push(component);
+                    converted_layers.push((mode, component));
                 }
                 Ok(Component::Layers(converted_layers))
             }
             ComponentDefinition::Layout(definition) => Ok(Component::Layout(Box::new(
                 definition.convert(parent, factor)?,
             ))),
+            ComponentDefinition::Mask(definition) => Ok(Component::Mask(Box::new(
+                definition.convert(parent, factor)?,
+            ))),
             ComponentDefinition::Mock(id) => Ok(Component::Mock(*id)),
+            ComponentDefinition::Outline(definition) => Ok(Component::Outline(Box::new(
+                definition.convert(parent, factor)?,
+            ))),
+            ComponentDefinition::Reference(name) => {
+                bail!("'{}.Reference' to '{}' wasn't resolved", parent, name)
+            }
             ComponentDefinition::Rendering(definition) => Ok(Component::Rendering(Box::new(
                 definition.convert(parent, factor)?,
             ))),
+            ComponentDefinition::SelectByGlobalId(definitions) => {
+                if definitions.is_empty() {
+                    bail!(
+                        "'{}.SelectByGlobalId' requires at least 1 component",
+                        parent
+                    );
+                }
+
+                let mut components = Vec::with_capacity(definitions.len());
+
+                for (i, definition) in definitions.iter().enumerate() {
+                    let component = definition.convert(
+                        &format!(
+                            "{}.SelectByGlobalId.{}|{}.",
+                            parent,
+                            i + 1,
+                            definitions.len()
+                        ),
+                        factor,
+                    )?;
+                    components.push(component);
+                }
+                Ok(Component::SelectByGlobalId(components))
+            }
         }
     }
 }
 
+/// A layer of [`ComponentDefinition::Layers`]. A plain [`ComponentDefinition`] uses [`BlendMode::Depth`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LayerDefinition {
+    Blended {
+        blend_mode: BlendMode,
+        component: ComponentDefinition,
+    },
+    Plain(ComponentDefinition),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,11 +145,59 @@ mod tests {
     #[test]
     fn test_convert_layers() {
         let definition = ComponentDefinition::Layers(vec![
+            LayerDefinition::Plain(ComponentDefinition::Mock(1)),
+            LayerDefinition::Blended {
+                blend_mode: BlendMode::Multiply,
+                component: ComponentDefinition::Mock(2),
+            },
+        ]);
+        let component = Component::Layers(vec![
+            (BlendMode::Depth, Component::Mock(1)),
+            (BlendMode::Multiply, Component::Mock(2)),
+        ]);
+
+        assert_eq!(component, definition.convert("test", 2.0).unwrap())
+    }
+
+    #[test]
+    fn test_convert_missing_heightmap() {
+        let definition = ComponentDefinition::Heightmap {
+            path: "missing_heightmap.png".to_string(),
+        };
+
+        assert!(definition.convert("test", 1.0).is_err())
+    }
+
+    #[test]
+    fn test_convert_select_by_global_id() {
+        let definition = ComponentDefinition::SelectByGlobalId(vec![
             ComponentDefinition::Mock(1),
             ComponentDefinition::Mock(2),
         ]);
-        let component = Component::Layers(vec![Component::Mock(1), Component::Mock(2)]);
+        let component = Component::SelectByGlobalId(vec![Component::Mock(1), Component::Mock(2)]);
 
         assert_eq!(component, definition.convert("test", 2.0).unwrap())
     }
+
+    #[test]
+    fn test_convert_select_by_global_id_without_components() {
+        let definition = ComponentDefinition::SelectByGlobalId(Vec::new());
+
+        assert!(definition.convert("test", 2.0).is_err())
+    }
+
+    #[test]
+    fn test_parse_plain_and_blended_layers() {
+        let yaml = "Layers:\n  - Mock: 1\n  - blend_mode: Add\n    component:\n      Mock: 2\n";
+        let definition: ComponentDefinition = serde_yaml::from_str(yaml).unwrap();
+        let expected = ComponentDefinition::Layers(vec![
+            LayerDefinition::Plain(ComponentDefinition::Mock(1)),
+            LayerDefinition::Blended {
+                blend_mode: BlendMode::Add,
+                component: ComponentDefinition::Mock(2),
+            },
+        ]);
+
+        assert_eq!(definition, expected)
+    }
 }
@@ -0,0 +1,70 @@
+use crate::definition::convert;
+use crate::definition::generation::component::ComponentDefinition;
+use crate::generation::component::outline::OutlineComponent;
+use crate::math::color::Color;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OutlineDefinition {
+    thickness: u32,
+    color: String,
+    component: ComponentDefinition,
+}
+
+impl OutlineDefinition {
+    pub fn new(thickness: u32, color: String, component: ComponentDefinition) -> Self {
+        OutlineDefinition {
+            thickness,
+            color,
+            component,
+        }
+    }
+
+    pub fn convert(&self, parent: &str, factor: f32) -> Result<OutlineComponent> {
+        let color = Color::convert(&self.color)
+            .context(format!("Failed to convert 'color' of '{}.Outline'", parent))?;
+        let component = self
+            .component
+            .convert(&format!("{}.Outline.component", parent), factor)?;
+
+        Ok(OutlineComponent::new(
+            convert(self.thickness, factor),
+            color,
+            component,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::component::Component;
+    use crate::math::color::BLUE;
+
+    #[test]
+    fn test_convert() {
+        let definition =
+            OutlineDefinition::new(2, "#0000FF".to_string(), ComponentDefinition::Mock(42));
+        let component = OutlineComponent::new(4, BLUE, Component::Mock(42));
+
+        assert_eq!(component, definition.convert("test", 2.0).unwrap())
+    }
+
+    #[test]
+    fn test_convert_with_named_color() {
+        let definition =
+            OutlineDefinition::new(2, "blue".to_string(), ComponentDefinition::Mock(42));
+        let component = OutlineComponent::new(4, BLUE, Component::Mock(42));
+
+        assert_eq!(component, definition.convert("test", 2.0).unwrap())
+    }
+
+    #[test]
+    fn test_convert_with_invalid_color() {
+        let definition =
+            OutlineDefinition::new(2, "not-a-color".to_string(), ComponentDefinition::Mock(42));
+
+        assert!(definition.convert("test", 2.0).is_err())
+    }
+}
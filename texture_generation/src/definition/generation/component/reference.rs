@@ -0,0 +1,240 @@
+use crate::definition::generation::component::ComponentDefinition;
+use crate::definition::generation::palette::apply_palette;
+use crate::definition::generation::TextureDefinition;
+use crate::definition::read;
+use anyhow::{bail, Context, Result};
+use serde_yaml::{Mapping, Value};
+use std::path::{Path, PathBuf};
+
+const REFERENCE: &str = "Reference";
+
+/// Replaces each [`ComponentDefinition::Reference`] with the component of the referenced [`TextureDefinition`],
+/// which is read from the directory. The palette of the referenced definition is applied to its component first,
+/// so only its undefined colors use the palette of the parent. Returns an error for cyclic references.
+pub fn resolve_references(
+    definition: &ComponentDefinition,
+    dir: &Path,
+) -> Result<ComponentDefinition> {
+    let value = serde_yaml::to_value(definition)?;
+    let resolved = resolve_value(value, dir, &mut Vec::new())?;
+    Ok(serde_yaml::from_value(resolved)?)
+}
+
+/// Resolves the references nested anywhere inside the value.
+/// `visited` contains the canonical paths of the references, which are currently resolved.
+fn resolve_value(value: Value, dir: &Path, visited: &mut Vec<PathBuf>) -> Result<Value> {
+    match value {
+        Value::Mapping(mapping) => {
+            if let Some(name) = get_reference(&mapping) {
+                return resolve_reference(name, dir, visited);
+            }
+
+            let mut resolved = Mapping::new();
+
+            for (key, value) in mapping {
+                resolved.insert(key, resolve_value(value, dir, visited)?);
+            }
+
+            Ok(Value::Mapping(resolved))
+        }
+        Value::Sequence(values) => values
+            .into_iter()
+            .map(|value| resolve_value(value, dir, visited))
+            .collect::<Result<Vec<Value>>>()
+            .map(Value::Sequence),
+        value => Ok(value),
+    }
+}
+
+fn get_reference(mapping: &Mapping) -> Option<String> {
+    if mapping.len() != 1 {
+        return None;
+    }
+
+    match mapping.get(&Value::String(REFERENCE.to_string())) {
+        Some(Value::String(name)) => Some(name.clone()),
+        _ => None,
+    }
+}
+
+fn resolve_reference(name: String, dir: &Path, visited: &mut Vec<PathBuf>) -> Result<Value> {
+    let path = dir
+        .join(&name)
+        .canonicalize()
+        .context(format!("Failed to find the reference '{}'", name))?;
+
+    if visited.contains(&path) {
+        let cycle: Vec<String> = visited
+            .iter()
+            .chain(std::iter::once(&path))
+            .map(|path| path.display().to_string())
+            .collect();
+        bail!(
+            "The reference to '{}' is cyclic: {}",
+            name,
+            cycle.join(" -> ")
+        );
+    }
+
+    let texture: TextureDefinition =
+        read(&path).context(format!("Failed to read the reference '{}'", name))?;
+    let component = apply_palette(&texture.component, &texture.palette).context(format!(
+        "Failed to apply the palette of the reference '{}'",
+        name
+    ))?;
+    let value = serde_yaml::to_value(&component)?;
+
+    visited.push(path);
+    let resolved = resolve_value(value, dir, visited);
+    visited.pop();
+
+    resolved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::definition::generation::component::rendering::RenderingDefinition;
+    use crate::definition::generation::component::LayerDefinition;
+    use crate::generation::component::blend::BlendMode;
+    use crate::generation::component::rendering::RenderingComponent;
+    use crate::generation::component::Component;
+    use crate::generation::TextureGenerator;
+    use crate::math::color::{Color, BLUE, RED, WHITE};
+    use crate::math::size::Size;
+    use crate::utils::resource::ResourceDefinition;
+    use std::collections::HashMap;
+    use std::fs;
+
+    /// Creates an empty directory, which is unique for the test & the process.
+    fn create_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("texture_generator_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_texture(dir: &Path, name: &str, component: ComponentDefinition) {
+        let texture = TextureDefinition::new(100, "#FFFFFF".to_string(), component);
+        texture.write(dir.join(name).to_str().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_reference() {
+        let dir = create_dir("reference");
+        write_texture(&dir, "b.yaml", ComponentDefinition::Mock(2));
+
+        let a = ComponentDefinition::Layers(vec![
+            LayerDefinition::Plain(ComponentDefinition::Mock(1)),
+            LayerDefinition::Plain(ComponentDefinition::Reference("b.yaml".to_string())),
+        ]);
+        let resolved = resolve_references(&a, &dir).unwrap();
+
+        assert_eq!(
+            resolved.convert("test", 1.0).unwrap(),
+            Component::Layers(vec![
+                (BlendMode::Depth, Component::Mock(1)),
+                (BlendMode::Depth, Component::Mock(2)),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_reference_uses_its_own_palette() {
+        let dir = create_dir("reference_palette");
+        let mut child = TextureDefinition::new(100, "#FFFFFF".to_string(), fill_wall(2));
+        child.set_palette(create_palette("#0000FF"));
+        child
+            .write(dir.join("child.yaml").to_str().unwrap())
+            .unwrap();
+
+        let component = ComponentDefinition::Layers(vec![
+            LayerDefinition::Plain(fill_wall(1)),
+            LayerDefinition::Plain(ComponentDefinition::Reference("child.yaml".to_string())),
+        ]);
+        let mut parent = TextureDefinition::new(100, "#FFFFFF".to_string(), component);
+        parent.set_palette(create_palette("#FF0000"));
+
+        let component = Component::Layers(vec![
+            (BlendMode::Depth, fill_area(RED, 1)),
+            (BlendMode::Depth, fill_area(BLUE, 2)),
+        ]);
+        let generator = TextureGenerator::new("test", Size::square(100), WHITE, component);
+
+        assert_eq!(
+            generator,
+            parent
+                .resolve_references(&dir)
+                .unwrap()
+                .convert("test", 100)
+                .unwrap()
+        );
+    }
+
+    fn fill_wall(depth: u8) -> ComponentDefinition {
+        ComponentDefinition::Rendering(Box::new(RenderingDefinition::FillArea {
+            color: "$wall".to_string(),
+            depth,
+        }))
+    }
+
+    fn fill_area(color: Color, depth: u8) -> Component {
+        Component::Rendering(Box::new(RenderingComponent::new_fill_area(color, depth)))
+    }
+
+    fn create_palette(wall: &str) -> HashMap<String, String> {
+        let mut palette = HashMap::new();
+        palette.insert("wall".to_string(), wall.to_string());
+        palette
+    }
+
+    #[test]
+    fn test_cyclic_reference() {
+        let dir = create_dir("cyclic_reference");
+        write_texture(
+            &dir,
+            "a.yaml",
+            ComponentDefinition::Reference("b.yaml".to_string()),
+        );
+        write_texture(
+            &dir,
+            "b.yaml",
+            ComponentDefinition::Reference("a.yaml".to_string()),
+        );
+
+        let a = ComponentDefinition::Reference("a.yaml".to_string());
+
+        assert!(resolve_references(&a, &dir).is_err());
+    }
+
+    #[test]
+    fn test_cyclic_reference_with_different_paths() {
+        let dir = create_dir("cyclic_reference_paths");
+        write_texture(
+            &dir,
+            "a.yaml",
+            ComponentDefinition::Reference("./a.yaml".to_string()),
+        );
+
+        let a = ComponentDefinition::Reference("a.yaml".to_string());
+        let error = resolve_references(&a, &dir).unwrap_err();
+
+        assert!(error.to_string().contains("cyclic"));
+    }
+
+    #[test]
+    fn test_missing_reference() {
+        let dir = create_dir("missing_reference");
+        let a = ComponentDefinition::Reference("a.yaml".to_string());
+
+        assert!(resolve_references(&a, &dir).is_err());
+    }
+
+    #[test]
+    fn test_convert_unresolved_reference() {
+        let definition = ComponentDefinition::Reference("b.yaml".to_string());
+
+        assert!(definition.convert("test", 1.0).is_err());
+    }
+}
@@ -3,7 +3,9 @@ use crate::definition::generation::component::rendering::color::wood::WoodRingDe
 use crate::generation::component::rendering::color::factory::ColorFactory;
 use crate::generation::component::rendering::color::wood::WoodFactory;
 use crate::generation::random::Random;
+use crate::math::axis::Axis;
 use crate::math::color::Color;
+use crate::math::noise::{FractalNoise, NoiseFunction};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
@@ -18,15 +20,32 @@ pub enum ColorFactoryDefinition {
     Noise {
         color0: String,
         color1: String,
+        noise_function: Option<NoiseFunction>,
+        octaves: Option<u32>,
+        persistence: Option<f32>,
+        lacunarity: Option<f32>,
         base_factor: Option<f32>,
         scale_x: u32,
         scale_y: u32,
+        /// Repeats the noise seamlessly. The default is false.
+        tileable: Option<bool>,
     },
     NoiseWithRandomColors {
         colors: Vec<(usize, String)>,
+        noise_function: Option<NoiseFunction>,
+        octaves: Option<u32>,
+        persistence: Option<f32>,
+        lacunarity: Option<f32>,
         base_factor: Option<f32>,
         scale_x: u32,
         scale_y: u32,
+        /// Repeats the noise seamlessly. The default is false.
+        tileable: Option<bool>,
+    },
+    Voronoi {
+        colors: Vec<String>,
+        density: u32,
+        seed: u64,
     },
     WoodRings {
         early_wood: WoodRingDefinition,
@@ -41,6 +60,20 @@ pub enum ColorFactoryDefinition {
         noise_amplitude: f32,
         noise_scale: u32,
     },
+    Jitter {
+        base: Box<ColorFactoryDefinition>,
+        hue: f32,
+        value: f32,
+    },
+    Gradient {
+        color0: String,
+        color1: String,
+        axis: Axis,
+    },
+    Mirror {
+        base: Box<ColorFactoryDefinition>,
+        axis: Axis,
+    },
 }
 
 impl ColorFactoryDefinition {
@@ -64,38 +97,61 @@ impl ColorFactoryDefinition {
             ColorFactoryDefinition::Noise {
                 color0,
                 color1,
+                noise_function,
+                octaves,
+                persistence,
+                lacunarity,
                 base_factor,
                 scale_x,
                 scale_y,
+                tileable,
             } => {
                 let color0 = Color::convert(&color0)
                     .context("Failed to convert 'color0' of 'ColorFactory.Noise'")?;
                 let color1 = Color::convert(&color1)
                     .context("Failed to convert 'color1' of 'ColorFactory.Noise'")?;
+                let fractal = convert_fractal(*noise_function, *octaves, *persistence, *lacunarity)
+                    .context("Failed to convert 'ColorFactory.Noise'")?;
 
                 Ok(ColorFactory::Noise {
                     color0,
                     color1,
+                    fractal,
                     base_factor: base_factor.unwrap_or_default(),
                     scale_x: convert(*scale_x, factor) as f64,
                     scale_y: convert(*scale_y, factor) as f64,
+                    tileable: tileable.unwrap_or_default(),
                 })
             }
             ColorFactoryDefinition::NoiseWithRandomColors {
                 colors,
+                noise_function,
+                octaves,
+                persistence,
+                lacunarity,
                 base_factor,
                 scale_x,
                 scale_y,
+                tileable,
             } => {
                 let converted_colors = convert_probability(colors, "NoiseWithRandomColors")?;
+                let fractal = convert_fractal(*noise_function, *octaves, *persistence, *lacunarity)
+                    .context("Failed to convert 'ColorFactory.NoiseWithRandomColors'")?;
                 ColorFactory::new_noise(
                     Random::Hash,
                     converted_colors,
+                    fractal,
                     base_factor.unwrap_or_default(),
                     convert(*scale_x, factor),
                     convert(*scale_y, factor),
+                    tileable.unwrap_or_default(),
                 )
             }
+            ColorFactoryDefinition::Voronoi {
+                colors,
+                density,
+                seed,
+            } => ColorFactory::new_voronoi(convert_colors(colors, "Voronoi")?, *density, *seed),
             ColorFactoryDefinition::WoodRings {
                 early_wood,
                 late_wood,
@@ -146,6 +202,40 @@ impl ColorFactoryDefinition {
                     )))
                 }
             }
+            ColorFactoryDefinition::Jitter { base, hue, value } => {
+                let base = base
+                    .convert(factor)
+                    .context("Failed to convert 'base' of 'ColorFactory.Jitter'")?;
+
+                ColorFactory::new_jitter(base, *hue, *value, Random::Hash)
+                    .context("Failed to convert 'ColorFactory.Jitter'")
+            }
+            ColorFactoryDefinition::Gradient {
+                color0,
+                color1,
+                axis,
+            } => {
+                let color0 = Color::convert(color0)
+                    .context("Failed to convert 'color0' of 'ColorFactory.Gradient'")?;
+                let color1 = Color::convert(color1)
+                    .context("Failed to convert 'color1' of 'ColorFactory.Gradient'")?;
+
+                Ok(ColorFactory::Gradient {
+                    color0,
+                    color1,
+                    axis: *axis,
+                })
+            }
+            ColorFactoryDefinition::Mirror { base, axis } => {
+                let base = base
+                    .convert(factor)
+                    .context("Failed to convert 'base' of 'ColorFactory.Mirror'")?;
+
+                Ok(ColorFactory::Mirror {
+                    base: Box::new(base),
+                    axis: *axis,
+                })
+            }
         }
     }
 }
@@ -180,10 +270,24 @@ fn convert_probability(colors: &[(usize, String)], parent: &str) -> Result<Vec<(
     Ok(converted_colors)
 }
 
+fn convert_fractal(
+    noise_function: Option<NoiseFunction>,
+    octaves: Option<u32>,
+    persistence: Option<f32>,
+    lacunarity: Option<f32>,
+) -> Result<FractalNoise> {
+    FractalNoise::new(
+        noise_function.unwrap_or(NoiseFunction::Simplex),
+        octaves.unwrap_or(1),
+        persistence.unwrap_or(0.5),
+        lacunarity.unwrap_or(2.0),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::generation::random::Random;
+    use crate::generation::random::{Random, WeightedChoice};
     use crate::math::color::{ORANGE, PINK};
 
     #[test]
@@ -220,28 +324,47 @@ mod tests {
         ]);
         let factory = ColorFactory::Probability {
             random: Random::Hash,
-            colors: vec![(10, ORANGE), (15, PINK)],
-            max_number: 15,
+            choice: WeightedChoice::new(&[10, 5]).unwrap(),
+            colors: vec![ORANGE, PINK],
         };
 
         assert_eq!(factory, definition.convert(4.0).unwrap())
     }
 
+    #[test]
+    fn test_convert_voronoi() {
+        let definition = ColorFactoryDefinition::Voronoi {
+            colors: vec!["#FFA500".to_string(), "#FF0080".to_string()],
+            density: 8,
+            seed: 99,
+        };
+        let factory = ColorFactory::new_voronoi(vec![ORANGE, PINK], 8, 99).unwrap();
+
+        assert_eq!(factory, definition.convert(3.0).unwrap())
+    }
+
     #[test]
     fn test_convert_noise() {
         let definition = ColorFactoryDefinition::Noise {
             color0: "#FFA500".to_string(),
             color1: "#FF0080".to_string(),
+            noise_function: Some(NoiseFunction::Perlin),
+            octaves: Some(4),
+            persistence: Some(0.6),
+            lacunarity: Some(1.8),
             base_factor: Some(0.4),
             scale_x: 100,
             scale_y: 120,
+            tileable: Some(true),
         };
         let factory = ColorFactory::Noise {
             color0: ORANGE,
             color1: PINK,
+            fractal: FractalNoise::new(NoiseFunction::Perlin, 4, 0.6, 1.8).unwrap(),
             base_factor: 0.4,
             scale_x: 500.0,
             scale_y: 600.0,
+            tileable: true,
         };
 
         assert_eq!(factory, definition.convert(5.0).unwrap())
@@ -251,19 +374,73 @@ mod tests {
     fn test_convert_noise_with_random_colors() {
         let definition = ColorFactoryDefinition::NoiseWithRandomColors {
             colors: vec![(10, "#FFA500".to_string()), (5, "#FF0080".to_string())],
+            noise_function: None,
+            octaves: None,
+            persistence: None,
+            lacunarity: None,
             base_factor: None,
             scale_x: 100,
             scale_y: 120,
+            tileable: None,
         };
         let factory = ColorFactory::NoiseWithRandomColors {
             random: Random::Hash,
-            colors: vec![(10, ORANGE), (15, PINK)],
+            choice: WeightedChoice::new(&[10, 5]).unwrap(),
+            colors: vec![ORANGE, PINK],
+            fractal: FractalNoise::new(NoiseFunction::Simplex, 1, 0.5, 2.0).unwrap(),
             base_factor: 0.0,
-            max_number: 15,
             scale_x: 600.0,
             scale_y: 720.0,
+            tileable: false,
         };
 
         assert_eq!(factory, definition.convert(6.0).unwrap())
     }
+
+    #[test]
+    fn test_convert_gradient() {
+        let definition = ColorFactoryDefinition::Gradient {
+            color0: "#FFA500".to_string(),
+            color1: "#FF0080".to_string(),
+            axis: Axis::X,
+        };
+        let factory = ColorFactory::Gradient {
+            color0: ORANGE,
+            color1: PINK,
+            axis: Axis::X,
+        };
+
+        assert_eq!(factory, definition.convert(2.0).unwrap())
+    }
+
+    #[test]
+    fn test_convert_mirror() {
+        let definition = ColorFactoryDefinition::Mirror {
+            base: Box::new(ColorFactoryDefinition::ConstantColor("#FFA500".to_string())),
+            axis: Axis::Y,
+        };
+        let factory = ColorFactory::Mirror {
+            base: Box::new(ColorFactory::ConstantColor(ORANGE)),
+            axis: Axis::Y,
+        };
+
+        assert_eq!(factory, definition.convert(2.0).unwrap())
+    }
+
+    #[test]
+    fn test_convert_jitter() {
+        let definition = ColorFactoryDefinition::Jitter {
+            base: Box::new(ColorFactoryDefinition::ConstantColor("#FFA500".to_string())),
+            hue: 10.0,
+            value: 0.2,
+        };
+        let factory = ColorFactory::Jitter {
+            base: Box::new(ColorFactory::ConstantColor(ORANGE)),
+            hue: 10.0,
+            value: 0.2,
+            random: Random::Hash,
+        };
+
+        assert_eq!(factory, definition.convert(2.0).unwrap())
+    }
 }
@@ -1,3 +1,4 @@
+use crate::generation::component::rendering::depth::CombineOp;
 use crate::generation::component::rendering::depth_factory::DepthFactory;
 use crate::generation::random::Random;
 use anyhow::Result;
@@ -11,6 +12,11 @@ pub enum DepthDefinition {
         border: u8,
     },
     InterpolateMany(Vec<(f32, u8)>),
+    Combine {
+        op: CombineOp,
+        a: Box<DepthDefinition>,
+        b: Box<DepthDefinition>,
+    },
     Cylinder {
         is_horizontal: bool,
         center_depth: u8,
@@ -24,6 +30,10 @@ pub enum DepthDefinition {
         start: u8,
         end: u8,
     },
+    RandomUniform {
+        min: u8,
+        max: u8,
+    },
 }
 
 impl DepthDefinition {
@@ -36,6 +46,9 @@ impl DepthDefinition {
             DepthDefinition::InterpolateMany(data) => {
                 DepthFactory::new_interpolate_many(data.clone())
             }
+            DepthDefinition::Combine { op, a, b } => {
+                Ok(DepthFactory::new_combine(*op, a.convert()?, b.convert()?))
+            }
             DepthDefinition::Cylinder {
                 is_horizontal,
                 center_depth,
@@ -53,6 +66,9 @@ impl DepthDefinition {
                 start: *start,
                 end: *end,
             }),
+            DepthDefinition::RandomUniform { min, max } => {
+                DepthFactory::new_random_uniform(*min, *max, Random::Hash)
+            }
         }
     }
 }
@@ -94,4 +110,46 @@ mod tests {
             DepthFactory::new_dome(100, 200)
         );
     }
+
+    #[test]
+    fn test_convert_combine() {
+        assert_eq!(
+            DepthDefinition::Combine {
+                op: CombineOp::Add,
+                a: Box::new(DepthDefinition::Dome {
+                    center: 100,
+                    border: 200,
+                }),
+                b: Box::new(DepthDefinition::Uniform(42)),
+            }
+            .convert()
+            .unwrap(),
+            DepthFactory::new_combine(
+                CombineOp::Add,
+                DepthFactory::new_dome(100, 200),
+                DepthFactory::Uniform(42)
+            )
+        );
+    }
+
+    #[test]
+    fn test_convert_random_uniform() {
+        assert_eq!(
+            DepthDefinition::RandomUniform { min: 100, max: 200 }
+                .convert()
+                .unwrap(),
+            DepthFactory::RandomUniform {
+                min: 100,
+                max: 200,
+                random: Random::Hash,
+            }
+        );
+    }
+
+    #[test]
+    fn test_convert_invalid_random_uniform() {
+        assert!(DepthDefinition::RandomUniform { min: 200, max: 100 }
+            .convert()
+            .is_err());
+    }
 }
@@ -1,15 +1,20 @@
+use crate::definition::generation::component::reference::resolve_references;
 use crate::definition::generation::component::ComponentDefinition;
+use crate::definition::generation::palette::{apply_palette, resolve_color};
 use crate::generation::TextureGenerator;
 use crate::math::color::Color;
 use crate::math::size::Size;
 use crate::utils::error::ResourceError;
 use crate::utils::resource::ResourceDefinition;
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
+use std::path::Path;
 
 pub mod component;
+pub mod palette;
 pub mod process;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -17,6 +22,9 @@ pub struct TextureDefinition {
     size: u32,
     background: String,
     component: ComponentDefinition,
+    /// Named colors, which can be referenced like `$wall` instead of a color.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    palette: HashMap<String, String>,
 }
 
 impl TextureDefinition {
@@ -25,9 +33,30 @@ impl TextureDefinition {
             size,
             background,
             component,
+            palette: HashMap::new(),
         }
     }
 
+    /// Replaces the background color, which is validated during conversion.
+    pub fn set_background(&mut self, background: String) {
+        self.background = background;
+    }
+
+    /// Replaces the palette, which is applied during conversion.
+    pub fn set_palette(&mut self, palette: HashMap<String, String>) {
+        self.palette = palette;
+    }
+
+    /// Resolves all references to other texture definitions in the directory.
+    pub fn resolve_references(&self, dir: &Path) -> Result<TextureDefinition> {
+        Ok(TextureDefinition {
+            size: self.size,
+            background: self.background.clone(),
+            component: resolve_references(&self.component, dir)?,
+            palette: self.palette.clone(),
+        })
+    }
+
     pub fn write(&self, path: &str) -> Result<(), ResourceError> {
         let mut file = File::create(path)?;
 
@@ -43,18 +72,27 @@ impl ResourceDefinition for TextureDefinition {
     type R = TextureGenerator;
 
     fn convert(&self, name: &str, size: u32) -> Result<TextureGenerator> {
+        if Size::square(size).checked_len().is_none() {
+            bail!("The size {} of the texture '{}' is too big", size, name);
+        }
+
         let factor = size as f32 / self.size as f32;
-        let component = self
-            .component
+        let component = apply_palette(&self.component, &self.palette)
+            .context(format!(
+                "Failed to apply the palette to the texture '{}'",
+                name
+            ))?
             .convert(&"component", factor)
             .context(format!(
                 "Failed to convert 'component' of the texture '{}'",
                 name
             ))?;
-        let color = Color::convert(&self.background).context(format!(
-            "Failed to convert 'background' of the texture '{}'",
-            name
-        ))?;
+        let color = resolve_color(&self.background, &self.palette)
+            .and_then(|background| Color::convert(&background))
+            .context(format!(
+                "Failed to convert 'background' of the texture '{}'",
+                name
+            ))?;
 
         Ok(TextureGenerator::new(
             name,
@@ -68,8 +106,10 @@ impl ResourceDefinition for TextureDefinition {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::definition::generation::component::rendering::RenderingDefinition;
+    use crate::generation::component::rendering::RenderingComponent;
     use crate::generation::component::Component;
-    use crate::math::color::BLUE;
+    use crate::math::color::{BLUE, RED};
 
     #[test]
     fn test_convert_layout() {
@@ -79,4 +119,46 @@ mod tests {
 
         assert_eq!(generator, definition.convert("test", 200).unwrap());
     }
+
+    #[test]
+    fn test_convert_too_big_size() {
+        let definition =
+            TextureDefinition::new(100, "#0000FF".to_string(), ComponentDefinition::Mock(42));
+
+        assert!(definition.convert("test", 70000).is_err());
+    }
+
+    #[test]
+    fn test_convert_palette_color() {
+        let mut palette = HashMap::new();
+        palette.insert("wall".to_string(), "#FF0000".to_string());
+        palette.insert("background".to_string(), "#0000FF".to_string());
+        let mut definition = TextureDefinition::new(
+            100,
+            "$background".to_string(),
+            ComponentDefinition::Rendering(Box::new(RenderingDefinition::FillArea {
+                color: "$wall".to_string(),
+                depth: 42,
+            })),
+        );
+        definition.set_palette(palette);
+        let component = Component::Rendering(Box::new(RenderingComponent::new_fill_area(RED, 42)));
+        let generator = TextureGenerator::new("test", Size::square(100), BLUE, component);
+
+        assert_eq!(generator, definition.convert("test", 100).unwrap());
+    }
+
+    #[test]
+    fn test_convert_undefined_palette_color() {
+        let definition = TextureDefinition::new(
+            100,
+            "#0000FF".to_string(),
+            ComponentDefinition::Rendering(Box::new(RenderingDefinition::FillArea {
+                color: "$wall".to_string(),
+                depth: 42,
+            })),
+        );
+
+        assert!(definition.convert("test", 100).is_err());
+    }
 }
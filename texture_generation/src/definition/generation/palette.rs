@@ -0,0 +1,64 @@
+use crate::definition::generation::component::ComponentDefinition;
+use anyhow::{bail, Result};
+use serde_yaml::Value;
+use std::collections::HashMap;
+
+/// The prefix of a reference to a color of the palette.
+const PREFIX: char = '$';
+
+/// Replaces each reference like `$wall` with the color of the palette.
+pub fn apply_palette(
+    component: &ComponentDefinition,
+    palette: &HashMap<String, String>,
+) -> Result<ComponentDefinition> {
+    if palette.is_empty() {
+        return Ok(component.clone());
+    }
+
+    let value = serde_yaml::to_value(component)?;
+    let resolved = apply_to_value(value, palette)?;
+    Ok(serde_yaml::from_value(resolved)?)
+}
+
+/// Returns the color of the palette, if the text is a reference like `$wall`.
+///
+/// ```
+///# use std::collections::HashMap;
+///# use texture_generation::definition::generation::palette::resolve_color;
+/// let mut palette = HashMap::new();
+/// palette.insert("wall".to_string(), "#AAAAAA".to_string());
+///
+/// assert_eq!(resolve_color("$wall", &palette).unwrap(), "#AAAAAA");
+/// assert_eq!(resolve_color("#FF0000", &palette).unwrap(), "#FF0000");
+/// assert!(resolve_color("$floor", &palette).is_err());
+/// ```
+pub fn resolve_color(text: &str, palette: &HashMap<String, String>) -> Result<String> {
+    match text.strip_prefix(PREFIX) {
+        Some(name) => match palette.get(name) {
+            Some(color) => Ok(color.clone()),
+            None => bail!("The palette has no color '{}'", name),
+        },
+        None => Ok(text.to_string()),
+    }
+}
+
+fn apply_to_value(value: Value, palette: &HashMap<String, String>) -> Result<Value> {
+    match value {
+        Value::String(text) => Ok(Value::String(resolve_color(&text, palette)?)),
+        Value::Mapping(mapping) => {
+            let mut resolved = serde_yaml::Mapping::new();
+
+            for (key, value) in mapping {
+                resolved.insert(key, apply_to_value(value, palette)?);
+            }
+
+            Ok(Value::Mapping(resolved))
+        }
+        Value::Sequence(values) => values
+            .into_iter()
+            .map(|value| apply_to_value(value, palette))
+            .collect::<Result<Vec<Value>>>()
+            .map(Value::Sequence),
+        value => Ok(value),
+    }
+}
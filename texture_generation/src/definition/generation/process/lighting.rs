@@ -1,38 +1,176 @@
-use crate::generation::process::lighting::Lighting;
+use crate::generation::process::lighting::{Light, Lighting, SpecularParams};
+use crate::math::color::{Color, WHITE};
 use crate::math::vector3::Vector3;
+use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LightDefinition {
+    direction: Vector3,
+    color: Option<String>,
+    intensity: Option<f32>,
+}
+
+impl LightDefinition {
+    pub fn new(direction: Vector3, color: Option<String>, intensity: Option<f32>) -> Self {
+        LightDefinition {
+            direction,
+            color,
+            intensity,
+        }
+    }
+
+    pub fn convert(&self) -> Result<Light> {
+        let color = match &self.color {
+            None => WHITE,
+            Some(color) => Color::convert(color)?,
+        };
+
+        Ok(Light::new(
+            self.direction,
+            color,
+            self.intensity.unwrap_or(1.0),
+        ))
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SpecularDefinition {
+    shininess: f32,
+    intensity: f32,
+}
+
+impl SpecularDefinition {
+    pub fn new(shininess: f32, intensity: f32) -> Self {
+        SpecularDefinition {
+            shininess,
+            intensity,
+        }
+    }
+}
+
+impl From<SpecularDefinition> for SpecularParams {
+    fn from(definition: SpecularDefinition) -> Self {
+        SpecularParams::new(definition.shininess, definition.intensity)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct LightingDefinition {
-    light_direction: Vector3,
+    /// A single white light for backward compatibility.
+    light_direction: Option<Vector3>,
+    lights: Option<Vec<LightDefinition>>,
     normal_z: u32,
-    shininess: u32,
+    /// Is 0.1 by default.
+    ambient: Option<f32>,
+    /// There is no specular highlight by default.
+    specular: Option<SpecularDefinition>,
+    /// A specular highlight with intensity 1 for backward compatibility.
+    shininess: Option<u32>,
 }
 
-impl From<LightingDefinition> for Lighting {
-    fn from(definition: LightingDefinition) -> Self {
-        Lighting::new(
-            definition.light_direction,
-            definition.normal_z,
-            definition.shininess,
-        )
+impl LightingDefinition {
+    pub fn new(
+        light_direction: Option<Vector3>,
+        lights: Option<Vec<LightDefinition>>,
+        normal_z: u32,
+        ambient: Option<f32>,
+        specular: Option<SpecularDefinition>,
+    ) -> Self {
+        LightingDefinition {
+            light_direction,
+            lights,
+            normal_z,
+            ambient,
+            specular,
+            shininess: None,
+        }
+    }
+
+    pub fn convert(&self) -> Result<Lighting> {
+        let mut lights = Vec::new();
+
+        if let Some(direction) = self.light_direction {
+            lights.push(Light::white(direction));
+        }
+
+        if let Some(definitions) = &self.lights {
+            for (i, definition) in definitions.iter().enumerate() {
+                let light = definition
+                    .convert()
+                    .context(format!("Failed to convert {}.light of 'Lighting'", i + 1))?;
+                lights.push(light);
+            }
+        }
+
+        if lights.is_empty() {
+            bail!("'Lighting' requires at least one light");
+        }
+
+        let specular = self.specular.map(|specular| specular.into()).or_else(|| {
+            self.shininess
+                .map(|shininess| SpecularParams::new(shininess as f32, 1.0))
+        });
+
+        Ok(Lighting::new(
+            lights,
+            self.normal_z,
+            self.ambient.unwrap_or(0.1),
+            specular,
+        ))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::math::color::RED;
 
     #[test]
-    fn test_convert() {
+    fn test_convert_single_light() {
         let light_direction = Vector3::new(1.0, 0.0, 0.0);
-        let definition = LightingDefinition {
-            light_direction,
-            normal_z: 10,
-            shininess: 32,
-        };
-        let result = Lighting::new(light_direction, 10, 32);
+        let definition = LightingDefinition::new(Some(light_direction), None, 10, None, None);
+        let result = Lighting::new(vec![Light::white(light_direction)], 10, 0.1, None);
+
+        assert_eq!(result, definition.convert().unwrap())
+    }
+
+    #[test]
+    fn test_convert_many_lights() {
+        let direction0 = Vector3::new(1.0, 0.0, 1.0);
+        let direction1 = Vector3::new(-1.0, 0.0, 1.0);
+        let definition = LightingDefinition::new(
+            None,
+            Some(vec![
+                LightDefinition::new(direction0, None, None),
+                LightDefinition::new(direction1, Some("#FF0000".to_string()), Some(0.5)),
+            ]),
+            10,
+            Some(0.2),
+            Some(SpecularDefinition::new(16.0, 0.5)),
+        );
+        let lights = vec![Light::white(direction0), Light::new(direction1, RED, 0.5)];
+        let specular = SpecularParams::new(16.0, 0.5);
+        let result = Lighting::new(lights, 10, 0.2, Some(specular));
+
+        assert_eq!(result, definition.convert().unwrap())
+    }
+
+    #[test]
+    fn test_convert_without_lights() {
+        let definition = LightingDefinition::new(None, Some(Vec::new()), 10, None, None);
+
+        assert!(definition.convert().is_err())
+    }
+
+    #[test]
+    fn test_read_old_format() {
+        let string = "light_direction:\n  x: 1.0\n  y: 0.0\n  z: 2.0\nnormal_z: 10\nshininess: 32";
+        let definition: LightingDefinition = serde_yaml::from_str(string).unwrap();
+        let direction = Vector3::new(1.0, 0.0, 2.0);
+        let specular = SpecularParams::new(32.0, 1.0);
+        let result = Lighting::new(vec![Light::white(direction)], 10, 0.1, Some(specular));
 
-        assert_eq!(result, definition.into())
+        assert_eq!(result, definition.convert().unwrap())
     }
 }
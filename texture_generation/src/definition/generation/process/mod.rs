@@ -1,7 +1,10 @@
 use crate::definition::generation::process::lighting::LightingDefinition;
 use crate::generation::process::PostProcess;
+use crate::math::color::Color;
 use crate::utils::error::ResourceError;
+use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
 use std::fs;
 use std::path::Path;
 
@@ -9,8 +12,14 @@ pub mod lighting;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum PostProcessDefinition {
+    Emboss { angle: f32, strength: f32 },
+    Grain { amount: u8, seed: u64 },
     Lighting(LightingDefinition),
+    MakeTileable { blend: u32 },
     Mock(u8),
+    NormalizeDepth { target_min: u8, target_max: u8 },
+    Sharpen { amount: f32 },
+    Tint { color: String, factor: f32 },
 }
 
 impl PostProcessDefinition {
@@ -19,13 +28,232 @@ impl PostProcessDefinition {
         let data: Vec<PostProcessDefinition> = serde_yaml::from_str(&string)?;
         Ok(data)
     }
-}
 
-impl From<PostProcessDefinition> for PostProcess {
-    fn from(definition: PostProcessDefinition) -> Self {
-        match definition {
-            PostProcessDefinition::Lighting(definition) => PostProcess::Lighting(definition.into()),
-            PostProcessDefinition::Mock(id) => PostProcess::Mock(id),
+    pub fn convert(&self) -> Result<PostProcess> {
+        match self {
+            PostProcessDefinition::Emboss { angle, strength } => Ok(PostProcess::Emboss {
+                angle: *angle,
+                strength: *strength,
+            }),
+            PostProcessDefinition::Grain { amount, seed } => Ok(PostProcess::Grain {
+                amount: *amount,
+                seed: *seed,
+            }),
+            PostProcessDefinition::Lighting(definition) => Ok(PostProcess::Lighting(
+                definition
+                    .convert()
+                    .context("Failed to convert 'PostProcess.Lighting'")?,
+            )),
+            PostProcessDefinition::MakeTileable { blend } => {
+                Ok(PostProcess::MakeTileable { blend: *blend })
+            }
+            PostProcessDefinition::Mock(id) => Ok(PostProcess::Mock(*id)),
+            PostProcessDefinition::NormalizeDepth {
+                target_min,
+                target_max,
+            } => {
+                if target_min > target_max {
+                    bail!(
+                        "'target_min' of 'PostProcess.NormalizeDepth' is greater than 'target_max'"
+                    );
+                }
+
+                Ok(PostProcess::NormalizeDepth {
+                    target_min: *target_min,
+                    target_max: *target_max,
+                })
+            }
+            PostProcessDefinition::Sharpen { amount } => {
+                Ok(PostProcess::Sharpen { amount: *amount })
+            }
+            PostProcessDefinition::Tint { color, factor } => {
+                let color = Color::convert(color)
+                    .context("Failed to convert 'color' of 'PostProcess.Tint'")?;
+                Ok(PostProcess::Tint {
+                    color,
+                    factor: *factor,
+                })
+            }
         }
     }
 }
+
+/// Replaces the former `From` implementation, because some definitions are invalid.
+impl TryFrom<PostProcessDefinition> for PostProcess {
+    type Error = anyhow::Error;
+
+    fn try_from(definition: PostProcessDefinition) -> Result<Self> {
+        definition.convert()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::color::RED;
+
+    #[test]
+    fn test_convert_emboss() {
+        let definition = PostProcessDefinition::Emboss {
+            angle: 45.0,
+            strength: 2.0,
+        };
+        let post_process = PostProcess::Emboss {
+            angle: 45.0,
+            strength: 2.0,
+        };
+
+        assert_eq!(post_process, definition.convert().unwrap())
+    }
+
+    #[test]
+    fn test_round_trip_emboss() {
+        assert_round_trip(PostProcessDefinition::Emboss {
+            angle: 45.0,
+            strength: 2.0,
+        });
+    }
+
+    #[test]
+    fn test_convert_grain() {
+        let definition = PostProcessDefinition::Grain {
+            amount: 10,
+            seed: 42,
+        };
+        let post_process = PostProcess::Grain {
+            amount: 10,
+            seed: 42,
+        };
+
+        assert_eq!(post_process, definition.convert().unwrap())
+    }
+
+    #[test]
+    fn test_round_trip_grain() {
+        assert_round_trip(PostProcessDefinition::Grain {
+            amount: 10,
+            seed: 42,
+        });
+    }
+
+    #[test]
+    fn test_convert_make_tileable() {
+        let definition = PostProcessDefinition::MakeTileable { blend: 8 };
+        let post_process = PostProcess::MakeTileable { blend: 8 };
+
+        assert_eq!(post_process, definition.convert().unwrap())
+    }
+
+    #[test]
+    fn test_round_trip_make_tileable() {
+        assert_round_trip(PostProcessDefinition::MakeTileable { blend: 8 });
+    }
+
+    #[test]
+    fn test_convert_normalize_depth() {
+        let definition = PostProcessDefinition::NormalizeDepth {
+            target_min: 10,
+            target_max: 200,
+        };
+        let post_process = PostProcess::NormalizeDepth {
+            target_min: 10,
+            target_max: 200,
+        };
+
+        assert_eq!(post_process, definition.convert().unwrap())
+    }
+
+    #[test]
+    fn test_convert_normalize_depth_with_invalid_range() {
+        let definition = PostProcessDefinition::NormalizeDepth {
+            target_min: 200,
+            target_max: 10,
+        };
+
+        assert!(definition.convert().is_err())
+    }
+
+    #[test]
+    fn test_round_trip_normalize_depth() {
+        assert_round_trip(PostProcessDefinition::NormalizeDepth {
+            target_min: 10,
+            target_max: 200,
+        });
+    }
+
+    #[test]
+    fn test_convert_sharpen() {
+        let definition = PostProcessDefinition::Sharpen { amount: 0.5 };
+        let post_process = PostProcess::Sharpen { amount: 0.5 };
+
+        assert_eq!(post_process, definition.convert().unwrap())
+    }
+
+    #[test]
+    fn test_round_trip_sharpen() {
+        assert_round_trip(PostProcessDefinition::Sharpen { amount: 0.5 });
+    }
+
+    #[test]
+    fn test_convert_tint() {
+        let definition = PostProcessDefinition::Tint {
+            color: "#FF0000".to_string(),
+            factor: 0.5,
+        };
+        let post_process = PostProcess::Tint {
+            color: RED,
+            factor: 0.5,
+        };
+
+        assert_eq!(post_process, definition.convert().unwrap())
+    }
+
+    #[test]
+    fn test_convert_tint_with_named_color() {
+        let definition = PostProcessDefinition::Tint {
+            color: "red".to_string(),
+            factor: 0.5,
+        };
+        let post_process = PostProcess::Tint {
+            color: RED,
+            factor: 0.5,
+        };
+
+        assert_eq!(post_process, definition.convert().unwrap())
+    }
+
+    #[test]
+    fn test_try_from() {
+        let definition = PostProcessDefinition::Mock(42);
+
+        assert_eq!(
+            PostProcess::Mock(42),
+            PostProcess::try_from(definition).unwrap()
+        )
+    }
+
+    #[test]
+    fn test_convert_tint_with_invalid_color() {
+        let definition = PostProcessDefinition::Tint {
+            color: "not-a-color".to_string(),
+            factor: 0.5,
+        };
+
+        assert!(definition.convert().is_err())
+    }
+
+    #[test]
+    fn test_round_trip_tint() {
+        assert_round_trip(PostProcessDefinition::Tint {
+            color: "#FF0000".to_string(),
+            factor: 0.5,
+        });
+    }
+
+    fn assert_round_trip(definition: PostProcessDefinition) {
+        let string = serde_yaml::to_string(&definition).unwrap();
+        let result: PostProcessDefinition = serde_yaml::from_str(&string).unwrap();
+
+        assert_eq!(definition, result)
+    }
+}
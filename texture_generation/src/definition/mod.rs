@@ -6,8 +6,9 @@ use serde::Serialize;
 use std::collections::HashMap;
 use std::fs;
 use std::fs::{DirEntry, File};
-use std::io::{Error, Write};
+use std::io::{Error, Read, Write};
 use std::path::Path;
+use std::time::SystemTime;
 
 pub mod generation;
 pub mod math;
@@ -98,12 +99,99 @@ pub fn read_resources<T: DeserializeOwned>(
         .collect()
 }
 
+/// Reads resources like [`read_resources()`], but only reads a file again, if its modification time changed.
+pub struct ResourceReader<T> {
+    names: Vec<String>,
+    entries: HashMap<String, (SystemTime, T)>,
+}
+
+impl<T: DeserializeOwned + Clone> ResourceReader<T> {
+    /// Returns the resources & the names of the changed ones.
+    pub fn read(
+        &mut self,
+        dir: &Path,
+        names: &[String],
+    ) -> (Vec<Option<(String, T)>>, Vec<String>) {
+        self.read_with(dir, names, get_modification_time)
+    }
+
+    fn read_with<F: Fn(&Path) -> Option<SystemTime>>(
+        &mut self,
+        dir: &Path,
+        names: &[String],
+        get_time: F,
+    ) -> (Vec<Option<(String, T)>>, Vec<String>) {
+        let names_changed = self.names != names;
+        let mut resources = Vec::with_capacity(names.len());
+        let mut changed = Vec::new();
+
+        for name in names {
+            let path = dir.join(name);
+            let time = get_time(&path);
+
+            if let (Some(time), Some((cached_time, resource))) = (time, self.entries.get(name)) {
+                if time == *cached_time {
+                    if names_changed {
+                        changed.push(name.clone());
+                    }
+
+                    resources.push(Some((name.clone(), resource.clone())));
+                    continue;
+                }
+            }
+
+            changed.push(name.clone());
+
+            match (read::<T>(&path), time) {
+                (Ok(resource), Some(time)) => {
+                    self.entries.insert(name.clone(), (time, resource.clone()));
+                    resources.push(Some((name.clone(), resource)));
+                }
+                (Ok(resource), None) => {
+                    self.entries.remove(name);
+                    resources.push(Some((name.clone(), resource)));
+                }
+                (Err(error), _) => {
+                    warn!("Couldn't read '{}', because of {:?}", name, error);
+                    self.entries.remove(name);
+                    resources.push(None);
+                }
+            }
+        }
+
+        self.names = names.to_vec();
+
+        (resources, changed)
+    }
+}
+
+impl<T> Default for ResourceReader<T> {
+    fn default() -> Self {
+        ResourceReader {
+            names: Vec::new(),
+            entries: HashMap::new(),
+        }
+    }
+}
+
+fn get_modification_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
 pub fn read<T: DeserializeOwned>(path: &Path) -> Result<T> {
     let string = fs::read_to_string(path).context(format!("Failed to load {:?}", path))?;
     let data: T = serde_yaml::from_str(&string).context(format!("Failed to parse {:?}", path))?;
     Ok(data)
 }
 
+/// Reads a definition from a reader like stdin instead of a file.
+pub fn read_from_reader<T: DeserializeOwned, R: Read>(reader: R) -> Result<T> {
+    let data: T = serde_yaml::from_reader(reader).context("Failed to parse the definition")?;
+    Ok(data)
+}
+
 pub fn write<T: Serialize>(object: &T, path: &Path) -> Result<(), ResourceError> {
     let mut file = File::create(path)?;
 
@@ -113,3 +201,53 @@ pub fn write<T: Serialize>(object: &T, path: &Path) -> Result<(), ResourceError>
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_resource_reader_skips_unchanged_files() {
+        let dir = std::env::temp_dir().join("texture_generator_resource_reader");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.yaml"), "1").unwrap();
+        fs::write(dir.join("b.yaml"), "2").unwrap();
+
+        let names = vec!["a.yaml".to_string(), "b.yaml".to_string()];
+        let mut reader: ResourceReader<u32> = ResourceReader::default();
+        let old_time = SystemTime::UNIX_EPOCH;
+        let new_time = old_time + Duration::from_secs(10);
+
+        let (resources, changed) = reader.read_with(&dir, &names, |_| Some(old_time));
+
+        assert_eq!(
+            resources,
+            vec![to_entry("a.yaml", 1), to_entry("b.yaml", 2)]
+        );
+        assert_eq!(changed, names);
+
+        fs::write(dir.join("a.yaml"), "3").unwrap();
+        fs::write(dir.join("b.yaml"), "4").unwrap();
+
+        let (resources, changed) = reader.read_with(&dir, &names, |path| {
+            if path.ends_with("b.yaml") {
+                Some(new_time)
+            } else {
+                Some(old_time)
+            }
+        });
+
+        assert_eq!(
+            resources,
+            vec![to_entry("a.yaml", 1), to_entry("b.yaml", 4)]
+        );
+        assert_eq!(changed, vec!["b.yaml".to_string()]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn to_entry(name: &str, value: u32) -> Option<(String, u32)> {
+        Some((name.to_string(), value))
+    }
+}
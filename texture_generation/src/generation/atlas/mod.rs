@@ -0,0 +1,148 @@
+use crate::generation::data::texture::Texture;
+use crate::generation::TextureGenerator;
+use crate::math::color::BLACK;
+use crate::math::point::Point;
+use crate::math::size::Size;
+use crate::utils::resource::{Resource, ResourceManager};
+use anyhow::{bail, Result};
+
+pub mod packer;
+
+/// Arranges textures in a grid, e.g. to show all textures of a [`ResourceManager`] in one image.
+pub struct Atlas {
+    texture: Texture,
+    /// The name & the top left corner of each cell.
+    labels: Vec<(String, Point)>,
+}
+
+impl Atlas {
+    /// Copies each texture with [`Texture::blit()`] into its cell, which are filled row by row.
+    /// Only pixels with a depth above 0 are copied.
+    pub fn new(cells: &[(String, Texture)], cell_size: Size, columns: u32) -> Result<Atlas> {
+        if cells.is_empty() {
+            bail!("The atlas requires at least 1 texture");
+        } else if columns == 0 {
+            bail!("Argument 'columns' needs to be greater than 0");
+        }
+
+        let n = cells.len() as u32;
+        let used_columns = columns.min(n);
+        let rows = n.div_ceil(columns);
+        let size = Size::new(used_columns * cell_size.width(), rows * cell_size.height());
+        let mut texture = Texture::new(size, BLACK);
+        let mut labels = Vec::with_capacity(cells.len());
+
+        for (i, (name, cell)) in cells.iter().enumerate() {
+            let i = i as u32;
+            let start = Point::new(
+                ((i % columns) * cell_size.width()) as i32,
+                ((i / columns) * cell_size.height()) as i32,
+            );
+
+            texture.blit(cell, start);
+            labels.push((name.clone(), start));
+        }
+
+        Ok(Atlas { texture, labels })
+    }
+
+    /// Generates each [`TextureGenerator`] & arranges them like [`Atlas::new()`].
+    pub fn from_manager(
+        manager: &ResourceManager<TextureGenerator>,
+        cell_size: Size,
+        columns: u32,
+    ) -> Result<Atlas> {
+        let cells: Vec<(String, Texture)> = (0..manager.len())
+            .map(|id| {
+                let generator = manager.get(id);
+                info!("Add '{}' to the atlas", generator.get_name());
+                // The base depth ensures that the background is copied, too.
+                let texture = generator.generate_with_base_depth(1, false);
+                (generator.get_name().to_string(), texture)
+            })
+            .collect();
+
+        Atlas::new(&cells, cell_size, columns)
+    }
+
+    pub fn get_texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    pub fn get_labels(&self) -> &[(String, Point)] {
+        &self.labels
+    }
+
+    /// Returns a line with the position & name of each cell.
+    pub fn format_labels(&self) -> String {
+        self.labels
+            .iter()
+            .map(|(name, point)| format!("{} {} {}\n", point.x, point.y, name))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::component::rendering::RenderingComponent;
+    use crate::generation::component::Component;
+    use crate::math::color::{Color, BLUE, GREEN, RED};
+
+    #[test]
+    fn test_atlas_of_3_textures_with_2_columns() {
+        let manager = ResourceManager::new(
+            vec![
+                create_generator("red", RED),
+                create_generator("green", GREEN),
+                create_generator("blue", BLUE),
+            ],
+            TextureGenerator::default(),
+        );
+
+        let atlas = Atlas::from_manager(&manager, Size::square(2), 2).unwrap();
+
+        assert_eq!(atlas.get_texture().get_size(), &Size::new(4, 4));
+
+        #[rustfmt::skip]
+        let colors = vec![
+              RED,   RED, GREEN, GREEN,
+              RED,   RED, GREEN, GREEN,
+             BLUE,  BLUE, BLACK, BLACK,
+             BLUE,  BLUE, BLACK, BLACK,
+        ];
+
+        assert_eq!(atlas.get_texture().get_color_data(), &colors);
+        assert_eq!(atlas.format_labels(), "0 0 red\n2 0 green\n0 2 blue\n");
+    }
+
+    #[test]
+    fn test_less_textures_than_columns() {
+        let cells = vec![(
+            "a".to_string(),
+            Texture::with_depth(Size::square(2), RED, 1),
+        )];
+
+        let atlas = Atlas::new(&cells, Size::square(2), 4).unwrap();
+
+        assert_eq!(atlas.get_texture().get_size(), &Size::square(2));
+    }
+
+    #[test]
+    fn test_atlas_without_textures() {
+        assert!(Atlas::new(&[], Size::square(2), 2).is_err());
+    }
+
+    #[test]
+    fn test_atlas_without_columns() {
+        let cells = vec![("a".to_string(), Texture::new(Size::square(2), RED))];
+
+        assert!(Atlas::new(&cells, Size::square(2), 0).is_err());
+    }
+
+    fn create_generator(name: &str, color: Color) -> TextureGenerator {
+        let rendering = RenderingComponent::new_fill_area(color, 0);
+        let component = Component::Rendering(Box::new(rendering));
+        TextureGenerator::new(name, Size::square(2), color, component)
+    }
+}
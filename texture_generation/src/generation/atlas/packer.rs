@@ -0,0 +1,247 @@
+use crate::generation::data::texture::Texture;
+use crate::math::aabb::AABB;
+use crate::math::color::BLACK;
+use crate::math::point::Point;
+use crate::math::size::Size;
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+/// The area of a packed texture inside a [`PackedAtlas`].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PackedTexture {
+    name: String,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    /// The left, top, right & bottom edge in texture coordinates between 0 & 1.
+    uv: [f32; 4],
+}
+
+impl PackedTexture {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_aabb(&self) -> AABB {
+        AABB::new(
+            Point::new(self.x as i32, self.y as i32),
+            Size::new(self.width, self.height),
+        )
+    }
+
+    pub fn get_uv(&self) -> [f32; 4] {
+        self.uv
+    }
+}
+
+/// Packs textures of different sizes tightly into one texture, e.g. for the import into an engine.
+pub struct PackedAtlas {
+    texture: Texture,
+    /// The area of each texture in the same order as the input.
+    packed: Vec<PackedTexture>,
+}
+
+/// The description of a [`PackedAtlas`] in the JSON sidecar.
+#[derive(Serialize)]
+struct Sidecar<'a> {
+    width: u32,
+    height: u32,
+    textures: &'a [PackedTexture],
+}
+
+impl PackedAtlas {
+    /// Packs the textures with the shelf algorithm:
+    /// The textures are sorted by height & placed from left to right in rows,
+    /// which are as high as their first texture.
+    pub fn new(textures: &[(String, Texture)], width: u32) -> Result<PackedAtlas> {
+        if textures.is_empty() {
+            bail!("The atlas requires at least 1 texture");
+        } else if let Some((name, _)) = textures
+            .iter()
+            .find(|(_, texture)| texture.get_size().width() > width)
+        {
+            bail!("The texture '{}' is wider than the atlas", name);
+        }
+
+        let mut order: Vec<usize> = (0..textures.len()).collect();
+        order.sort_by_key(|i| std::cmp::Reverse(textures[*i].1.get_size().height()));
+
+        let mut starts = vec![Point::default(); textures.len()];
+        let mut x = 0;
+        let mut y = 0;
+        let mut shelf_height = 0;
+
+        for i in order {
+            let size = textures[i].1.get_size();
+
+            if x + size.width() > width {
+                x = 0;
+                y += shelf_height;
+                shelf_height = 0;
+            }
+
+            starts[i] = Point::new(x as i32, y as i32);
+            x += size.width();
+            shelf_height = shelf_height.max(size.height());
+        }
+
+        let size = Size::new(width, y + shelf_height);
+        let mut texture = Texture::new(size, BLACK);
+        let packed = textures
+            .iter()
+            .zip(starts)
+            .map(|((name, source), start)| {
+                copy(source, &mut texture, start);
+                create_packed(name, source.get_size(), start, size)
+            })
+            .collect();
+
+        Ok(PackedAtlas { texture, packed })
+    }
+
+    pub fn get_texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    pub fn get_packed(&self) -> &[PackedTexture] {
+        &self.packed
+    }
+
+    /// Describes the size of the atlas & the area of each texture as JSON.
+    pub fn to_json(&self) -> Result<String> {
+        let size = self.texture.get_size();
+        let sidecar = Sidecar {
+            width: size.width(),
+            height: size.height(),
+            textures: &self.packed,
+        };
+
+        Ok(serde_json::to_string_pretty(&sidecar)?)
+    }
+
+    /// Saves the JSON from [`PackedAtlas::to_json()`].
+    pub fn save_sidecar(&self, path: &Path) -> Result<()> {
+        info!("Save the atlas sidecar to {:?}", path);
+
+        fs::write(path, self.to_json()?).context(format!("Failed to save {:?}", path))
+    }
+}
+
+fn create_packed(name: &str, size: &Size, start: Point, atlas_size: Size) -> PackedTexture {
+    let x = start.x as u32;
+    let y = start.y as u32;
+    let atlas_width = atlas_size.width() as f32;
+    let atlas_height = atlas_size.height() as f32;
+
+    PackedTexture {
+        name: name.to_string(),
+        x,
+        y,
+        width: size.width(),
+        height: size.height(),
+        uv: [
+            x as f32 / atlas_width,
+            y as f32 / atlas_height,
+            (x + size.width()) as f32 / atlas_width,
+            (y + size.height()) as f32 / atlas_height,
+        ],
+    }
+}
+
+/// Copies the colors & depth of the source unchanged, unlike [`Texture::blit()`].
+fn copy(source: &Texture, target: &mut Texture, start: Point) {
+    let source_size = *source.get_size();
+    let target_size = *target.get_size();
+
+    for y in 0..source_size.height() {
+        for x in 0..source_size.width() {
+            let source_index = source_size.convert_x_y(x, y);
+            let target_index = target_size.convert_x_y(start.x as u32 + x, start.y as u32 + y);
+
+            target.get_color_data_mut()[target_index] = source.get_color_data()[source_index];
+            target.get_depth_data_mut()[target_index] = source.get_depth_data()[source_index];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::color::{BLUE, GREEN, RED};
+
+    #[test]
+    fn test_pack_3_textures() {
+        let textures = vec![
+            ("red".to_string(), Texture::new(Size::new(4, 2), RED)),
+            ("green".to_string(), Texture::new(Size::new(3, 3), GREEN)),
+            ("blue".to_string(), Texture::new(Size::new(2, 5), BLUE)),
+        ];
+
+        let atlas = PackedAtlas::new(&textures, 6).unwrap();
+        let size = *atlas.get_texture().get_size();
+        let packed = atlas.get_packed();
+
+        assert_eq!(size, Size::new(6, 7));
+        assert_eq!(packed.len(), 3);
+
+        for (i, a) in packed.iter().enumerate() {
+            assert!(size.is_aabb_inside(&a.get_aabb()));
+
+            for b in &packed[(i + 1)..] {
+                assert!(!a.get_aabb().overlaps(&b.get_aabb()));
+            }
+        }
+
+        for ((name, texture), packed) in textures.iter().zip(packed) {
+            let start = packed.get_aabb().start();
+
+            assert_eq!(packed.get_name(), name);
+            assert_eq!(packed.get_aabb().size(), *texture.get_size());
+            assert_eq!(
+                atlas.get_texture().get_color(&start),
+                texture.get_color(&Point::default())
+            );
+        }
+    }
+
+    #[test]
+    fn test_uv() {
+        let textures = vec![
+            ("a".to_string(), Texture::new(Size::new(2, 4), RED)),
+            ("b".to_string(), Texture::new(Size::new(2, 2), BLUE)),
+        ];
+
+        let atlas = PackedAtlas::new(&textures, 4).unwrap();
+
+        assert_eq!(atlas.get_packed()[0].get_uv(), [0.0, 0.0, 0.5, 1.0]);
+        assert_eq!(atlas.get_packed()[1].get_uv(), [0.5, 0.0, 1.0, 0.5]);
+    }
+
+    #[test]
+    fn test_json_sidecar() {
+        let textures = vec![("a".to_string(), Texture::new(Size::new(2, 4), RED))];
+
+        let atlas = PackedAtlas::new(&textures, 4).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&atlas.to_json().unwrap()).unwrap();
+
+        assert_eq!(json["width"], 4);
+        assert_eq!(json["height"], 4);
+        assert_eq!(json["textures"][0]["name"], "a");
+        assert_eq!(json["textures"][0]["width"], 2);
+    }
+
+    #[test]
+    fn test_texture_wider_than_atlas() {
+        let textures = vec![("a".to_string(), Texture::new(Size::new(5, 1), RED))];
+
+        assert!(PackedAtlas::new(&textures, 4).is_err());
+    }
+
+    #[test]
+    fn test_pack_without_textures() {
+        assert!(PackedAtlas::new(&[], 4).is_err());
+    }
+}
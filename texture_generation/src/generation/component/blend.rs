@@ -0,0 +1,108 @@
+use crate::generation::component::Component;
+use crate::generation::data::texture::Texture;
+use crate::generation::data::Data;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// Defines how a layer of [`Component::Layers`] is combined with the layers below it.
+pub enum BlendMode {
+    /// Only overwrites pixels with a lower depth. The default.
+    Depth,
+    /// Overwrites the color & depth of all pixels covered by the layer.
+    Normal,
+    /// Multiplies the color of the covered pixels with the layer's color & keeps their depth.
+    Multiply,
+    /// Adds the layer's color to the color of the covered pixels & keeps their depth.
+    Add,
+}
+
+impl BlendMode {
+    /// Generates the [`Component`] & combines it with the texture.
+    pub fn generate(&self, component: &Component, texture: &mut Texture, data: &Data) {
+        if *self == BlendMode::Depth {
+            return component.generate(texture, data);
+        }
+
+        component.generate_in_scratch(texture, data, |texture, _scratch, point, layer, depth| {
+            if depth == 0 {
+                return;
+            }
+
+            let index = match texture.get_index(point) {
+                Some(index) => index,
+                None => return,
+            };
+            let color = &mut texture.get_color_data_mut()[index];
+
+            match self {
+                BlendMode::Multiply => *color = color.multiply(layer),
+                BlendMode::Add => *color = color.add_clamped(layer),
+                _ => {
+                    *color = layer.blend(color);
+                    texture.get_depth_data_mut()[index] = depth;
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::component::rendering::RenderingComponent;
+    use crate::math::color::{Color, BLACK, BLUE, RED};
+    use crate::math::size::Size;
+
+    #[test]
+    fn test_depth() {
+        let texture = blend(BlendMode::Depth, Color::gray(128), 1);
+
+        assert_eq!(texture.get_color_data(), &vec![BASE; 2]);
+        assert_eq!(texture.get_depth_data(), &vec![5; 2]);
+    }
+
+    #[test]
+    fn test_normal() {
+        let texture = blend(BlendMode::Normal, BLUE, 1);
+
+        assert_eq!(texture.get_color_data(), &vec![BLUE; 2]);
+        assert_eq!(texture.get_depth_data(), &vec![1; 2]);
+    }
+
+    #[test]
+    fn test_multiply_darkens_base() {
+        let texture = blend(BlendMode::Multiply, Color::gray(128), 1);
+
+        assert_eq!(
+            texture.get_color_data(),
+            &vec![Color::from_rgb(100, 50, 25); 2]
+        );
+        assert_eq!(texture.get_depth_data(), &vec![5; 2]);
+    }
+
+    #[test]
+    fn test_add() {
+        let texture = blend(BlendMode::Add, RED, 1);
+
+        assert_eq!(
+            texture.get_color_data(),
+            &vec![Color::from_rgb(255, 100, 50); 2]
+        );
+        assert_eq!(texture.get_depth_data(), &vec![5; 2]);
+    }
+
+    const BASE: Color = Color::from_rgb(200, 100, 50);
+
+    fn blend(mode: BlendMode, color: Color, depth: u8) -> Texture {
+        let size = Size::new(2, 1);
+        let mut texture = Texture::new(size, BLACK);
+        let data = Data::for_texture(texture.get_aabb());
+        let base = RenderingComponent::new_fill_area(BASE, 5);
+        let layer = RenderingComponent::new_fill_area(color, depth);
+
+        BlendMode::Depth.generate(&Component::Rendering(Box::new(base)), &mut texture, &data);
+        mode.generate(&Component::Rendering(Box::new(layer)), &mut texture, &data);
+
+        texture
+    }
+}
@@ -44,6 +44,15 @@ impl BorderComponent {
         BorderComponent::UniformBorder { border, component }
     }
 
+    /// Returns true, if the inner [`Component`] is streamable.
+    pub fn is_streamable(&self) -> bool {
+        match self {
+            BorderComponent::MinBorder(component) => component.is_streamable(),
+            BorderComponent::UniformBorder { component, .. } => component.is_streamable(),
+            BorderComponent::ShrinkAxis(shrink) => shrink.is_streamable(),
+        }
+    }
+
     /// Generates the border in the area defined by the [`AABB`].
     pub fn generate(&self, texture: &mut Texture, data: &Data) {
         let aabbs = data.get_aabbs();
@@ -58,7 +67,7 @@ impl BorderComponent {
                 let min_side = border * 2;
 
                 if size.width() <= min_side || size.height() <= min_side {
-                    info!("{:?} smaller than {}", size, min_side);
+                    trace!("{:?} smaller than {}", size, min_side);
                     return;
                 }
 
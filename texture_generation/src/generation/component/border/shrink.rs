@@ -7,13 +7,21 @@ use crate::math::point::Point;
 use crate::math::size::Size;
 use anyhow::{bail, Result};
 
+/// How much a [`ShrinkAxis`] shrinks.
+#[derive(Clone, Debug, PartialEq)]
+enum ShrinkMode {
+    /// A fixed border on both sides, which is optionally randomized.
+    Border { min_border: u32, border_diff: u32 },
+    /// The inner length is a fraction of the original length.
+    Fraction(f32),
+}
+
 #[svgbobdoc::transform]
 /// Shrinks a [`Component`] along the x-axis or y-axis.
 #[derive(Clone, Debug, PartialEq)]
 pub struct ShrinkAxis {
     is_horizontal: bool,
-    min_border: u32,
-    border_diff: u32,
+    mode: ShrinkMode,
     component: Component,
     random: Random,
 }
@@ -30,8 +38,10 @@ impl ShrinkAxis {
 
         Ok(ShrinkAxis {
             is_horizontal,
-            min_border: desired_border,
-            border_diff: 0,
+            mode: ShrinkMode::Border {
+                min_border: desired_border,
+                border_diff: 0,
+            },
             component,
             random: Random::Hash,
         })
@@ -50,28 +60,57 @@ impl ShrinkAxis {
 
         Ok(ShrinkAxis {
             is_horizontal,
-            min_border,
-            border_diff: 1 + max_border - min_border,
+            mode: ShrinkMode::Border {
+                min_border,
+                border_diff: 1 + max_border - min_border,
+            },
             component,
             random,
         })
     }
 
+    /// Shrinks the [`Component`] to a fraction of the original length, while keeping it centered.
+    pub fn new_fraction(
+        is_horizontal: bool,
+        fraction: f32,
+        component: Component,
+    ) -> Result<ShrinkAxis> {
+        if fraction <= 0.0 || fraction >= 1.0 {
+            bail!("Argument 'fraction' needs to be between 0 and 1");
+        }
+
+        Ok(ShrinkAxis {
+            is_horizontal,
+            mode: ShrinkMode::Fraction(fraction),
+            component,
+            random: Random::Hash,
+        })
+    }
+
+    pub fn is_streamable(&self) -> bool {
+        self.component.is_streamable()
+    }
+
     /// Generates the pattern in all the repeating areas intersected by the [`AABB`].
     pub fn generate(&self, texture: &mut Texture, data: &Data) {
-        let border = self.calculate_random_border(&data);
         let old_start = data.get_aabbs().get_inner().start();
         let old_size = data.get_aabbs().get_inner().size();
+        let length = if self.is_horizontal {
+            old_size.width()
+        } else {
+            old_size.height()
+        };
+        let (offset, inner_length) = self.calculate_inner(data, length);
 
         let (start, size) = if self.is_horizontal {
             (
-                Point::new(old_start.x + border as i32, old_start.y),
-                Size::new(old_size.width() - border * 2, old_size.height()),
+                Point::new(old_start.x + offset as i32, old_start.y),
+                Size::new(inner_length, old_size.height()),
             )
         } else {
             (
-                Point::new(old_start.x, old_start.y + border as i32),
-                Size::new(old_size.width(), old_size.height() - border * 2),
+                Point::new(old_start.x, old_start.y + offset as i32),
+                Size::new(old_size.width(), inner_length),
             )
         };
 
@@ -79,15 +118,29 @@ impl ShrinkAxis {
         self.component.generate(texture, &data.transform(aabb));
     }
 
-    fn calculate_random_border(&self, data: &Data) -> u32 {
-        if self.border_diff == 0 {
-            return self.min_border;
+    /// Returns the offset & length of the inner area along the axis.
+    fn calculate_inner(&self, data: &Data, length: u32) -> (u32, u32) {
+        match self.mode {
+            ShrinkMode::Border {
+                min_border,
+                border_diff,
+            } => {
+                let border = self.calculate_random_border(data, min_border, border_diff);
+                (border, length - border * 2)
+            }
+            ShrinkMode::Fraction(fraction) => {
+                let inner_length = (length as f32 * fraction).round() as u32;
+                ((length - inner_length) / 2, inner_length)
+            }
+        }
+    }
+
+    fn calculate_random_border(&self, data: &Data, min_border: u32, border_diff: u32) -> u32 {
+        if border_diff == 0 {
+            return min_border;
         }
 
-        self.min_border
-            + self
-                .random
-                .get_random_instance_u32(&data, self.border_diff, 42)
+        min_border + self.random.get_random_instance_u32(data, border_diff, 42)
     }
 }
 
@@ -111,6 +164,12 @@ mod tests {
         ShrinkAxis::new_random(true, 3, 3, Component::Mock(42), Random::Hash).unwrap();
     }
 
+    #[test]
+    #[should_panic]
+    fn test_new_fraction_too_big() {
+        ShrinkAxis::new_fraction(true, 1.0, Component::Mock(42)).unwrap();
+    }
+
     #[test]
     fn test_shrink_x() {
         let size = Size::new(8, 2);
@@ -160,4 +219,25 @@ mod tests {
 
         assert_eq!(texture.get_color_data(), &expected_colors);
     }
+
+    #[test]
+    fn test_shrink_x_by_fraction() {
+        let size = Size::new(10, 2);
+        let aabb = AABB::with_size(size);
+        let mut texture = Texture::new(size, WHITE);
+
+        let renderer = RenderingComponent::new_fill_area(RED, 1);
+        let component = Component::Rendering(Box::new(renderer));
+        let layout = ShrinkAxis::new_fraction(true, 0.8, component).unwrap();
+
+        layout.generate(&mut texture, &Data::for_texture(aabb));
+
+        #[rustfmt::skip]
+        let expected_colors = vec![
+            WHITE, RED, RED, RED, RED, RED, RED, RED, RED, WHITE,
+            WHITE, RED, RED, RED, RED, RED, RED, RED, RED, WHITE,
+        ];
+
+        assert_eq!(texture.get_color_data(), &expected_colors);
+    }
 }
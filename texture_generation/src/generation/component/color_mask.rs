@@ -0,0 +1,156 @@
+use crate::generation::component::Component;
+use crate::generation::data::texture::Texture;
+use crate::generation::data::Data;
+use crate::math::color::Color;
+use crate::math::size::Size;
+use anyhow::{bail, Result};
+
+#[derive(Clone, Debug, PartialEq)]
+/// Uses a mask image, which is scaled to fit the [`AABB`](crate::math::aabb::AABB),
+/// to select a [`Component`] for each pixel.
+pub struct ColorMaskComponent {
+    size: Size,
+    /// The index of the selected [`Component`] for each pixel of the mask.
+    selection: Vec<Option<usize>>,
+    components: Vec<Component>,
+}
+
+impl ColorMaskComponent {
+    /// Selects the [`Component`] with the nearest [`Color`] for each pixel of the mask,
+    /// if the largest difference of their channels is within the tolerance.
+    pub fn new(
+        size: Size,
+        mask: &[Color],
+        mapping: Vec<(Color, Component)>,
+        tolerance: u8,
+    ) -> Result<ColorMaskComponent> {
+        if size.len() == 0 {
+            bail!("The mask is empty");
+        } else if size.len() != mask.len() {
+            bail!("The mask of size {:?} has {} colors", size, mask.len());
+        } else if mapping.is_empty() {
+            bail!("The mask requires at least 1 component");
+        }
+
+        let (colors, components): (Vec<Color>, Vec<Component>) = mapping.into_iter().unzip();
+        let selection = mask
+            .iter()
+            .map(|pixel| select(pixel, &colors, tolerance))
+            .collect();
+
+        Ok(ColorMaskComponent {
+            size,
+            selection,
+            components,
+        })
+    }
+
+    pub fn is_streamable(&self) -> bool {
+        self.components
+            .iter()
+            .all(|component| component.is_streamable())
+    }
+
+    /// Generates each [`Component`] & only keeps the pixels, where the mask selects it.
+    pub fn generate(&self, texture: &mut Texture, data: &Data) {
+        let aabbs = data.get_aabbs_in_texture_space();
+        let inner = *aabbs.get_inner();
+        let start = inner.start();
+        let size = inner.size();
+
+        for (i, component) in self.components.iter().enumerate() {
+            component.generate_in_scratch(
+                texture,
+                data,
+                |texture, _scratch, point, color, depth| {
+                    if depth == 0 || !inner.is_inside(point) {
+                        return;
+                    }
+
+                    let mask_x = (point.x - start.x) as u32 * self.size.width() / size.width();
+                    let mask_y = (point.y - start.y) as u32 * self.size.height() / size.height();
+
+                    if self.selection[self.size.convert_x_y(mask_x, mask_y)] == Some(i) {
+                        texture.set(point, color, depth);
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// Returns the index of the nearest [`Color`], if it is within the tolerance.
+fn select(pixel: &Color, colors: &[Color], tolerance: u8) -> Option<usize> {
+    colors
+        .iter()
+        .map(|color| calculate_difference(pixel, color))
+        .enumerate()
+        .filter(|(_i, difference)| *difference <= tolerance)
+        .min_by_key(|(_i, difference)| *difference)
+        .map(|(i, _difference)| i)
+}
+
+/// Returns the largest difference of the color channels.
+fn calculate_difference(a: &Color, b: &Color) -> u8 {
+    a.r()
+        .abs_diff(b.r())
+        .max(a.g().abs_diff(b.g()))
+        .max(a.b().abs_diff(b.b()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::component::rendering::RenderingComponent;
+    use crate::generation::io::load_color_image;
+    use crate::math::aabb::AABB;
+    use crate::math::color::{BLACK, BLUE, GREEN, RED, WHITE};
+
+    #[test]
+    fn test_split_by_mask() {
+        let path = std::env::temp_dir().join("color_mask_test.png");
+        #[rustfmt::skip]
+        let mask = [
+            255, 0, 0,   0, 0, 255,
+            255, 0, 0,   0, 0, 255,
+        ];
+        image::save_buffer(&path, &mask, 2, 2, image::ColorType::Rgb8).unwrap();
+        let (size, mask) = load_color_image(&path).unwrap();
+        let mapping = vec![(RED, create_fill(GREEN)), (BLUE, create_fill(WHITE))];
+        let component = ColorMaskComponent::new(size, &mask, mapping, 16).unwrap();
+        let mut texture = Texture::new(Size::square(4), BLACK);
+
+        component.generate(
+            &mut texture,
+            &Data::for_texture(AABB::with_size(Size::square(4))),
+        );
+
+        #[rustfmt::skip]
+        let colors = vec![
+            GREEN, GREEN, WHITE, WHITE,
+            GREEN, GREEN, WHITE, WHITE,
+            GREEN, GREEN, WHITE, WHITE,
+            GREEN, GREEN, WHITE, WHITE,
+        ];
+
+        assert_eq!(texture.get_color_data(), &colors);
+    }
+
+    #[test]
+    fn test_select_nearest_color_within_tolerance() {
+        let colors = [RED, BLUE];
+
+        assert_eq!(select(&Color::from_rgb(250, 10, 0), &colors, 16), Some(0));
+        assert_eq!(select(&Color::from_rgb(5, 0, 240), &colors, 16), Some(1));
+        assert_eq!(select(&Color::from_rgb(128, 0, 128), &colors, 16), None);
+    }
+
+    #[test]
+    fn test_new_without_mapping() {
+        assert!(ColorMaskComponent::new(Size::square(1), &[RED], Vec::new(), 0).is_err());
+    }
+
+    fn create_fill(color: Color) -> Component {
+        Component::Rendering(Box::new(RenderingComponent::new_fill_area(color, 1)))
+    }
+}
@@ -0,0 +1,97 @@
+use crate::generation::data::texture::Texture;
+use crate::generation::data::Data;
+use crate::generation::TextureGenerator;
+use crate::math::point::Point;
+
+#[derive(Clone, Debug, PartialEq)]
+/// Generates a small texture & stamps it onto the target texture.
+pub struct DecalComponent {
+    generator: TextureGenerator,
+    /// The position of the top left corner relative to the inner [`AABB`].
+    position: Point,
+}
+
+impl DecalComponent {
+    pub fn new(generator: TextureGenerator, position: Point) -> DecalComponent {
+        DecalComponent {
+            generator,
+            position,
+        }
+    }
+
+    /// Generates the decal & blits it into the texture, where it is higher than the existing pixels.
+    pub fn generate(&self, texture: &mut Texture, data: &Data) {
+        let decal = self
+            .generator
+            .generate_with_base_depth(texture.get_base_depth(), texture.is_dithering());
+        let aabbs = data.get_aabbs_in_texture_space();
+        let start = aabbs.get_inner().start() + self.position;
+
+        texture.blit(&decal, start);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::component::rendering::RenderingComponent;
+    use crate::generation::component::Component;
+    use crate::math::aabb::AABB;
+    use crate::math::color::{BLUE, RED};
+    use crate::math::size::Size;
+
+    #[test]
+    fn test_stamp_red_square() {
+        let mut texture = Texture::new(Size::square(5), BLUE);
+        let component = DecalComponent::new(create_red_square(2), Point::new(1, 2));
+        let data = Data::for_two_aabb(
+            0,
+            AABB::with_size(Size::square(5)),
+            AABB::new(Point::new(1, 0), Size::new(4, 5)),
+        );
+
+        component.generate(&mut texture, &data);
+
+        #[rustfmt::skip]
+        let colors = vec![
+            BLUE, BLUE, BLUE, BLUE, BLUE,
+            BLUE, BLUE, BLUE, BLUE, BLUE,
+            BLUE, BLUE,  RED,  RED, BLUE,
+            BLUE, BLUE,  RED,  RED, BLUE,
+            BLUE, BLUE, BLUE, BLUE, BLUE,
+        ];
+
+        assert_eq!(texture.get_color_data(), &colors);
+
+        #[rustfmt::skip]
+        let depth = vec![
+            0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+            0, 0, 7, 7, 0,
+            0, 0, 7, 7, 0,
+            0, 0, 0, 0, 0,
+        ];
+
+        assert_eq!(texture.get_depth_data(), &depth);
+    }
+
+    #[test]
+    fn test_stamp_behind_higher_pixels() {
+        let mut texture = Texture::new(Size::square(2), BLUE);
+        texture.set(&Point::new(1, 1), &BLUE, 10);
+        let component = DecalComponent::new(create_red_square(2), Point::new(0, 0));
+        let data = Data::for_texture(texture.get_aabb());
+
+        component.generate(&mut texture, &data);
+
+        assert_eq!(texture.get_color_data(), &vec![RED, RED, RED, BLUE]);
+        assert_eq!(texture.get_depth_data(), &vec![7, 7, 7, 10]);
+    }
+
+    fn create_red_square(size: u32) -> TextureGenerator {
+        let rendering = RenderingComponent::new_fill_area(RED, 7);
+        let component = Component::Rendering(Box::new(rendering));
+
+        TextureGenerator::new("decal", Size::square(size), BLUE, component)
+    }
+}
@@ -0,0 +1,105 @@
+use crate::generation::data::texture::Texture;
+use crate::generation::data::Data;
+use crate::math::point::Point;
+use crate::math::size::Size;
+use anyhow::{bail, Result};
+
+#[derive(Clone, Debug, PartialEq)]
+/// Writes a grayscale image into the depth buffer, which is scaled to fit the [`AABB`](crate::math::aabb::AABB).
+pub struct HeightmapComponent {
+    size: Size,
+    depth: Vec<u8>,
+}
+
+impl HeightmapComponent {
+    pub fn new(size: Size, depth: Vec<u8>) -> Result<HeightmapComponent> {
+        if size.len() == 0 {
+            bail!("The heightmap is empty");
+        } else if size.len() != depth.len() {
+            bail!(
+                "The heightmap of size {:?} has {} depth values",
+                size,
+                depth.len()
+            );
+        }
+
+        Ok(HeightmapComponent { size, depth })
+    }
+
+    /// Overwrites the depth inside the [`AABB`](crate::math::aabb::AABB) with the nearest pixel of the heightmap.
+    pub fn generate(&self, texture: &mut Texture, data: &Data) {
+        let aabbs = data.get_aabbs_in_texture_space();
+        let inner = *aabbs.get_inner();
+        let area = match inner.intersection(&texture.get_aabb()) {
+            Some(area) => area,
+            None => return,
+        };
+        let start = inner.start();
+        let size = inner.size();
+        let base_depth = texture.get_base_depth();
+
+        for y in area.start().y..area.end().y {
+            let source_y = (y - start.y) as u32 * self.size.height() / size.height();
+
+            for x in area.start().x..area.end().x {
+                let source_x = (x - start.x) as u32 * self.size.width() / size.width();
+                let depth = self.depth[self.size.convert_x_y(source_x, source_y)];
+
+                if let Some(index) = texture.get_index(&Point::new(x, y)) {
+                    texture.get_depth_data_mut()[index] = base_depth.saturating_add(depth);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::io::load_grayscale_image;
+    use crate::math::aabb::AABB;
+    use crate::math::color::WHITE;
+
+    #[test]
+    fn test_new_with_wrong_size() {
+        assert!(HeightmapComponent::new(Size::new(2, 2), vec![1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_generate_from_png() {
+        let path = std::env::temp_dir().join("heightmap_test.png");
+        image::save_buffer(&path, &[10, 20, 30, 40], 2, 2, image::ColorType::L8).unwrap();
+        let (size, depth) = load_grayscale_image(&path).unwrap();
+        let component = HeightmapComponent::new(size, depth).unwrap();
+        let mut texture = Texture::new(Size::square(5), WHITE);
+        let data = Data::for_two_aabb(
+            0,
+            AABB::with_size(Size::square(5)),
+            AABB::new(Point::new(1, 1), Size::square(4)),
+        );
+
+        component.generate(&mut texture, &data);
+
+        #[rustfmt::skip]
+        let depth = vec![
+            0,  0,  0,  0,  0,
+            0, 10, 10, 20, 20,
+            0, 10, 10, 20, 20,
+            0, 30, 30, 40, 40,
+            0, 30, 30, 40, 40,
+        ];
+
+        assert_eq!(texture.get_depth_data(), &depth);
+    }
+
+    #[test]
+    fn test_downscale_with_nearest_neighbor() {
+        let component = HeightmapComponent::new(Size::new(4, 1), vec![1, 2, 3, 4]).unwrap();
+        let mut texture = Texture::new(Size::new(2, 1), WHITE);
+        let data = Data::for_texture(AABB::with_size(Size::new(2, 1)));
+
+        component.generate(&mut texture, &data);
+
+        assert_eq!(texture.get_depth_data(), &[1, 3]);
+    }
+}
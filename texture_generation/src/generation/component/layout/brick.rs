@@ -102,9 +102,11 @@ impl BrickPattern {
 
     /// Calculates the number of columns & rows of bricks in the whole texture.
     fn calculate_total_bricks(&self, texture: &mut Texture) -> Size {
+        let size = texture.get_full_size();
+
         Size::new(
-            (texture.get_size().width() as f32 / self.brick.width() as f32).ceil() as u32,
-            (texture.get_size().height() as f32 / self.brick.height() as f32).ceil() as u32,
+            (size.width() as f32 / self.brick.width() as f32).ceil() as u32,
+            (size.height() as f32 / self.brick.height() as f32).ceil() as u32,
         )
     }
 
@@ -25,6 +25,16 @@ pub enum LayoutComponent {
 }
 
 impl LayoutComponent {
+    /// Returns true for layouts, which only depend on their [`AABB`], with streamable components.
+    pub fn is_streamable(&self) -> bool {
+        match self {
+            LayoutComponent::Mock(_) => true,
+            LayoutComponent::Repeat(repeat) => repeat.is_streamable(),
+            LayoutComponent::Split(split) => split.is_streamable(),
+            _ => false,
+        }
+    }
+
     /// Generates the component in the area defined by the [`AABB`].
     pub fn generate(&self, texture: &mut Texture, data: &Data) {
         let combined = data.combine();
@@ -1,3 +1,5 @@
+use crate::generation::component::layout::LayoutComponent;
+use crate::generation::component::rendering::distance::DistanceCache;
 use crate::generation::component::Component;
 use crate::generation::data::texture::Texture;
 use crate::generation::data::Data;
@@ -86,16 +88,25 @@ impl RepeatLayout {
         })
     }
 
+    pub fn is_streamable(&self) -> bool {
+        self.component.is_streamable()
+    }
+
     /// Generates the component in the area defined by the [`AABB`].
     pub fn generate(&self, texture: &mut Texture, data: Data) {
+        self.generate_cached(texture, data, &mut DistanceCache::default())
+    }
+
+    /// Generates like [`RepeatLayout::generate()`], but shares the cache with nested repeat layouts.
+    fn generate_cached(&self, texture: &mut Texture, data: Data, cache: &mut DistanceCache) {
         if self.is_horizontal {
-            self.inner_generate(texture, data.make_horizontal())
+            self.inner_generate(texture, data.make_horizontal(), cache)
         } else {
-            self.inner_generate(texture, data.make_vertical())
+            self.inner_generate(texture, data.make_vertical(), cache)
         }
     }
 
-    fn inner_generate(&self, texture: &mut Texture, mut data: Data) {
+    fn inner_generate(&self, texture: &mut Texture, mut data: Data, cache: &mut DistanceCache) {
         let inner = data.get_aabbs().get_inner();
         let height = inner.size().height();
         let mut point = inner.start();
@@ -103,8 +114,19 @@ impl RepeatLayout {
         for step in self.calculate_steps(&data, inner.size().width()) {
             let size = Size::new(step, height);
             let aabb = AABB::new(point, size);
-
-            self.component.generate(texture, &data.next(aabb));
+            let data = data.next(aabb);
+
+            // Most instances have the same size, so the distances of their shapes are shared.
+            match &self.component {
+                Component::Rendering(renderer) => renderer.render_cached(texture, &data, cache),
+                Component::Layout(layout) => match layout.as_ref() {
+                    LayoutComponent::Repeat(repeat) => {
+                        repeat.generate_cached(texture, data.combine(), cache)
+                    }
+                    _ => layout.generate(texture, &data),
+                },
+                _ => self.component.generate(texture, &data),
+            }
 
             point.x += step as i32;
         }
@@ -167,13 +189,14 @@ pub fn calculate_steps(distance: u32, desired_step: u32) -> Vec<u32> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::generation::component::blend::BlendMode;
     use crate::generation::component::layout::tests::create_component;
     use crate::generation::component::rendering::color::factory::ColorFactory;
     use crate::generation::component::rendering::depth_factory::DepthFactory;
     use crate::generation::component::rendering::RenderingComponent;
     use crate::generation::data::texture::Texture;
     use crate::math::color::{BLUE, GREEN, RED, WHITE};
-    use crate::math::shape_factory::ShapeFactory::Rectangle;
+    use crate::math::shape_factory::ShapeFactory::{Circle, Rectangle};
     use crate::math::size::Size;
 
     #[test]
@@ -286,6 +309,34 @@ mod tests {
         assert_eq!(texture.get_color_data(), &expected_colors);
     }
 
+    #[test]
+    fn test_cached_distances_match_uncached() {
+        let size = Size::square(100);
+        let aabb = AABB::with_size(size);
+        let color = ColorFactory::Sequence(vec![RED, GREEN, BLUE]);
+        let depth = DepthFactory::new_dome(200, 100);
+        let renderer = RenderingComponent::new_shape_with_depth(Circle, color, depth);
+        let cached = Component::Rendering(Box::new(renderer));
+        // Layers aren't cached by the repeat layout
+        let uncached = Component::Layers(vec![(BlendMode::Depth, cached.clone())]);
+
+        let mut results = Vec::new();
+
+        for component in [cached, uncached] {
+            let row = RepeatLayout::new(true, 10, component).unwrap();
+            let row = Component::Layout(Box::new(LayoutComponent::Repeat(row)));
+            let layout = RepeatLayout::new(false, 10, row).unwrap();
+            let mut texture = Texture::new(size, WHITE);
+
+            layout.generate(&mut texture, Data::for_texture(aabb));
+
+            results.push(texture);
+        }
+
+        assert_eq!(results[0].get_color_data(), results[1].get_color_data());
+        assert_eq!(results[0].get_depth_data(), results[1].get_depth_data());
+    }
+
     pub fn create_random_component() -> Component {
         let color = ColorFactory::Sequence(vec![RED, GREEN, BLUE]);
         let depth = DepthFactory::Uniform(255);
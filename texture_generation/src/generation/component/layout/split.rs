@@ -113,6 +113,13 @@ impl SplitLayout {
         })
     }
 
+    pub fn is_streamable(&self) -> bool {
+        self.entries.iter().all(|entry| match entry {
+            SplitEntry::Fixed(_, component) => component.is_streamable(),
+            SplitEntry::Proportional(_, component) => component.is_streamable(),
+        })
+    }
+
     /// Generates the component in the area defined by the [`AABB`].
     pub fn generate(&self, texture: &mut Texture, data: Data) {
         if self.is_horizontal {
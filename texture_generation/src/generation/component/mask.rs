@@ -0,0 +1,102 @@
+use crate::generation::component::Component;
+use crate::generation::data::texture::Texture;
+use crate::generation::data::Data;
+use crate::math::shape_factory::ShapeFactory;
+
+#[derive(Clone, Debug, PartialEq)]
+/// Renders an inner [`Component`], but only inside a [`Shape`].
+pub struct MaskComponent {
+    shape_factory: ShapeFactory,
+    component: Component,
+}
+
+impl MaskComponent {
+    pub fn new(shape_factory: ShapeFactory, component: Component) -> MaskComponent {
+        MaskComponent {
+            shape_factory,
+            component,
+        }
+    }
+
+    pub fn is_streamable(&self) -> bool {
+        self.component.is_streamable()
+    }
+
+    /// Generates the inner [`Component`] & only keeps the pixels inside the shape in the area defined by the [`AABB`].
+    pub fn generate(&self, texture: &mut Texture, data: &Data) {
+        let aabbs = data.get_aabbs_in_texture_space();
+        let shape = match self.shape_factory.create_shape(aabbs.get_inner()) {
+            Ok(shape) => shape,
+            Err(_) => return,
+        };
+
+        self.component.generate_in_scratch(
+            texture,
+            data,
+            |texture, _scratch, point, color, depth| {
+                if depth > 0 && shape.distance(point) <= 1.0 {
+                    texture.set(point, color, depth);
+                }
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::component::rendering::RenderingComponent;
+    use crate::math::aabb::AABB;
+    use crate::math::color::{RED, WHITE};
+    use crate::math::point::Point;
+    use crate::math::size::Size;
+
+    #[test]
+    fn test_mask_fill_area_with_circle() {
+        let size = Size::square(5);
+        let mut texture = Texture::new(size, WHITE);
+
+        let fill = RenderingComponent::new_fill_area(RED, 1);
+        let component = Component::Rendering(Box::new(fill));
+        let mask = MaskComponent::new(ShapeFactory::Circle, component);
+
+        mask.generate(&mut texture, &Data::for_texture(AABB::with_size(size)));
+
+        #[rustfmt::skip]
+        let expected_colors = vec![
+            WHITE, WHITE,   RED, WHITE, WHITE,
+            WHITE,   RED,   RED,   RED, WHITE,
+              RED,   RED,   RED,   RED,   RED,
+            WHITE,   RED,   RED,   RED, WHITE,
+            WHITE, WHITE,   RED, WHITE, WHITE,
+        ];
+
+        assert_eq!(texture.get_color_data(), &expected_colors);
+
+        for corner in &[0, 4, 20, 24] {
+            assert_eq!(texture.get_depth_data()[*corner], 0);
+        }
+    }
+
+    #[test]
+    fn test_mask_with_offset() {
+        let mut texture = Texture::new(Size::new(6, 4), WHITE);
+
+        let fill = RenderingComponent::new_fill_area(RED, 1);
+        let component = Component::Rendering(Box::new(fill));
+        let mask = MaskComponent::new(ShapeFactory::Rectangle, component);
+        let aabb = AABB::new(Point::new(2, 1), Size::new(3, 2));
+
+        mask.generate(&mut texture, &Data::for_texture(aabb));
+
+        #[rustfmt::skip]
+        let expected_colors = vec![
+            WHITE, WHITE, WHITE, WHITE, WHITE, WHITE,
+            WHITE, WHITE,   RED,   RED,   RED, WHITE,
+            WHITE, WHITE,   RED,   RED,   RED, WHITE,
+            WHITE, WHITE, WHITE, WHITE, WHITE, WHITE,
+        ];
+
+        assert_eq!(texture.get_color_data(), &expected_colors);
+    }
+}
@@ -1,35 +1,157 @@
+use crate::generation::component::blend::BlendMode;
 use crate::generation::component::border::BorderComponent;
+use crate::generation::component::color_mask::ColorMaskComponent;
+use crate::generation::component::decal::DecalComponent;
+use crate::generation::component::heightmap::HeightmapComponent;
 use crate::generation::component::layout::LayoutComponent;
+use crate::generation::component::mask::MaskComponent;
+use crate::generation::component::outline::OutlineComponent;
 use crate::generation::component::rendering::RenderingComponent;
 use crate::generation::data::texture::Texture;
 use crate::generation::data::Data;
+use crate::math::color::Color;
+use crate::math::point::Point;
 
+pub mod blend;
 pub mod border;
+pub mod color_mask;
+pub mod decal;
+pub mod heightmap;
 pub mod layout;
+pub mod mask;
+pub mod outline;
 pub mod rendering;
 
 #[derive(Clone, Debug, PartialEq)]
 /// A wrapper for different types of components.
 pub enum Component {
     Border(Box<BorderComponent>),
+    ColorMask(Box<ColorMaskComponent>),
+    Decal(Box<DecalComponent>),
     Empty,
-    Layers(Vec<Component>),
+    Heightmap(Box<HeightmapComponent>),
+    /// Generates the layers in order & combines each with the ones below based on its [`BlendMode`].
+    Layers(Vec<(BlendMode, Component)>),
     Layout(Box<LayoutComponent>),
+    Mask(Box<MaskComponent>),
     Mock(u8),
+    Outline(Box<OutlineComponent>),
     Rendering(Box<RenderingComponent>),
+    /// Selects one of the components based on the `global_id` of the tile, e.g. for a checkerboard.
+    SelectByGlobalId(Vec<Component>),
 }
 
 impl Component {
+    /// Returns true, if the component only depends on coordinates relative to its [`AABB`]
+    /// and never reads neighboring pixels, so that the texture can be generated in blocks of rows.
+    pub fn is_streamable(&self) -> bool {
+        match self {
+            Component::Border(component) => component.is_streamable(),
+            Component::ColorMask(component) => component.is_streamable(),
+            Component::Decal(_)
+            | Component::Empty
+            | Component::Heightmap(_)
+            | Component::Mock(_) => true,
+            Component::Layers(layers) => layers
+                .iter()
+                .all(|(_mode, component)| component.is_streamable()),
+            Component::Layout(component) => component.is_streamable(),
+            Component::Mask(component) => component.is_streamable(),
+            Component::Outline(_) => false,
+            Component::Rendering(component) => component.is_streamable(),
+            Component::SelectByGlobalId(components) => {
+                components.iter().all(|component| component.is_streamable())
+            }
+        }
+    }
+
     /// Generates the texture inside the [`AABB`].
     pub fn generate(&self, texture: &mut Texture, data: &Data) {
         match self {
             Component::Border(component) => component.generate(texture, data),
+            Component::ColorMask(component) => component.generate(texture, data),
+            Component::Decal(component) => component.generate(texture, data),
+            Component::Heightmap(component) => component.generate(texture, data),
             Component::Layers(layers) => layers
                 .iter()
-                .for_each(|component| component.generate(texture, data)),
+                .for_each(|(mode, component)| mode.generate(component, texture, data)),
             Component::Layout(component) => component.generate(texture, data),
+            Component::Mask(component) => component.generate(texture, data),
+            Component::Outline(component) => component.generate(texture, data),
             Component::Rendering(component) => component.render(texture, data),
+            Component::SelectByGlobalId(components) if !components.is_empty() => {
+                let index = data.get_global_id() % components.len();
+                components[index].generate(texture, data);
+            }
             _ => {}
         }
     }
+
+    /// Generates the [`Component`] into a scratch [`Texture`], which only covers the outer [`AABB`](crate::math::aabb::AABB).
+    /// Then calls `composite` for each pixel of the scratch with its [`Point`], [`Color`] & depth,
+    /// which combines it with the texture.
+    pub fn generate_in_scratch<F>(&self, texture: &mut Texture, data: &Data, mut composite: F)
+    where
+        F: FnMut(&mut Texture, &Texture, &Point, &Color, u8),
+    {
+        let aabbs = data.get_aabbs_in_texture_space();
+        let area = match aabbs.get_outer().intersection(&texture.get_aabb()) {
+            Some(area) => area,
+            None => return,
+        };
+        let mut scratch = texture.create_scratch(&area);
+
+        self.generate(&mut scratch, data);
+
+        let start = area.start();
+        let end = area.end();
+        let mut point = start;
+        let mut index = 0;
+
+        while point.y < end.y {
+            point.x = start.x;
+
+            while point.x < end.x {
+                let color = scratch.get_color_data()[index];
+                let depth = scratch.get_depth_data()[index];
+
+                composite(texture, &scratch, &point, &color, depth);
+
+                point.x += 1;
+                index += 1;
+            }
+
+            point.y += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::aabb::AABB;
+    use crate::math::color::{BLUE, RED, WHITE};
+    use crate::math::point::Point;
+    use crate::math::size::Size;
+
+    #[test]
+    fn test_select_by_global_id() {
+        let red = RenderingComponent::new_fill_area(RED, 1);
+        let blue = RenderingComponent::new_fill_area(BLUE, 1);
+        let component = Component::SelectByGlobalId(vec![
+            Component::Rendering(Box::new(red)),
+            Component::Rendering(Box::new(blue)),
+        ]);
+        let size = Size::new(3, 1);
+        let outer = AABB::with_size(size);
+        let mut texture = Texture::new(size, WHITE);
+
+        for x in 0..3 {
+            let inner = AABB::new(Point::new(x, 0), Size::square(1));
+            let data = Data::for_two_aabb(x as usize, outer, inner);
+            component.generate(&mut texture, &data);
+        }
+
+        assert_eq!(texture.get_color_data(), &vec![RED, BLUE, RED]);
+    }
 }
@@ -0,0 +1,102 @@
+use crate::generation::component::Component;
+use crate::generation::data::texture::Texture;
+use crate::generation::data::Data;
+use crate::math::color::Color;
+use crate::math::point::Point;
+
+#[derive(Clone, Debug, PartialEq)]
+/// Renders an inner [`Component`] & draws a stroke around the area covered by it.
+pub struct OutlineComponent {
+    thickness: u32,
+    color: Color,
+    component: Component,
+}
+
+impl OutlineComponent {
+    pub fn new(thickness: u32, color: Color, component: Component) -> OutlineComponent {
+        OutlineComponent {
+            thickness,
+            color,
+            component,
+        }
+    }
+
+    /// Generates the inner [`Component`] with its outline in the area defined by the [`AABB`].
+    pub fn generate(&self, texture: &mut Texture, data: &Data) {
+        let thickness = self.thickness as i32;
+        let max_distance = thickness * thickness;
+
+        self.component.generate_in_scratch(
+            texture,
+            data,
+            |texture, scratch, point, color, depth| {
+                if depth > 0 {
+                    texture.set(point, color, depth);
+                    return;
+                }
+
+                let area = scratch.get_aabb();
+                let start = area.start();
+                let end = area.end();
+                let mut outline_depth = 0;
+
+                for y in (point.y - thickness).max(start.y)..(point.y + thickness + 1).min(end.y) {
+                    for x in
+                        (point.x - thickness).max(start.x)..(point.x + thickness + 1).min(end.x)
+                    {
+                        let diff_x = x - point.x;
+                        let diff_y = y - point.y;
+
+                        if diff_x * diff_x + diff_y * diff_y > max_distance {
+                            continue;
+                        }
+
+                        let neighbor = scratch.get_depth(&Point::new(x, y)).unwrap_or_default();
+                        outline_depth = outline_depth.max(neighbor);
+                    }
+                }
+
+                if outline_depth > 0 {
+                    texture.set(point, &self.color, outline_depth);
+                }
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::component::rendering::RenderingComponent;
+    use crate::math::aabb::AABB;
+    use crate::math::color::{BLUE, RED, WHITE};
+    use crate::math::shape_factory::ShapeFactory;
+    use crate::math::size::Size;
+
+    #[test]
+    fn test_outline_circle() {
+        let size = Size::square(7);
+        let outer = AABB::with_size(size);
+        let inner = AABB::new(Point::new(1, 1), Size::square(5));
+        let mut texture = Texture::new(size, WHITE);
+
+        let circle = RenderingComponent::new_shape(ShapeFactory::Circle, RED, 1);
+        let component = Component::Rendering(Box::new(circle));
+        let outline = OutlineComponent::new(1, BLUE, component);
+
+        outline.generate(&mut texture, &Data::for_two_aabb(0, outer, inner));
+
+        #[rustfmt::skip]
+        let expected_colors = vec![
+            WHITE, WHITE, WHITE,  BLUE, WHITE, WHITE, WHITE,
+            WHITE, WHITE,  BLUE,   RED,  BLUE, WHITE, WHITE,
+            WHITE,  BLUE,   RED,   RED,   RED,  BLUE, WHITE,
+             BLUE,   RED,   RED,   RED,   RED,   RED,  BLUE,
+            WHITE,  BLUE,   RED,   RED,   RED,  BLUE, WHITE,
+            WHITE, WHITE,  BLUE,   RED,  BLUE, WHITE, WHITE,
+            WHITE, WHITE, WHITE,  BLUE, WHITE, WHITE, WHITE,
+        ];
+
+        assert_eq!(texture.get_color_data(), &expected_colors);
+    }
+}
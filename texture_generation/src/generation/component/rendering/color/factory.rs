@@ -1,10 +1,16 @@
 use crate::generation::component::rendering::color::selector::ColorSelector;
+use crate::generation::component::rendering::color::voronoi::VoronoiSelector;
 use crate::generation::component::rendering::color::wood::WoodFactory;
 use crate::generation::data::Data;
-use crate::generation::random::{Random, COLOR_INDEX};
+use crate::generation::random::{Random, WeightedChoice, COLOR_INDEX};
+use crate::math::aabb::AABB;
+use crate::math::axis::Axis;
 use crate::math::color::Color;
+use crate::math::noise::FractalNoise;
 use anyhow::{bail, Result};
-use noise::{Seedable, SuperSimplex};
+
+const HUE_INDEX: u32 = 1;
+const VALUE_INDEX: u32 = 2;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum ColorFactory {
@@ -20,29 +26,60 @@ pub enum ColorFactory {
     /// Randomly select a color from a list based on probability.
     Probability {
         random: Random,
-        colors: Vec<(usize, Color)>,
-        max_number: usize,
+        choice: WeightedChoice,
+        colors: Vec<Color>,
     },
     /// Uses a noise function to interpolate between 2 colors.
     Noise {
         color0: Color,
         color1: Color,
+        fractal: FractalNoise,
         base_factor: f32,
         scale_x: f64,
         scale_y: f64,
+        /// Repeats the noise seamlessly across the [`AABB`](crate::math::aabb::AABB).
+        tileable: bool,
     },
     /// Uses a noise function to interpolate between 2 colors randomly selected from a list.
     NoiseWithRandomColors {
         random: Random,
-        colors: Vec<(usize, Color)>,
-        max_number: usize,
+        choice: WeightedChoice,
+        colors: Vec<Color>,
+        fractal: FractalNoise,
         base_factor: f32,
         scale_x: f64,
         scale_y: f64,
+        /// Repeats the noise seamlessly across the [`AABB`](crate::math::aabb::AABB).
+        tileable: bool,
+    },
+    /// A cellular pattern, where each pixel uses the color of its nearest feature point.
+    Voronoi {
+        colors: Vec<Color>,
+        density: u32,
+        seed: u64,
     },
     WoodRings(WoodFactory),
     WoodX(WoodFactory),
     WoodY(WoodFactory),
+    /// Interpolates linearly between 2 colors from the start to the end of the [`AABB`](crate::math::aabb::AABB) along the axis.
+    Gradient {
+        color0: Color,
+        color1: Color,
+        axis: Axis,
+    },
+    /// Mirrors the first half of another factory onto the second half of the [`AABB`](crate::math::aabb::AABB).
+    /// [`Axis::X`] makes it symmetric about the center column.
+    Mirror {
+        base: Box<ColorFactory>,
+        axis: Axis,
+    },
+    /// Shifts the hue & value of another factory's colors per instance.
+    Jitter {
+        base: Box<ColorFactory>,
+        hue: f32,
+        value: f32,
+        random: Random,
+    },
 }
 
 impl ColorFactory {
@@ -77,21 +114,23 @@ impl ColorFactory {
     }
 
     pub fn new_probability(random: Random, colors: Vec<(usize, Color)>) -> Result<ColorFactory> {
-        let (threshold, converted_colors) = convert_probability("Probability", colors)?;
+        let (choice, colors) = convert_probability("Probability", colors)?;
 
         Ok(ColorFactory::Probability {
             random,
-            colors: converted_colors,
-            max_number: threshold,
+            choice,
+            colors,
         })
     }
 
     pub fn new_noise(
         random: Random,
         colors: Vec<(usize, Color)>,
+        fractal: FractalNoise,
         base_factor: f32,
         scale_x: u32,
         scale_y: u32,
+        tileable: bool,
     ) -> Result<ColorFactory> {
         if base_factor < 0.0 {
             bail!("Argument 'base_factor' needs to be 0 or greater");
@@ -99,18 +138,69 @@ impl ColorFactory {
             bail!("Argument 'base_factor' needs to be less than 1");
         }
 
-        let (threshold, converted_colors) = convert_probability("Noise", colors)?;
+        let (choice, colors) = convert_probability("Noise", colors)?;
 
         Ok(ColorFactory::NoiseWithRandomColors {
             random,
-            colors: converted_colors,
-            max_number: threshold,
+            choice,
+            colors,
+            fractal,
             base_factor,
             scale_x: scale_x as f64,
             scale_y: scale_y as f64,
+            tileable,
+        })
+    }
+
+    pub fn new_voronoi(colors: Vec<Color>, density: u32, seed: u64) -> Result<ColorFactory> {
+        if colors.len() < 2 {
+            bail!("ColorFactory::Voronoi requires at least 2 colors");
+        } else if density == 0 {
+            bail!("Argument 'density' needs to be greater than 0");
+        }
+
+        Ok(ColorFactory::Voronoi {
+            colors,
+            density,
+            seed,
+        })
+    }
+
+    pub fn new_jitter(
+        base: ColorFactory,
+        hue: f32,
+        value: f32,
+        random: Random,
+    ) -> Result<ColorFactory> {
+        if !(0.0..=180.0).contains(&hue) {
+            bail!("Argument 'hue' needs to be between 0 and 180");
+        } else if !(0.0..=1.0).contains(&value) {
+            bail!("Argument 'value' needs to be between 0 and 1");
+        }
+
+        Ok(ColorFactory::Jitter {
+            base: Box::new(base),
+            hue,
+            value,
+            random,
         })
     }
 
+    /// Returns false for factories, which sample noise at absolute coordinates.
+    pub fn is_streamable(&self) -> bool {
+        match self {
+            ColorFactory::Jitter { base, .. } | ColorFactory::Mirror { base, .. } => {
+                base.is_streamable()
+            }
+            ColorFactory::Noise { .. }
+            | ColorFactory::NoiseWithRandomColors { .. }
+            | ColorFactory::WoodRings(_)
+            | ColorFactory::WoodX(_)
+            | ColorFactory::WoodY(_) => false,
+            _ => true,
+        }
+    }
+
     /// Creates a ['ColorSelector'].
     pub fn create(&self, data: &Data) -> ColorSelector {
         match self {
@@ -125,65 +215,68 @@ impl ColorFactory {
             }
             ColorFactory::Probability {
                 random,
+                choice,
                 colors,
-                max_number,
             } => {
-                let index = random.get_random_instance_usize(data, *max_number, COLOR_INDEX);
-
-                for (threshold, color) in colors {
-                    if index < *threshold {
-                        return ColorSelector::ConstantColor(*color);
-                    }
-                }
-
-                ColorSelector::ConstantColor(colors[0].1)
+                let index = choice.choose_instance(random, data, COLOR_INDEX);
+                ColorSelector::ConstantColor(colors[index])
             }
             ColorFactory::Noise {
                 color0,
                 color1,
+                fractal,
                 base_factor,
                 scale_x,
                 scale_y,
+                tileable,
             } => {
-                let noise = SuperSimplex::new().set_seed(data.get_instance_id() as u32);
+                let noise = fractal.create(data.get_instance_id() as u32);
                 ColorSelector::Noise {
                     color0: *color0,
                     color1: *color1,
                     noise: Box::new(noise),
+                    fractal: *fractal,
                     base_factor: *base_factor,
                     scale_x: *scale_x,
                     scale_y: *scale_y,
+                    tiling: get_tiling(data, *tileable),
                 }
             }
             ColorFactory::NoiseWithRandomColors {
                 random,
+                choice,
                 colors,
-                max_number,
+                fractal,
                 base_factor,
                 scale_x,
                 scale_y,
+                tileable,
             } => {
-                let random0 = random.get_random_instance_usize(data, *max_number, 0);
-                let random1 = random.get_random_instance_usize(data, *max_number, 1);
-
-                let index0 = get_color_index(colors, random0);
-                let mut index1 = get_color_index(colors, random1);
+                let index0 = choice.choose_instance(random, data, 0);
+                let mut index1 = choice.choose_instance(random, data, 1);
 
                 if index0 == index1 {
                     index1 = (index0 + 1) % colors.len();
                 }
 
-                let noise = SuperSimplex::new().set_seed(data.get_instance_id() as u32);
+                let noise = fractal.create(data.get_instance_id() as u32);
 
                 ColorSelector::Noise {
-                    color0: colors[index0].1,
-                    color1: colors[index1].1,
+                    color0: colors[index0],
+                    color1: colors[index1],
                     noise: Box::new(noise),
+                    fractal: *fractal,
                     base_factor: *base_factor,
                     scale_x: *scale_x,
                     scale_y: *scale_y,
+                    tiling: get_tiling(data, *tileable),
                 }
             }
+            ColorFactory::Voronoi {
+                colors,
+                density,
+                seed,
+            } => ColorSelector::Voronoi(VoronoiSelector::new(colors, *density, *seed, data)),
             ColorFactory::WoodRings(factory) => {
                 let data1 = data.get_aabbs_in_texture_space();
                 let aabb = data1.get_inner();
@@ -214,47 +307,106 @@ impl ColorFactory {
                     selector: factory.create(data, aabb.size().width()),
                 }
             }
+            ColorFactory::Gradient {
+                color0,
+                color1,
+                axis,
+            } => {
+                let aabbs = data.get_aabbs_in_texture_space();
+                let aabb = aabbs.get_inner();
+                let (start, length) = match axis {
+                    Axis::X => (aabb.start().x, aabb.size().width()),
+                    Axis::Y => (aabb.start().y, aabb.size().height()),
+                };
+
+                ColorSelector::Gradient {
+                    color0: *color0,
+                    color1: *color1,
+                    axis: *axis,
+                    start,
+                    steps: length.saturating_sub(1).max(1) as f32,
+                }
+            }
+            ColorFactory::Mirror { base, axis } => {
+                let aabbs = data.get_aabbs_in_texture_space();
+                let aabb = aabbs.get_inner();
+                let sum = match axis {
+                    Axis::X => aabb.start().x + aabb.end().x - 1,
+                    Axis::Y => aabb.start().y + aabb.end().y - 1,
+                };
+
+                match base.create(data) {
+                    ColorSelector::ConstantColor(color) => ColorSelector::ConstantColor(color),
+                    selector => ColorSelector::Mirror {
+                        selector: Box::new(selector),
+                        axis: *axis,
+                        sum,
+                    },
+                }
+            }
+            ColorFactory::Jitter {
+                base,
+                hue,
+                value,
+                random,
+            } => {
+                let hue = random.get_random_instance_f32(data, 2.0 * *hue, HUE_INDEX) - *hue;
+                let value =
+                    random.get_random_instance_f32(data, 2.0 * *value, VALUE_INDEX) - *value;
+
+                match base.create(data) {
+                    ColorSelector::ConstantColor(color) => {
+                        ColorSelector::ConstantColor(color.shift_hsv(hue, value))
+                    }
+                    selector => ColorSelector::Jitter {
+                        selector: Box::new(selector),
+                        hue,
+                        value,
+                    },
+                }
+            }
         }
     }
 }
 
+/// Returns the area, which a tileable noise repeats across.
+fn get_tiling(data: &Data, tileable: bool) -> Option<AABB> {
+    if tileable {
+        Some(*data.get_aabbs_in_texture_space().get_inner())
+    } else {
+        None
+    }
+}
+
 fn convert_probability(
     parent: &str,
     colors: Vec<(usize, Color)>,
-) -> Result<(usize, Vec<(usize, Color)>)> {
+) -> Result<(WeightedChoice, Vec<Color>)> {
     if colors.len() < 2 {
         bail!("ColorFactory::{} requires at least 2 colors", parent);
     }
 
+    let mut weights = Vec::with_capacity(colors.len());
     let mut converted_colors = Vec::with_capacity(colors.len());
-    let mut threshold = 0;
 
     for (i, (probability, color)) in colors.into_iter().enumerate() {
         if probability == 0 {
             bail!("{}.probability of ColorFactory::{} is 0", i + 1, parent);
         }
 
-        threshold += probability;
-        converted_colors.push((threshold, color));
-    }
-
-    Ok((threshold, converted_colors))
-}
-
-fn get_color_index(colors: &[(usize, Color)], index: usize) -> usize {
-    for (i, (threshold, _color)) in colors.iter().enumerate() {
-        if index < *threshold {
-            return i;
-        }
+        weights.push(probability);
+        converted_colors.push(color);
     }
 
-    0
+    Ok((WeightedChoice::new(&weights)?, converted_colors))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::math::color::{BLUE, GREEN, RED};
+    use crate::math::point::Point;
+    use crate::math::size::Size;
     use Random::Hash;
 
     #[test]
@@ -282,6 +434,30 @@ mod tests {
         ColorFactory::new_probability(Hash, colors).unwrap();
     }
 
+    #[test]
+    #[should_panic]
+    fn test_new_jitter_with_invalid_hue() {
+        ColorFactory::new_jitter(ColorFactory::ConstantColor(RED), 200.0, 0.1, Hash).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_jitter_with_invalid_value() {
+        ColorFactory::new_jitter(ColorFactory::ConstantColor(RED), 10.0, 1.5, Hash).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_voronoi_too_few_colors() {
+        ColorFactory::new_voronoi(vec![RED], 4, 0).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_voronoi_without_density() {
+        ColorFactory::new_voronoi(vec![RED, GREEN], 0, 0).unwrap();
+    }
+
     #[test]
     fn test_constant() {
         let factory = ColorFactory::ConstantColor(RED);
@@ -333,6 +509,83 @@ mod tests {
         assert_cost(factory.create(&Data::only_instance_id(8)), BLUE);
     }
 
+    #[test]
+    fn test_jitter_without_magnitude() {
+        let base = ColorFactory::new_sequence(vec![RED, GREEN, BLUE]).unwrap();
+        let factory = ColorFactory::new_jitter(base, 0.0, 0.0, Hash).unwrap();
+
+        assert_cost(factory.create(&Data::only_instance_id(0)), RED);
+        assert_cost(factory.create(&Data::only_instance_id(1)), GREEN);
+        assert_cost(factory.create(&Data::only_instance_id(2)), BLUE);
+        assert_cost(factory.create(&Data::only_instance_id(3)), RED);
+    }
+
+    #[test]
+    fn test_jitter() {
+        let random = Random::Mock(vec![0, 750000, 250000]);
+        let base = ColorFactory::ConstantColor(RED);
+        let factory = ColorFactory::new_jitter(base, 60.0, 0.0, random).unwrap();
+
+        assert_cost(
+            factory.create(&Data::only_instance_id(0)),
+            Color::from_rgb(255, 128, 0),
+        );
+        assert_cost(
+            factory.create(&Data::only_instance_id(1)),
+            Color::from_rgb(255, 0, 128),
+        );
+    }
+
+    #[test]
+    fn test_gradient() {
+        let factory = ColorFactory::Gradient {
+            color0: RED,
+            color1: BLUE,
+            axis: Axis::Y,
+        };
+        let data = Data::for_texture(AABB::new(Point::new(10, 20), Size::new(5, 3)));
+        let selector = factory.create(&data);
+
+        for x in 10..15 {
+            assert_eq!(selector.select(&Point::new(x, 20)), RED);
+            assert_eq!(selector.select(&Point::new(x, 21)), RED.lerp(&BLUE, 0.5));
+            assert_eq!(selector.select(&Point::new(x, 22)), BLUE);
+        }
+    }
+
+    #[test]
+    fn test_mirror() {
+        let base = ColorFactory::Gradient {
+            color0: RED,
+            color1: BLUE,
+            axis: Axis::X,
+        };
+        let factory = ColorFactory::Mirror {
+            base: Box::new(base.clone()),
+            axis: Axis::X,
+        };
+        let data = Data::for_texture(AABB::new(Point::new(10, 20), Size::new(31, 40)));
+        let base_selector = base.create(&data);
+        let selector = factory.create(&data);
+
+        for y in 20..60 {
+            for x in 10..41 {
+                let point = Point::new(x, y);
+                let mirrored = Point::new(50 - x, y);
+
+                assert_eq!(selector.select(&point), selector.select(&mirrored));
+
+                if x <= 25 {
+                    assert_eq!(selector.select(&point), base_selector.select(&point));
+                }
+            }
+
+            assert_eq!(selector.select(&Point::new(10, y)), RED);
+            assert_eq!(selector.select(&Point::new(40, y)), RED);
+            assert_eq!(selector.select(&Point::new(25, y)), RED.lerp(&BLUE, 0.5));
+        }
+    }
+
     fn assert_cost(factory: ColorSelector, color: Color) {
         assert_eq!(factory, ColorSelector::ConstantColor(color));
     }
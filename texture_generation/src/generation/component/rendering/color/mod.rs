@@ -1,3 +1,4 @@
 pub mod factory;
 pub mod selector;
+pub mod voronoi;
 pub mod wood;
@@ -1,7 +1,10 @@
+use crate::generation::component::rendering::color::voronoi::VoronoiSelector;
 use crate::generation::component::rendering::color::wood::WoodSelector;
+use crate::math::aabb::AABB;
+use crate::math::axis::Axis;
 use crate::math::color::Color;
+use crate::math::noise::{wrap, FractalNoise, Noise};
 use crate::math::point::Point;
-use noise::{NoiseFn, SuperSimplex};
 
 #[derive(Clone, Debug)]
 pub enum ColorSelector {
@@ -11,11 +14,16 @@ pub enum ColorSelector {
     Noise {
         color0: Color,
         color1: Color,
-        noise: Box<SuperSimplex>,
+        noise: Box<Noise>,
+        fractal: FractalNoise,
         base_factor: f32,
         scale_x: f64,
         scale_y: f64,
+        /// The noise repeats seamlessly outside this area, if it is set.
+        tiling: Option<AABB>,
     },
+    /// Uses the color of the nearest feature point.
+    Voronoi(VoronoiSelector),
     WoodRings {
         center: Point,
         selector: WoodSelector,
@@ -28,6 +36,26 @@ pub enum ColorSelector {
         start_x: f32,
         selector: WoodSelector,
     },
+    /// Interpolates linearly between 2 colors along the axis.
+    Gradient {
+        color0: Color,
+        color1: Color,
+        axis: Axis,
+        start: i32,
+        steps: f32,
+    },
+    /// Delegates to another selector, but reflects the points in the second half along the axis.
+    Mirror {
+        selector: Box<ColorSelector>,
+        axis: Axis,
+        sum: i32,
+    },
+    /// Shifts the hue & value of another selector's colors.
+    Jitter {
+        selector: Box<ColorSelector>,
+        hue: f32,
+        value: f32,
+    },
 }
 
 impl ColorSelector {
@@ -38,15 +66,31 @@ impl ColorSelector {
                 color0,
                 color1,
                 noise,
+                fractal,
                 base_factor,
                 scale_x,
                 scale_y,
+                tiling,
             } => {
-                let x = point.x as f64 / scale_x;
-                let y = point.y as f64 / scale_y;
-                let factor = noise.get([x, y]);
+                let get = |x, y| fractal.get(noise, x, y);
+                let factor = match tiling {
+                    Some(aabb) => {
+                        let start = aabb.start();
+                        let size = aabb.size();
+
+                        wrap(
+                            (point.x - start.x) as f64 / scale_x,
+                            (point.y - start.y) as f64 / scale_y,
+                            size.width() as f64 / scale_x,
+                            size.height() as f64 / scale_y,
+                            get,
+                        )
+                    }
+                    None => get(point.x as f64 / scale_x, point.y as f64 / scale_y),
+                };
                 color0.lerp(color1, factor as f32 + *base_factor)
             }
+            ColorSelector::Voronoi(selector) => selector.select(point),
             ColorSelector::WoodRings { center, selector } => {
                 let distance = center.calculate_distance(point);
                 selector.select(point, distance)
@@ -57,6 +101,40 @@ impl ColorSelector {
             ColorSelector::WoodY { start_x, selector } => {
                 selector.select(point, point.x as f32 - *start_x)
             }
+            ColorSelector::Gradient {
+                color0,
+                color1,
+                axis,
+                start,
+                steps,
+            } => {
+                let position = match axis {
+                    Axis::X => point.x,
+                    Axis::Y => point.y,
+                };
+                color0.lerp(color1, (position - *start) as f32 / *steps)
+            }
+            ColorSelector::Mirror {
+                selector,
+                axis,
+                sum,
+            } => {
+                let position = match axis {
+                    Axis::X => point.x,
+                    Axis::Y => point.y,
+                };
+
+                if 2 * position > *sum {
+                    selector.select(&axis.mirror(point, *sum))
+                } else {
+                    selector.select(point)
+                }
+            }
+            ColorSelector::Jitter {
+                selector,
+                hue,
+                value,
+            } => selector.select(point).shift_hsv(*hue, *value),
         }
     }
 }
@@ -76,7 +154,9 @@ impl PartialEq for ColorSelector {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::math::color::RED;
+    use crate::math::color::{BLUE, RED};
+    use crate::math::noise::NoiseFunction;
+    use crate::math::size::Size;
 
     #[test]
     fn test_constant_color() {
@@ -85,4 +165,34 @@ mod tests {
             RED
         );
     }
+
+    #[test]
+    fn test_tileable_noise() {
+        let aabb = AABB::new(Point::new(10, 20), Size::new(64, 32));
+        let fractal = FractalNoise::new(NoiseFunction::Perlin, 2, 0.5, 2.0).unwrap();
+        let selector = ColorSelector::Noise {
+            color0: RED,
+            color1: BLUE,
+            noise: Box::new(fractal.create(3)),
+            fractal,
+            base_factor: 0.5,
+            scale_x: 10.0,
+            scale_y: 10.0,
+            tiling: Some(aabb),
+        };
+
+        for y in 20..52 {
+            assert_eq!(
+                selector.select(&Point::new(10, y)),
+                selector.select(&Point::new(74, y))
+            );
+        }
+
+        for x in 10..74 {
+            assert_eq!(
+                selector.select(&Point::new(x, 20)),
+                selector.select(&Point::new(x, 52))
+            );
+        }
+    }
 }
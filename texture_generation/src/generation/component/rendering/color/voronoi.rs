@@ -0,0 +1,169 @@
+use crate::generation::data::Data;
+use crate::generation::random::Random;
+use crate::math::color::Color;
+use crate::math::point::Point;
+
+#[derive(Clone, Debug)]
+struct Feature {
+    x: f32,
+    y: f32,
+    color: Color,
+}
+
+#[derive(Clone, Debug)]
+pub struct VoronoiSelector {
+    start: Point,
+    density: i32,
+    cell_width: f32,
+    cell_height: f32,
+    features: Vec<Feature>,
+}
+
+impl VoronoiSelector {
+    /// Splits the inner [`AABB`] into `density` x `density` cells & places one feature point in each.
+    pub fn new(colors: &[Color], density: u32, seed: u64, data: &Data) -> VoronoiSelector {
+        let aabbs = data.get_aabbs_in_texture_space();
+        let aabb = aabbs.get_inner();
+        let cell_width = aabb.size().width() as f32 / density as f32;
+        let cell_height = aabb.size().height() as f32 / density as f32;
+        let random = Random::Seed(seed);
+        let mut features = Vec::with_capacity((density * density) as usize);
+
+        for y in 0..density {
+            for x in 0..density {
+                let index = (y * density + x) * 3;
+                let offset_x = random.get_random_instance_f32(data, 1.0, index);
+                let offset_y = random.get_random_instance_f32(data, 1.0, index + 1);
+                let color_index = random.get_random_instance_usize(data, colors.len(), index + 2);
+
+                features.push(Feature {
+                    x: (x as f32 + offset_x) * cell_width,
+                    y: (y as f32 + offset_y) * cell_height,
+                    color: colors[color_index],
+                });
+            }
+        }
+
+        VoronoiSelector {
+            start: aabb.start(),
+            density: density as i32,
+            cell_width,
+            cell_height,
+            features,
+        }
+    }
+
+    /// Returns the color of the nearest feature point.
+    pub fn select(&self, point: &Point) -> Color {
+        self.features[self.get_feature_index(point)].color
+    }
+
+    /// Returns the index of the nearest feature point, which only checks the neighboring cells.
+    pub fn get_feature_index(&self, point: &Point) -> usize {
+        let x = (point.x - self.start.x) as f32;
+        let y = (point.y - self.start.y) as f32;
+        let cell_x = self.get_cell(x, self.cell_width);
+        let cell_y = self.get_cell(y, self.cell_height);
+        let mut nearest_index = 0;
+        let mut nearest_distance = f32::MAX;
+
+        for neighbor_y in (cell_y - 1).max(0)..=(cell_y + 1).min(self.density - 1) {
+            for neighbor_x in (cell_x - 1).max(0)..=(cell_x + 1).min(self.density - 1) {
+                let index = (neighbor_y * self.density + neighbor_x) as usize;
+                let feature = &self.features[index];
+                let diff_x = feature.x - x;
+                let diff_y = feature.y - y;
+                let distance = diff_x * diff_x + diff_y * diff_y;
+
+                if distance < nearest_distance {
+                    nearest_index = index;
+                    nearest_distance = distance;
+                }
+            }
+        }
+
+        nearest_index
+    }
+
+    fn get_cell(&self, value: f32, cell_size: f32) -> i32 {
+        if cell_size <= 0.0 {
+            return 0;
+        }
+
+        ((value / cell_size) as i32).max(0).min(self.density - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::aabb::AABB;
+    use crate::math::color::{BLUE, GREEN, RED};
+    use crate::math::size::Size;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_adjacent_pixels_mostly_share_a_color() {
+        let selector = create_selector(4);
+        let mut same = 0;
+        let mut total = 0;
+
+        for y in 0..100 {
+            for x in 0..99 {
+                let color = selector.select(&Point::new(x, y));
+                let next_color = selector.select(&Point::new(x + 1, y));
+
+                if color == next_color {
+                    same += 1;
+                }
+
+                total += 1;
+            }
+        }
+
+        assert!(same * 10 > total * 9);
+    }
+
+    #[test]
+    fn test_cell_count_scales_with_density() {
+        let count2 = count_cells(&create_selector(2));
+        let count4 = count_cells(&create_selector(4));
+        let count8 = count_cells(&create_selector(8));
+
+        assert!(count2 <= 4);
+        assert!(count4 <= 16);
+        assert!(count8 <= 64);
+        assert!(count2 < count4);
+        assert!(count4 < count8);
+    }
+
+    #[test]
+    fn test_same_seed_is_deterministic() {
+        let selector0 = create_selector(5);
+        let selector1 = create_selector(5);
+
+        for y in 0..100 {
+            for x in 0..100 {
+                let point = Point::new(x, y);
+                assert_eq!(selector0.select(&point), selector1.select(&point));
+            }
+        }
+    }
+
+    fn create_selector(density: u32) -> VoronoiSelector {
+        let data = Data::for_texture(AABB::with_size(Size::square(100)));
+        VoronoiSelector::new(&[RED, GREEN, BLUE], density, 42, &data)
+    }
+
+    fn count_cells(selector: &VoronoiSelector) -> usize {
+        let mut cells = HashSet::new();
+
+        for y in 0..100 {
+            for x in 0..100 {
+                cells.insert(selector.get_feature_index(&Point::new(x, y)));
+            }
+        }
+
+        cells.len()
+    }
+}
@@ -1,6 +1,39 @@
 use crate::generation::component::rendering::depth_factory::convert_many;
+use crate::generation::data::dither::dither;
 use crate::math::point::Point;
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// Defines how 2 depth values are combined.
+pub enum CombineOp {
+    Max,
+    Min,
+    /// Adds both values, but saturates at 255.
+    Add,
+    Average,
+}
+
+impl CombineOp {
+    /// Combines 2 depth values.
+    ///
+    /// ```
+    ///# use texture_generation::generation::component::rendering::depth::CombineOp;
+    /// assert_eq!(CombineOp::Max.combine(10.0, 30.0), 30.0);
+    /// assert_eq!(CombineOp::Min.combine(10.0, 30.0), 10.0);
+    /// assert_eq!(CombineOp::Add.combine(10.0, 30.0), 40.0);
+    /// assert_eq!(CombineOp::Add.combine(200.0, 100.0), 255.0);
+    /// assert_eq!(CombineOp::Average.combine(10.0, 30.0), 20.0);
+    /// ```
+    pub fn combine(&self, a: f32, b: f32) -> f32 {
+        match self {
+            CombineOp::Max => a.max(b),
+            CombineOp::Min => a.min(b),
+            CombineOp::Add => (a + b).min(255.0),
+            CombineOp::Average => (a + b) * 0.5,
+        }
+    }
+}
 
 #[derive(Clone, Debug, PartialEq)]
 /// Calculates the depth for each pixel.
@@ -11,6 +44,12 @@ pub enum DepthCalculator {
     InterpolateTwo { center: f32, diff: f32 },
     /// A linear interpolation between many depth values.
     InterpolateMany(Vec<(f32, f32)>),
+    /// Combines the depth values of 2 calculators.
+    Combine {
+        op: CombineOp,
+        a: Box<DepthCalculator>,
+        b: Box<DepthCalculator>,
+    },
     /// Creates a cylinder along the x-axis.
     CylinderX {
         center_x: f32,
@@ -122,14 +161,24 @@ impl DepthCalculator {
 
     /// Calculates the depth value based on a factor between 0 & 1.
     pub fn calculate(&self, point: &Point, factor: f32) -> u8 {
+        self.calculate_value(point, factor) as u8
+    }
+
+    /// Calculates the depth value like [`DepthCalculator::calculate()`],
+    /// but uses an ordered dither instead of truncating it to reduce banding.
+    pub fn calculate_dithered(&self, point: &Point, factor: f32) -> u8 {
+        dither(self.calculate_value(point, factor), point)
+    }
+
+    fn calculate_value(&self, point: &Point, factor: f32) -> f32 {
         match self {
-            DepthCalculator::Uniform(depth) => *depth,
-            DepthCalculator::InterpolateTwo { center, diff } => (*center + factor * (*diff)) as u8,
+            DepthCalculator::Uniform(depth) => *depth as f32,
+            DepthCalculator::InterpolateTwo { center, diff } => *center + factor * (*diff),
             DepthCalculator::InterpolateMany(data) => {
                 let (mut last_pos, mut last_depth) = data[0];
 
                 if factor <= last_pos {
-                    return last_depth as u8;
+                    return last_depth;
                 }
 
                 for &(pos, depth) in data {
@@ -140,8 +189,12 @@ impl DepthCalculator {
                     last_depth = depth;
                 }
 
-                last_depth as u8
+                last_depth
             }
+            DepthCalculator::Combine { op, a, b } => op.combine(
+                a.calculate_value(point, factor),
+                b.calculate_value(point, factor),
+            ),
             DepthCalculator::Dome { center, diff } => calculate_rounded(factor, *center, *diff),
             DepthCalculator::CylinderX {
                 center_x,
@@ -168,7 +221,7 @@ impl DepthCalculator {
                 diff_depth,
             } => {
                 let factor = (point.x as f32 - *start_x) / *diff_x;
-                (*start_depth + factor * (*diff_depth)) as u8
+                *start_depth + factor * (*diff_depth)
             }
             DepthCalculator::GradientY {
                 start_y,
@@ -177,20 +230,20 @@ impl DepthCalculator {
                 diff_depth,
             } => {
                 let factor = (point.y as f32 - *start_y) / *diff_y;
-                (*start_depth + factor * (*diff_depth)) as u8
+                *start_depth + factor * (*diff_depth)
             }
         }
     }
 }
 
-fn calculate_rounded(factor: f32, center: f32, diff: f32) -> u8 {
+fn calculate_rounded(factor: f32, center: f32, diff: f32) -> f32 {
     let factor = 1.0 - (1.0 - factor * factor).sqrt();
-    (center + factor * (diff)) as u8
+    center + factor * (diff)
 }
 
-fn interpolate(factor: f32, pos0: f32, pos1: f32, depth0: f32, depth1: f32) -> u8 {
+fn interpolate(factor: f32, pos0: f32, pos1: f32, depth0: f32, depth1: f32) -> f32 {
     let factor = (factor - pos0) / (pos1 - pos0);
-    (depth0 + factor * (depth1 - depth0)) as u8
+    depth0 + factor * (depth1 - depth0)
 }
 
 #[cfg(test)]
@@ -359,6 +412,26 @@ mod tests {
         assert_point(&calculator, &Point::new(-30, 14), 200);
     }
 
+    #[test]
+    fn test_dithered_gradient_preserves_mean() {
+        // the depth is x / 10, because the gradient continues beyond its end
+        let calculator = DepthCalculator::new_gradient_x(0, 10, 0, 1);
+        let mut truncated = 0;
+        let mut dithered = 0;
+
+        for y in 0..4 {
+            for x in 0..160 {
+                let point = Point::new(x, y);
+                truncated += calculator.calculate(&point, 0.0) as u32;
+                dithered += calculator.calculate_dithered(&point, 0.0) as u32;
+            }
+        }
+
+        // the exact sum of x / 10 is 5088
+        assert_eq!(truncated, 4800);
+        assert_eq!(dithered, 5088);
+    }
+
     fn assert(calculator: &DepthCalculator, factor: f32, result: u8) {
         assert_eq!(calculator.calculate(&POINT, factor), result);
     }
@@ -1,4 +1,4 @@
-use crate::generation::component::rendering::depth::DepthCalculator;
+use crate::generation::component::rendering::depth::{CombineOp, DepthCalculator};
 use crate::generation::data::Data;
 use crate::generation::random::Random;
 use anyhow::{bail, Result};
@@ -12,6 +12,12 @@ pub enum DepthFactory {
     InterpolateTwo { center: f32, diff: f32 },
     /// A linear interpolation between many depth values
     InterpolateMany(Vec<(f32, f32)>),
+    /// Combines the depth values of 2 factories per pixel.
+    Combine {
+        op: CombineOp,
+        a: Box<DepthFactory>,
+        b: Box<DepthFactory>,
+    },
     /// Creates a cylinder along the x-axis or y-axis.
     Cylinder {
         is_horizontal: bool,
@@ -22,6 +28,8 @@ pub enum DepthFactory {
     Dome { center: f32, diff: f32 },
     /// Creates a gradient along the x- or y-axis based on `random`.
     Gradient { random: Random, start: u8, end: u8 },
+    /// Each instance has a uniform depth between `min` & `max` based on `random`.
+    RandomUniform { min: u8, max: u8, random: Random },
 }
 
 impl DepthFactory {
@@ -37,6 +45,14 @@ impl DepthFactory {
         Ok(DepthFactory::InterpolateMany(convert_many(data)?))
     }
 
+    pub fn new_combine(op: CombineOp, a: DepthFactory, b: DepthFactory) -> DepthFactory {
+        DepthFactory::Combine {
+            op,
+            a: Box::new(a),
+            b: Box::new(b),
+        }
+    }
+
     pub fn new_dome(center: u8, border: u8) -> DepthFactory {
         let diff = border as f32 - center as f32;
         DepthFactory::Dome {
@@ -45,6 +61,14 @@ impl DepthFactory {
         }
     }
 
+    pub fn new_random_uniform(min: u8, max: u8, random: Random) -> Result<DepthFactory> {
+        if min > max {
+            bail!("RandomUniform's min {} is above its max {}", min, max);
+        }
+
+        Ok(DepthFactory::RandomUniform { min, max, random })
+    }
+
     /// Creates a ['DepthCalculator'].
     pub fn create(&self, data: &Data) -> DepthCalculator {
         match self {
@@ -54,6 +78,11 @@ impl DepthFactory {
                 diff: *diff,
             },
             DepthFactory::InterpolateMany(data) => DepthCalculator::InterpolateMany(data.clone()),
+            DepthFactory::Combine { op, a, b } => DepthCalculator::Combine {
+                op: *op,
+                a: Box::new(a.create(data)),
+                b: Box::new(b.create(data)),
+            },
             DepthFactory::Cylinder {
                 is_horizontal,
                 center_depth,
@@ -101,6 +130,11 @@ impl DepthFactory {
                     }
                 }
             }
+            DepthFactory::RandomUniform { min, max, random } => {
+                let range = (*max - *min) as u32 + 1;
+                let offset = random.get_random_instance_u32(data, range, 0);
+                DepthCalculator::Uniform(*min + offset as u8)
+            }
         }
     }
 }
@@ -134,6 +168,60 @@ pub fn convert_many(data: Vec<(f32, u8)>) -> Result<Vec<(f32, f32)>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::math::point::Point;
+
+    #[test]
+    fn test_new_random_uniform_with_min_above_max() {
+        assert!(DepthFactory::new_random_uniform(200, 100, Random::Hash).is_err());
+    }
+
+    #[test]
+    fn test_random_uniform() {
+        let random = Random::Mock(vec![3, 17]);
+        let factory = DepthFactory::new_random_uniform(100, 110, random).unwrap();
+        let point = Point::new(0, 0);
+
+        let depth0 = factory
+            .create(&Data::only_instance_id(0))
+            .calculate(&point, 0.0);
+        let depth1 = factory
+            .create(&Data::only_instance_id(1))
+            .calculate(&point, 0.0);
+
+        assert_eq!(depth0, 103);
+        assert_eq!(depth1, 106);
+        assert_ne!(depth0, depth1);
+        assert_eq!(
+            factory
+                .create(&Data::only_instance_id(0))
+                .calculate(&point, 0.0),
+            depth0
+        );
+    }
+
+    #[test]
+    fn test_random_uniform_with_single_value() {
+        let factory = DepthFactory::new_random_uniform(42, 42, Random::Hash).unwrap();
+
+        for instance_id in 0..10 {
+            assert_eq!(
+                factory.create(&Data::only_instance_id(instance_id)),
+                DepthCalculator::Uniform(42)
+            );
+        }
+    }
+
+    #[test]
+    fn test_combine_dome_with_uniform_base() {
+        let dome = DepthFactory::new_dome(200, 100);
+        let base = DepthFactory::Uniform(20);
+        let factory = DepthFactory::new_combine(CombineOp::Add, dome, base);
+        let calculator = factory.create(&Data::only_instance_id(0));
+        let point = Point::new(0, 0);
+
+        assert_eq!(calculator.calculate(&point, 0.0), 220);
+        assert_eq!(calculator.calculate(&point, 1.0), 120);
+    }
 
     #[test]
     fn test_new_interpolate_many_with_too_few_entries() {
@@ -0,0 +1,81 @@
+use crate::math::aabb::AABB;
+use crate::math::point::Point;
+use crate::math::shape_factory::ShapeFactory;
+use crate::math::size::Size;
+use std::collections::HashMap;
+
+/// The precomputed distances of a [`Shape`] for each pixel of its [`AABB`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistanceTable {
+    size: Size,
+    distances: Vec<f32>,
+}
+
+impl DistanceTable {
+    /// Creates the table for a [`Shape`] that fits inside an [`AABB`] with a specific [`Size`].
+    pub fn new(shape_factory: &ShapeFactory, size: Size) -> Option<DistanceTable> {
+        let shape = shape_factory.create_shape(&AABB::with_size(size)).ok()?;
+        let mut distances = Vec::with_capacity(size.len());
+
+        for y in 0..size.height() as i32 {
+            for x in 0..size.width() as i32 {
+                distances.push(shape.distance(&Point::new(x, y)));
+            }
+        }
+
+        Some(DistanceTable { size, distances })
+    }
+
+    /// Gets the distance at a [`Point`] relative to the start of the [`AABB`].
+    pub fn get(&self, point: &Point) -> f32 {
+        self.distances[self.size.to_index_risky(point)]
+    }
+}
+
+/// Caches the [`DistanceTable`]s of a [`ShapeFactory`], because many instances of a layout share the same [`Size`].
+#[derive(Clone, Debug, Default)]
+pub struct DistanceCache {
+    tables: HashMap<Size, Option<DistanceTable>>,
+}
+
+impl DistanceCache {
+    /// Gets the [`DistanceTable`] for a [`Size`]. Creates it, if it doesn't exist yet.
+    pub fn get_or_create(
+        &mut self,
+        shape_factory: &ShapeFactory,
+        size: Size,
+    ) -> Option<&DistanceTable> {
+        self.tables
+            .entry(size)
+            .or_insert_with(|| DistanceTable::new(shape_factory, size))
+            .as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::shape_factory::ShapeFactory::Circle;
+
+    #[test]
+    fn test_table_matches_shape() {
+        let aabb = AABB::new(Point::new(3, 7), Size::new(6, 4));
+        let shape = Circle.create_shape(&aabb).unwrap();
+        let table = DistanceTable::new(&Circle, aabb.size()).unwrap();
+
+        for y in 0..4 {
+            for x in 0..6 {
+                let point = Point::new(x, y);
+
+                assert_eq!(table.get(&point), shape.distance(&(point + aabb.start())));
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_shape() {
+        let mut cache = DistanceCache::default();
+
+        assert!(cache.get_or_create(&Circle, Size::new(1, 5)).is_none());
+    }
+}
@@ -1,14 +1,17 @@
 use color::factory::ColorFactory;
 
 use crate::generation::component::rendering::depth_factory::DepthFactory;
-use crate::generation::data::texture::Texture;
+use crate::generation::component::rendering::distance::DistanceCache;
+use crate::generation::data::texture::{Texture, TextureRow};
 use crate::generation::data::Data;
 use crate::math::color::{Color, PINK};
+use crate::math::point::Point;
 use crate::math::shape_factory::ShapeFactory;
 
 pub mod color;
 pub mod depth;
 pub mod depth_factory;
+pub mod distance;
 
 #[derive(Clone, Debug, PartialEq)]
 /// Renders the texture.
@@ -56,28 +59,52 @@ impl RenderingComponent {
         }
     }
 
+    /// Returns true, if the color doesn't depend on absolute coordinates.
+    pub fn is_streamable(&self) -> bool {
+        match self {
+            RenderingComponent::Shape { color_selector, .. } => color_selector.is_streamable(),
+            _ => true,
+        }
+    }
+
     /// Renders the texture in the area defined by the [`AABB`].
+    /// Only the part inside the texture is iterated.
+    ///
+    /// The rows are rendered in parallel, if the feature *parallel* is enabled.
     pub fn render(&self, texture: &mut Texture, data: &Data) {
+        self.render_rows(texture, data, cfg!(feature = "parallel"), None)
+    }
+
+    /// Renders the texture like [`RenderingComponent::render()`],
+    /// but reuses the distances of shapes with the same size.
+    pub fn render_cached(&self, texture: &mut Texture, data: &Data, cache: &mut DistanceCache) {
+        self.render_rows(texture, data, cfg!(feature = "parallel"), Some(cache))
+    }
+
+    fn render_rows(
+        &self,
+        texture: &mut Texture,
+        data: &Data,
+        parallel: bool,
+        cache: Option<&mut DistanceCache>,
+    ) {
         let aabbs = data.get_aabbs_in_texture_space();
-        let start = aabbs.get_start();
-        let end = aabbs.get_end();
+        let texture_aabb = texture.get_aabb();
+        let start = aabbs.get_start().max(&texture_aabb.start());
+        let end = aabbs.get_end().min(&texture_aabb.end());
+        let base_depth = texture.get_base_depth();
+        let dithering = texture.is_dithering();
 
         match self {
             RenderingComponent::FillArea { color, depth, .. } => {
-                let mut point = start;
-                let depth = texture.get_base_depth() + *depth;
-
-                while point.y < end.y {
-                    point.x = start.x;
+                let depth = base_depth + *depth;
+                let rows = texture.get_rows_mut(start.y, end.y);
 
-                    while point.x < end.x {
-                        texture.set(&point, color, depth);
-
-                        point.x += 1;
+                for_each_row(rows, parallel, |row| {
+                    for x in start.x..end.x {
+                        row.set(x, color, depth);
                     }
-
-                    point.y += 1;
-                }
+                });
             }
             RenderingComponent::Mock => {}
             RenderingComponent::Shape {
@@ -86,44 +113,89 @@ impl RenderingComponent {
                 depth_factory,
                 ..
             } => {
-                let mut point = start;
                 let color_selector = color_factory.create(data);
                 let depth_calculator = depth_factory.create(data);
-                let base_depth = texture.get_base_depth();
 
-                if let Ok(shape) = shape_factory.create_shape(aabbs.get_inner()) {
-                    while point.y < end.y {
-                        point.x = start.x;
+                let inner = aabbs.get_inner();
+                let render_row = |row: &mut TextureRow, distance: &dyn Fn(&Point) -> f32| {
+                    let mut point = Point::new(start.x, row.get_y());
 
-                        while point.x < end.x {
-                            let distance = shape.distance(&point);
+                    while point.x < end.x {
+                        let distance = distance(&point);
+
+                        if distance <= 1.0 {
+                            let color = color_selector.select(&point);
+                            let depth = if dithering {
+                                depth_calculator.calculate_dithered(&point, distance)
+                            } else {
+                                depth_calculator.calculate(&point, distance)
+                            };
+                            let depth = base_depth.saturating_add(depth);
+                            row.set(point.x, &color, depth);
+                        }
 
-                            if distance <= 1.0 {
-                                let color = color_selector.select(&point);
-                                let depth = depth_calculator.calculate(&point, distance);
-                                let depth = base_depth.saturating_add(depth);
-                                texture.set(&point, &color, depth);
-                            }
+                        point.x += 1;
+                    }
+                };
 
-                            point.x += 1;
-                        }
+                if let Some(cache) = cache {
+                    if let Some(table) = cache.get_or_create(shape_factory, inner.size()) {
+                        let rows = texture.get_rows_mut(start.y, end.y);
+                        let offset = inner.start();
 
-                        point.y += 1;
+                        for_each_row(rows, parallel, |row| {
+                            render_row(row, &|point| table.get(&(*point - offset)))
+                        });
                     }
+                } else if let Ok(shape) = shape_factory.create_shape(inner) {
+                    let rows = texture.get_rows_mut(start.y, end.y);
+
+                    for_each_row(rows, parallel, |row| {
+                        render_row(row, &|point| shape.distance(point))
+                    });
                 }
             }
         }
     }
 }
 
+fn for_each_row<F>(rows: Vec<TextureRow>, parallel: bool, render_row: F)
+where
+    F: Fn(&mut TextureRow) + Send + Sync,
+{
+    if parallel {
+        for_each_row_in_parallel(rows, render_row);
+    } else {
+        rows.into_iter().for_each(|mut row| render_row(&mut row));
+    }
+}
+
+#[cfg(feature = "parallel")]
+fn for_each_row_in_parallel<F>(rows: Vec<TextureRow>, render_row: F)
+where
+    F: Fn(&mut TextureRow) + Send + Sync,
+{
+    use rayon::prelude::*;
+
+    rows.into_par_iter()
+        .for_each(|mut row| render_row(&mut row));
+}
+
+#[cfg(not(feature = "parallel"))]
+fn for_each_row_in_parallel<F>(rows: Vec<TextureRow>, render_row: F)
+where
+    F: Fn(&mut TextureRow) + Send + Sync,
+{
+    rows.into_iter().for_each(|mut row| render_row(&mut row));
+}
+
 #[cfg(test)]
 mod tests {
     use ShapeFactory::Rectangle;
 
-    use crate::generation::data::texture::Texture;
+    use crate::generation::data::texture::{Texture, SET_CALLS};
     use crate::math::aabb::AABB;
-    use crate::math::color::{RED, WHITE};
-    use crate::math::point::Point;
+    use crate::math::color::{BLUE, RED, WHITE};
     use crate::math::size::Size;
 
     use super::*;
@@ -241,4 +313,49 @@ mod tests {
 
         assert_eq!(texture.get_depth_data(), &depth);
     }
+
+    #[test]
+    fn test_render_only_visible_pixels() {
+        let data_size = Size::square(4);
+        let outer = AABB::new(Point::new(-1000, -1000), Size::square(3000));
+        let mut texture = Texture::new(data_size, WHITE);
+        let renderer = RenderingComponent::new_shape(Rectangle, RED, 200);
+
+        let far_off_screen = AABB::new(Point::new(1000, -500), Size::square(1000));
+        let data = Data::for_two_aabb(0, outer, far_off_screen);
+        SET_CALLS.with(|calls| calls.set(0));
+
+        renderer.render_rows(&mut texture, &data, false, None);
+
+        assert_eq!(SET_CALLS.with(|calls| calls.get()), 0);
+
+        let covering = AABB::new(Point::new(-1000, -1000), Size::square(2000));
+        let data = Data::for_two_aabb(0, outer, covering);
+
+        renderer.render_rows(&mut texture, &data, false, None);
+
+        assert_eq!(SET_CALLS.with(|calls| calls.get()), data_size.len());
+        assert_eq!(texture.get_color_data(), &vec![RED; data_size.len()]);
+    }
+
+    #[test]
+    fn test_parallel_rendering_is_identical_to_serial() {
+        let size = Size::square(2048);
+        let outer = AABB::with_size(size);
+        let inner = AABB::new(Point::new(100, 200), Size::new(1500, 1700));
+        let data = Data::for_two_aabb(0, outer, inner);
+        let fill = RenderingComponent::new_fill_area(RED, 10);
+        let shape = RenderingComponent::new_shape(ShapeFactory::Circle, BLUE, 20);
+
+        let mut serial = Texture::new(size, WHITE);
+        let mut parallel = Texture::new(size, WHITE);
+
+        for renderer in &[fill, shape] {
+            renderer.render_rows(&mut serial, &data, false, None);
+            renderer.render_rows(&mut parallel, &data, true, None);
+        }
+
+        assert_eq!(serial.get_color_data(), parallel.get_color_data());
+        assert_eq!(serial.get_depth_data(), parallel.get_depth_data());
+    }
 }
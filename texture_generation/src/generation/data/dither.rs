@@ -0,0 +1,87 @@
+use crate::math::point::Point;
+
+/// The 4x4 Bayer matrix for ordered dithering.
+#[rustfmt::skip]
+const BAYER_4X4: [u8; 16] = [
+     0,  8,  2, 10,
+    12,  4, 14,  6,
+     3, 11,  1,  9,
+    15,  7, 13,  5,
+];
+
+/// Converts a value to an 8 bit integer with an ordered dither based on the [`Point`].
+///
+/// The offsets of the 4x4 block are centered around 0 & the result is rounded,
+/// so the mean of each block matches the value up to 1/16.
+///
+/// ```
+///# use texture_generation::generation::data::dither::dither;
+///# use texture_generation::math::point::Point;
+/// assert_eq!(dither(10.5, &Point::new(0, 0)), 10);
+/// assert_eq!(dither(10.5, &Point::new(1, 0)), 11);
+/// ```
+pub fn dither(value: f32, point: &Point) -> u8 {
+    let x = point.x.rem_euclid(4) as usize;
+    let y = point.y.rem_euclid(4) as usize;
+    let offset = (BAYER_4X4[y * 4 + x] as f32 - 7.5) / 16.0;
+
+    (value + offset).round().clamp(0.0, 255.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bayer_pattern() {
+        #[rustfmt::skip]
+        let expected = vec![
+            10, 11, 10, 11,
+            11, 10, 11, 10,
+            10, 11, 10, 11,
+            11, 10, 11, 10,
+        ];
+
+        assert_eq!(dither_block(10.5), expected);
+    }
+
+    #[test]
+    fn test_mean_is_preserved() {
+        for step in 0..=16 {
+            let value = 10.0 + step as f32 / 16.0;
+            let sum: u32 = dither_block(value).iter().map(|v| *v as u32).sum();
+
+            assert_eq!(sum, 160 + step);
+        }
+    }
+
+    #[test]
+    fn test_pattern_repeats() {
+        for y in -4..8 {
+            for x in -4..8 {
+                assert_eq!(
+                    dither(20.3, &Point::new(x, y)),
+                    dither(20.3, &Point::new(x + 4, y + 8))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_clamp_to_u8() {
+        assert_eq!(dither(-3.0, &Point::new(3, 3)), 0);
+        assert_eq!(dither(300.0, &Point::new(3, 3)), 255);
+    }
+
+    fn dither_block(value: f32) -> Vec<u8> {
+        let mut values = Vec::new();
+
+        for y in 0..4 {
+            for x in 0..4 {
+                values.push(dither(value, &Point::new(x, y)));
+            }
+        }
+
+        values
+    }
+}
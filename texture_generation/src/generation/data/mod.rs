@@ -3,6 +3,7 @@ use crate::math::aabb::AABB;
 use crate::math::side::Side;
 
 pub mod aabb;
+pub mod dither;
 pub mod texture;
 
 /// [`Data`] is used to store information while traversing a tree of [`Component`]s during rendering.
@@ -1,19 +1,31 @@
 use crate::generation::process::PostProcess;
 use crate::math::aabb::AABB;
-use crate::math::color::Color;
+use crate::math::color::{Color, BLACK};
 use crate::math::occupancy::OccupancyMap;
 use crate::math::point::Point;
 use crate::math::size::Size;
+use crate::utils::hash::StableHasher;
+#[cfg(test)]
+use std::cell::Cell;
 use std::collections::HashMap;
 
+#[cfg(test)]
+thread_local! {
+    /// Counts the calls of [`TextureRow::set()`] in the current thread.
+    pub(crate) static SET_CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
 /// Stores all the data of the texture.
 pub struct Texture {
+    /// The top left corner of the stored pixels. Only a scratch texture has a different one.
+    origin: Point,
     size: Size,
     tiles: Size,
     tile_size: Size,
     colors: Vec<Color>,
     depth: Vec<u8>,
     base_depth: u8,
+    dithering: bool,
     occupancy_maps: HashMap<usize, OccupancyMap>,
 }
 
@@ -42,29 +54,67 @@ impl Texture {
         let depth = vec![0; n];
 
         Texture {
+            origin: Point::default(),
             size,
             tiles,
             tile_size,
             colors,
             depth,
             base_depth,
+            dithering: false,
+            occupancy_maps: HashMap::new(),
+        }
+    }
+
+    /// Creates an empty texture with the same tiles, base depth & dithering,
+    /// which only stores the pixels inside the [`AABB`].
+    /// So a [`Component`](crate::generation::component::Component) can be generated in isolation,
+    /// without the cost of the whole texture.
+    pub fn create_scratch(&self, aabb: &AABB) -> Texture {
+        let size = aabb.size();
+        let n = size.len();
+
+        Texture {
+            origin: aabb.start(),
+            size,
+            tiles: self.tiles,
+            tile_size: self.tile_size,
+            colors: vec![BLACK; n],
+            depth: vec![0; n],
+            base_depth: self.base_depth,
+            dithering: self.dithering,
             occupancy_maps: HashMap::new(),
         }
     }
 
+    /// Gets the [`AABB`] of the stored pixels.
     pub fn get_aabb(&self) -> AABB {
-        AABB::with_size(self.size)
+        AABB::new(self.origin, self.size)
     }
 
     pub fn set_base_depth(&mut self, depth: u8) {
         self.base_depth = depth;
     }
 
+    /// Enables an ordered dither, when rendering depth values. Is off by default.
+    pub fn set_dithering(&mut self, dithering: bool) {
+        self.dithering = dithering;
+    }
+
+    pub fn is_dithering(&self) -> bool {
+        self.dithering
+    }
+
     /// Gets the [`Size`] of the textures.
     pub fn get_size(&self) -> &Size {
         &self.size
     }
 
+    /// Gets the [`Size`] of the whole texture, which is larger than [`Texture::get_size()`] for a scratch texture.
+    pub fn get_full_size(&self) -> Size {
+        self.tile_size * self.tiles
+    }
+
     /// Gets the [`Size`] of the tilemap.
     pub fn get_tiles(&self) -> &Size {
         &self.tiles
@@ -75,15 +125,127 @@ impl Texture {
         &self.tile_size
     }
 
-    /// Sets the [`Color`] & depth at the [`Point`].
+    /// Sets the [`Color`] & depth at the [`Point`], if the depth is higher than the current one.
+    /// A translucent color is blended over the current one.
     pub fn set(&mut self, point: &Point, color: &Color, depth: u8) {
-        let index = self.size.to_index_risky(point);
+        let index = self.size.to_index_risky(&(*point - self.origin));
         let old_depth = self.depth[index];
 
         if depth > old_depth {
             self.depth[index] = depth;
-            self.colors[index] = *color;
+            self.colors[index] = color.blend(&self.colors[index]);
+        }
+    }
+
+    /// Sets the [`Color`] & depth like [`Texture::set()`], but ignores a [`Point`] outside the texture.
+    /// Returns true, if the write landed inside.
+    ///
+    /// ```
+    ///# use texture_generation::generation::data::texture::Texture;
+    ///# use texture_generation::math::color::RED;
+    ///# use texture_generation::math::point::Point;
+    ///# use texture_generation::math::size::Size;
+    /// let mut texture = Texture::new(Size::new(2, 3), RED);
+    ///
+    /// assert!(texture.set_checked(&Point::new(1, 2), &RED, 42));
+    /// assert!(!texture.set_checked(&Point::new(-1, 2), &RED, 42));
+    /// ```
+    pub fn set_checked(&mut self, point: &Point, color: &Color, depth: u8) -> bool {
+        if !self.size.is_inside(&(*point - self.origin)) {
+            return false;
         }
+
+        self.set(point, color, depth);
+        true
+    }
+
+    /// Copies the colors & depth of another texture with its top left corner at the [`Point`].
+    /// Each pixel is set like [`Texture::set()`], so only pixels with a higher depth are copied.
+    /// The parts outside of this texture are clipped.
+    pub fn blit(&mut self, other: &Texture, at: Point) {
+        let other_size = other.get_size();
+        let aabb = self.get_aabb();
+        let start_x = at.x.max(aabb.start().x);
+        let start_y = at.y.max(aabb.start().y);
+        let end_x = (at.x + other_size.width() as i32).min(aabb.end().x);
+        let end_y = (at.y + other_size.height() as i32).min(aabb.end().y);
+
+        for y in start_y..end_y {
+            for x in start_x..end_x {
+                let index = other_size.convert_x_y((x - at.x) as u32, (y - at.y) as u32);
+                let color = other.colors[index];
+                let depth = other.depth[index];
+
+                self.set(&Point::new(x, y), &color, depth);
+            }
+        }
+    }
+
+    /// Gets the [`Color`] at the [`Point`], if it is inside the texture.
+    ///
+    /// ```
+    ///# use texture_generation::generation::data::texture::Texture;
+    ///# use texture_generation::math::color::RED;
+    ///# use texture_generation::math::point::Point;
+    ///# use texture_generation::math::size::Size;
+    /// let texture = Texture::new(Size::new(2, 3), RED);
+    ///
+    /// assert_eq!(texture.get_color(&Point::new(1, 2)), Some(RED));
+    /// assert_eq!(texture.get_color(&Point::new(2, 0)), None);
+    /// assert_eq!(texture.get_color(&Point::new(0, -1)), None);
+    /// ```
+    pub fn get_color(&self, point: &Point) -> Option<Color> {
+        self.get_index(point).map(|index| self.colors[index])
+    }
+
+    /// Gets the depth at the [`Point`], if it is inside the texture.
+    ///
+    /// ```
+    ///# use texture_generation::generation::data::texture::Texture;
+    ///# use texture_generation::math::color::RED;
+    ///# use texture_generation::math::point::Point;
+    ///# use texture_generation::math::size::Size;
+    /// let mut texture = Texture::new(Size::new(2, 3), RED);
+    /// texture.set(&Point::new(1, 2), &RED, 42);
+    ///
+    /// assert_eq!(texture.get_depth(&Point::new(1, 2)), Some(42));
+    /// assert_eq!(texture.get_depth(&Point::new(0, 0)), Some(0));
+    /// assert_eq!(texture.get_depth(&Point::new(1, 3)), None);
+    /// ```
+    pub fn get_depth(&self, point: &Point) -> Option<u8> {
+        self.get_index(point).map(|index| self.depth[index])
+    }
+
+    /// Gets the index of the [`Point`] in the color & depth data, if it is inside the texture.
+    pub fn get_index(&self, point: &Point) -> Option<usize> {
+        self.size.to_index(&(*point - self.origin))
+    }
+
+    /// Splits the texture into disjoint rows between `start_y` & `end_y`, which can be written in parallel.
+    pub fn get_rows_mut(&mut self, start_y: i32, end_y: i32) -> Vec<TextureRow<'_>> {
+        let width = self.size.width() as usize;
+        let start_y = (start_y - self.origin.y).max(0) as usize;
+        let end_y = ((end_y - self.origin.y).max(0) as usize).min(self.size.height() as usize);
+        let origin = self.origin;
+
+        if width == 0 || start_y >= end_y {
+            return Vec::new();
+        }
+
+        let colors = &mut self.colors[start_y * width..end_y * width];
+        let depth = &mut self.depth[start_y * width..end_y * width];
+
+        colors
+            .chunks_mut(width)
+            .zip(depth.chunks_mut(width))
+            .enumerate()
+            .map(|(i, (colors, depth))| TextureRow {
+                y: (start_y + i) as i32 + origin.y,
+                start_x: origin.x,
+                colors,
+                depth,
+            })
+            .collect()
     }
 
     /// Gets all the colors.
@@ -96,11 +258,77 @@ impl Texture {
         &mut self.colors
     }
 
+    /// Gets the luma of all the colors.
+    pub fn get_luma_data(&self) -> Vec<u8> {
+        self.colors.iter().map(|color| color.luma()).collect()
+    }
+
     /// Gets all the depth values.
     pub fn get_depth_data(&self) -> &[u8] {
         &self.depth
     }
 
+    /// Gets all the depth values mutable.
+    pub fn get_depth_data_mut(&mut self) -> &mut [u8] {
+        &mut self.depth
+    }
+
+    /// Calculates a FNV-1a hash of the colors & depth values, which is stable across runs & platforms.
+    pub fn calculate_hash(&self) -> u64 {
+        let mut hasher = StableHasher::new();
+
+        for color in &self.colors {
+            hasher.write(&[color.r(), color.g(), color.b(), color.a()]);
+        }
+
+        hasher.write(&self.depth);
+        hasher.finish()
+    }
+
+    /// Mirrors the colors & depth values from left to right.
+    pub fn flip_horizontal(&mut self) {
+        let width = self.size.width();
+        self.reindex(self.size, |x, y| (width - 1 - x, y));
+    }
+
+    /// Mirrors the colors & depth values from top to bottom.
+    pub fn flip_vertical(&mut self) {
+        let height = self.size.height();
+        self.reindex(self.size, |x, y| (x, height - 1 - y));
+    }
+
+    /// Rotates the colors & depth values by 90 degrees clockwise, which switches width & height.
+    /// Clears the [`OccupancyMap`]s.
+    pub fn rotate_90_cw(&mut self) {
+        let old_height = self.size.height();
+        self.reindex(self.size.flip(), |x, y| (y, old_height - 1 - x));
+        self.tiles = self.tiles.flip();
+        self.tile_size = self.tile_size.flip();
+    }
+
+    /// Rebuilds both buffers with a new [`Size`],
+    /// where `source` returns the old position of each new position.
+    /// Clears the [`OccupancyMap`]s, because they no longer match the new layout.
+    fn reindex<F: Fn(u32, u32) -> (u32, u32)>(&mut self, size: Size, source: F) {
+        let n = size.len();
+        let mut colors = Vec::with_capacity(n);
+        let mut depth = Vec::with_capacity(n);
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                let (old_x, old_y) = source(x, y);
+                let index = self.size.convert_x_y(old_x, old_y);
+                colors.push(self.colors[index]);
+                depth.push(self.depth[index]);
+            }
+        }
+
+        self.size = size;
+        self.colors = colors;
+        self.depth = depth;
+        self.occupancy_maps.clear();
+    }
+
     /// Gets the base depth for the current tile of the tilemap.
     pub fn get_base_depth(&self) -> u8 {
         self.base_depth
@@ -121,3 +349,288 @@ impl Texture {
         }
     }
 }
+
+/// A mutable row of a [`Texture`].
+pub struct TextureRow<'a> {
+    y: i32,
+    /// The x-coordinate of the first pixel.
+    start_x: i32,
+    colors: &'a mut [Color],
+    depth: &'a mut [u8],
+}
+
+impl<'a> TextureRow<'a> {
+    /// Gets the y-coordinate of the row.
+    pub fn get_y(&self) -> i32 {
+        self.y
+    }
+
+    /// Sets the [`Color`] & depth at the x-coordinate, like [`Texture::set()`].
+    pub fn set(&mut self, x: i32, color: &Color, depth: u8) {
+        #[cfg(test)]
+        SET_CALLS.with(|calls| calls.set(calls.get() + 1));
+
+        let index = (x - self.start_x) as usize;
+
+        if depth > self.depth[index] {
+            self.depth[index] = depth;
+            self.colors[index] = color.blend(&self.colors[index]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::color::{BLACK, BLUE, GREEN, RED, WHITE};
+
+    #[test]
+    fn test_get_luma_data() {
+        let mut texture = Texture::new(Size::new(3, 1), RED);
+        texture.get_color_data_mut()[1] = WHITE;
+        texture.get_color_data_mut()[2] = BLACK;
+
+        assert_eq!(texture.get_luma_data(), vec![76, 255, 0]);
+    }
+
+    #[test]
+    fn test_scratch() {
+        let mut texture = Texture::with_depth(Size::new(8, 6), RED, 3);
+        texture.set_dithering(true);
+        let aabb = AABB::new(Point::new(2, 1), Size::new(3, 4));
+
+        let mut scratch = texture.create_scratch(&aabb);
+        scratch.set(&Point::new(2, 1), &GREEN, 10);
+        scratch.get_rows_mut(4, 5)[0].set(4, &BLUE, 20);
+
+        assert_eq!(scratch.get_aabb(), aabb);
+        assert_eq!(scratch.get_full_size(), Size::new(8, 6));
+        assert_eq!(scratch.get_base_depth(), 3);
+        assert!(scratch.is_dithering());
+        assert_eq!(scratch.get_color(&Point::new(2, 1)), Some(GREEN));
+        assert_eq!(scratch.get_color(&Point::new(4, 4)), Some(BLUE));
+        assert_eq!(scratch.get_depth(&Point::new(3, 2)), Some(0));
+        assert_eq!(scratch.get_depth(&Point::new(1, 1)), None);
+        assert_eq!(scratch.get_color_data()[0], GREEN);
+        assert_eq!(scratch.get_color_data()[11], BLUE);
+    }
+
+    #[test]
+    #[should_panic(expected = "covers too many cells")]
+    fn test_too_big_texture() {
+        Texture::new(Size::new(u32::MAX, 2), BLACK);
+    }
+
+    #[test]
+    fn test_calculate_hash() {
+        let mut texture = Texture::new(Size::new(2, 1), BLUE);
+        let hash = texture.calculate_hash();
+
+        assert_eq!(Texture::new(Size::new(2, 1), BLUE).calculate_hash(), hash);
+        assert_ne!(Texture::new(Size::new(2, 1), RED).calculate_hash(), hash);
+
+        texture.set(&Point::new(1, 0), &BLUE, 10);
+
+        assert_ne!(texture.calculate_hash(), hash);
+    }
+
+    #[test]
+    fn test_set_opaque_color() {
+        let mut texture = Texture::new(Size::new(2, 1), BLUE);
+
+        texture.set(&Point::new(0, 0), &RED, 10);
+
+        assert_eq!(texture.get_color_data(), &vec![RED, BLUE]);
+        assert_eq!(texture.get_depth_data(), &vec![10, 0]);
+    }
+
+    #[test]
+    fn test_set_translucent_color() {
+        let mut texture = Texture::new(Size::new(2, 1), BLUE);
+
+        texture.set(&Point::new(1, 0), &Color::from_rgba(255, 0, 0, 128), 10);
+
+        assert_eq!(
+            texture.get_color_data(),
+            &vec![BLUE, Color::from_rgb(128, 0, 127)]
+        );
+        assert_eq!(texture.get_depth_data(), &vec![0, 10]);
+    }
+
+    #[test]
+    fn test_set_checked_outside() {
+        let mut texture = Texture::new(Size::new(2, 1), BLUE);
+
+        assert!(!texture.set_checked(&Point::new(2, 0), &RED, 10));
+        assert!(!texture.set_checked(&Point::new(0, -1), &RED, 10));
+
+        assert_eq!(texture.get_color_data(), &vec![BLUE, BLUE]);
+        assert_eq!(texture.get_depth_data(), &vec![0, 0]);
+    }
+
+    #[test]
+    fn test_set_checked_inside() {
+        let mut texture = Texture::new(Size::new(2, 1), BLUE);
+
+        assert!(texture.set_checked(&Point::new(1, 0), &RED, 10));
+
+        assert_eq!(texture.get_color_data(), &vec![BLUE, RED]);
+        assert_eq!(texture.get_depth_data(), &vec![0, 10]);
+    }
+
+    #[test]
+    fn test_blit_full_overlap() {
+        let mut texture = Texture::new(Size::new(2, 2), WHITE);
+        let mut other = Texture::new(Size::new(2, 2), RED);
+        other.get_depth_data_mut().copy_from_slice(&[5, 0, 6, 7]);
+
+        texture.blit(&other, Point::new(0, 0));
+
+        assert_eq!(texture.get_color_data(), &vec![RED, WHITE, RED, RED]);
+        assert_eq!(texture.get_depth_data(), &vec![5, 0, 6, 7]);
+    }
+
+    #[test]
+    fn test_blit_partly_outside() {
+        let mut texture = Texture::new(Size::new(3, 3), WHITE);
+        let mut other = Texture::new(Size::new(2, 2), RED);
+        other.get_depth_data_mut().copy_from_slice(&[1, 2, 3, 4]);
+
+        texture.blit(&other, Point::new(2, -1));
+
+        #[rustfmt::skip]
+        let colors = vec![
+            WHITE, WHITE,   RED,
+            WHITE, WHITE, WHITE,
+            WHITE, WHITE, WHITE,
+        ];
+
+        assert_eq!(texture.get_color_data(), &colors);
+        assert_eq!(texture.get_depth_data(), &vec![0, 0, 3, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_blit_completely_outside() {
+        let mut texture = Texture::new(Size::new(2, 2), WHITE);
+        let mut other = Texture::new(Size::new(2, 2), RED);
+        other.get_depth_data_mut().copy_from_slice(&[9; 4]);
+
+        texture.blit(&other, Point::new(-2, 1));
+        texture.blit(&other, Point::new(2, 0));
+
+        assert_eq!(texture.get_color_data(), &vec![WHITE; 4]);
+        assert_eq!(texture.get_depth_data(), &vec![0; 4]);
+    }
+
+    #[test]
+    fn test_blit_respects_depth() {
+        let mut texture = Texture::new(Size::new(2, 1), WHITE);
+        texture.get_depth_data_mut().copy_from_slice(&[10, 10]);
+        let mut other = Texture::new(Size::new(2, 1), RED);
+        other.get_depth_data_mut().copy_from_slice(&[5, 15]);
+
+        texture.blit(&other, Point::new(0, 0));
+
+        assert_eq!(texture.get_color_data(), &vec![WHITE, RED]);
+        assert_eq!(texture.get_depth_data(), &vec![10, 15]);
+    }
+
+    #[test]
+    fn test_get_rows_mut() {
+        let mut texture = Texture::new(Size::new(2, 3), WHITE);
+
+        for mut row in texture.get_rows_mut(1, 5) {
+            let y = row.get_y();
+            row.set(y - 1, &RED, 10);
+        }
+
+        #[rustfmt::skip]
+        let colors = vec![
+            WHITE, WHITE,
+              RED, WHITE,
+            WHITE,   RED,
+        ];
+
+        assert_eq!(texture.get_color_data(), &colors);
+        assert_eq!(texture.get_depth_data(), &vec![0, 0, 10, 0, 0, 10]);
+    }
+
+    fn create_2x3_texture() -> Texture {
+        let mut texture = Texture::new(Size::new(2, 3), WHITE);
+        texture
+            .get_color_data_mut()
+            .copy_from_slice(&[RED, GREEN, BLUE, BLACK, WHITE, RED]);
+        texture
+            .get_depth_data_mut()
+            .copy_from_slice(&[1, 2, 3, 4, 5, 6]);
+        texture
+    }
+
+    #[test]
+    fn test_flip_horizontal() {
+        let mut texture = create_2x3_texture();
+
+        texture.flip_horizontal();
+
+        #[rustfmt::skip]
+        let colors = vec![
+            GREEN,   RED,
+            BLACK,  BLUE,
+              RED, WHITE,
+        ];
+
+        assert_eq!(texture.get_size(), &Size::new(2, 3));
+        assert_eq!(texture.get_color_data(), &colors);
+        assert_eq!(texture.get_depth_data(), &vec![2, 1, 4, 3, 6, 5]);
+    }
+
+    #[test]
+    fn test_flip_vertical() {
+        let mut texture = create_2x3_texture();
+
+        texture.flip_vertical();
+
+        #[rustfmt::skip]
+        let colors = vec![
+            WHITE,   RED,
+             BLUE, BLACK,
+              RED, GREEN,
+        ];
+
+        assert_eq!(texture.get_size(), &Size::new(2, 3));
+        assert_eq!(texture.get_color_data(), &colors);
+        assert_eq!(texture.get_depth_data(), &vec![5, 6, 3, 4, 1, 2]);
+    }
+
+    #[test]
+    fn test_rotate_90_cw() {
+        let mut texture = create_2x3_texture();
+
+        texture.rotate_90_cw();
+
+        #[rustfmt::skip]
+        let colors = vec![
+            WHITE,  BLUE,   RED,
+              RED, BLACK, GREEN,
+        ];
+
+        assert_eq!(texture.get_size(), &Size::new(3, 2));
+        assert_eq!(texture.get_tile_size(), &Size::new(3, 2));
+        assert_eq!(texture.get_color_data(), &colors);
+        assert_eq!(texture.get_depth_data(), &vec![5, 3, 1, 6, 4, 2]);
+    }
+
+    #[test]
+    fn test_rotate_90_cw_clears_occupancy_maps() {
+        let mut texture = Texture::for_tilemap(Size::new(2, 1), Size::new(4, 2), RED);
+        texture.get_occupancy_map_mut(3).enable(1);
+
+        texture.rotate_90_cw();
+
+        assert_eq!(texture.get_size(), &Size::new(2, 8));
+        assert_eq!(
+            texture.get_occupancy_map_mut(3),
+            &OccupancyMap::new(Size::new(1, 2), 3)
+        );
+    }
+}
@@ -1,35 +1,515 @@
 use crate::generation::data::texture::Texture;
-use crate::math::color::convert;
+use crate::generation::process::lighting::calculate_normal_from_heightmap;
+use crate::math::color::{convert, Color};
+use crate::math::size::Size;
+use crate::math::vector3::Vector3;
+use anyhow::Context;
+use std::fs;
+use std::fs::File;
+use std::io::{BufWriter, Result, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+/// The supported formats of the saved images.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ImageFormat {
+    Bmp,
+    Jpeg,
+    Png,
+    Tga,
+}
+
+impl ImageFormat {
+    /// Returns the file extension of the format.
+    ///
+    /// ```
+    ///# use texture_generation::generation::io::ImageFormat;
+    /// assert_eq!(ImageFormat::Jpeg.get_extension(), "jpg");
+    /// ```
+    pub fn get_extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Bmp => "bmp",
+            ImageFormat::Jpeg => "jpg",
+            ImageFormat::Png => "png",
+            ImageFormat::Tga => "tga",
+        }
+    }
+
+    fn get_encoder(&self) -> image::ImageFormat {
+        match self {
+            ImageFormat::Bmp => image::ImageFormat::Bmp,
+            ImageFormat::Jpeg => image::ImageFormat::Jpeg,
+            ImageFormat::Png => image::ImageFormat::Png,
+            ImageFormat::Tga => image::ImageFormat::Tga,
+        }
+    }
+
+    /// Returns the format matching the extension of the path. Defaults to png.
+    fn from_path(path: &str) -> ImageFormat {
+        Path::new(path)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(|extension| extension.parse().ok())
+            .unwrap_or(ImageFormat::Png)
+    }
+}
+
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "bmp" => Ok(ImageFormat::Bmp),
+            "jpeg" | "jpg" => Ok(ImageFormat::Jpeg),
+            "png" => Ok(ImageFormat::Png),
+            "tga" => Ok(ImageFormat::Tga),
+            _ => Err(format!("Unknown image format '{}'", s)),
+        }
+    }
+}
 
 /// Save the color image.
-pub fn save_color_image(texture: &Texture, path: &str) {
+pub fn save_color_image(texture: &Texture, path: &str) -> anyhow::Result<()> {
     info!("Save color to {:?}", path);
 
-    let size = texture.get_size();
     let color_data = convert(texture.get_color_data());
 
-    image::save_buffer(
+    save_buffer(
         path,
         &color_data,
-        size.width(),
-        size.height(),
+        texture.get_size(),
         image::ColorType::Rgb8,
     )
-    .unwrap();
+}
+
+/// Save the luma of the colors as a grayscale image.
+pub fn save_luma_image(texture: &Texture, path: &str) -> anyhow::Result<()> {
+    info!("Save luma to {:?}", path);
+
+    save_buffer(
+        path,
+        &texture.get_luma_data(),
+        texture.get_size(),
+        image::ColorType::L8,
+    )
 }
 
 /// Save the depth image.
-pub fn save_depth_image(texture: &Texture, path: &str) {
+pub fn save_depth_image(texture: &Texture, path: &str) -> anyhow::Result<()> {
     info!("Save depth to {:?}", path);
 
-    let size = texture.get_size();
-
-    image::save_buffer(
+    save_buffer(
         path,
         texture.get_depth_data(),
+        texture.get_size(),
+        image::ColorType::L8,
+    )
+}
+
+/// Saves the buffer in the format matching the extension & creates missing parent directories.
+fn save_buffer(
+    path: &str,
+    data: &[u8],
+    size: &Size,
+    color_type: image::ColorType,
+) -> anyhow::Result<()> {
+    create_parent_dir(Path::new(path))?;
+
+    image::save_buffer_with_format(
+        path,
+        data,
         size.width(),
         size.height(),
-        image::ColorType::L8,
+        color_type,
+        ImageFormat::from_path(path).get_encoder(),
     )
-    .unwrap();
+    .context(format!("Failed to save the image {:?}", path))
+}
+
+/// Creates the missing parent directories of a file.
+fn create_parent_dir(path: &Path) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            fs::create_dir_all(parent)
+                .context(format!("Failed to create the directory {:?}", parent))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Loads an image as grayscale, e.g. to use it as depth.
+pub fn load_grayscale_image(path: &Path) -> anyhow::Result<(Size, Vec<u8>)> {
+    info!("Load grayscale image from {:?}", path);
+
+    let image = image::open(path)
+        .context(format!("Failed to load the image {:?}", path))?
+        .into_luma8();
+    let size = Size::new(image.width(), image.height());
+
+    Ok((size, image.into_raw()))
+}
+
+/// Loads the colors of an image, e.g. to use it as a mask.
+pub fn load_color_image(path: &Path) -> anyhow::Result<(Size, Vec<Color>)> {
+    info!("Load color image from {:?}", path);
+
+    let image = image::open(path)
+        .context(format!("Failed to load the image {:?}", path))?
+        .into_rgb8();
+    let size = Size::new(image.width(), image.height());
+    let colors = image
+        .pixels()
+        .map(|pixel| Color::from_rgb(pixel[0], pixel[1], pixel[2]))
+        .collect();
+
+    Ok((size, colors))
+}
+
+/// Saves the colors & depth as PNG images without holding the whole texture in memory.
+///
+/// `render_block` is called with the first row & the number of rows of each block
+/// and returns a [`Texture`] of that block, which is appended to both images.
+pub fn save_png_streaming<F>(
+    size: &Size,
+    rows_per_block: u32,
+    color_path: &Path,
+    depth_path: &Path,
+    mut render_block: F,
+) -> anyhow::Result<()>
+where
+    F: FnMut(u32, u32) -> Texture,
+{
+    let rows_per_block = rows_per_block.max(1);
+    let mut color_writer = create_png_writer(size, png::ColorType::RGB, color_path)?;
+    let mut depth_writer = create_png_writer(size, png::ColorType::Grayscale, depth_path)?;
+    let mut color_stream = color_writer.stream_writer();
+    let mut depth_stream = depth_writer.stream_writer();
+    let mut start_y = 0;
+
+    while start_y < size.height() {
+        let rows = rows_per_block.min(size.height() - start_y);
+        let block = render_block(start_y, rows);
+
+        color_stream.write_all(&convert(block.get_color_data()))?;
+        depth_stream.write_all(block.get_depth_data())?;
+
+        start_y += rows;
+    }
+
+    color_stream.flush()?;
+    depth_stream.flush()?;
+
+    Ok(())
+}
+
+fn create_png_writer(
+    size: &Size,
+    color_type: png::ColorType,
+    path: &Path,
+) -> anyhow::Result<png::Writer<BufWriter<File>>> {
+    info!("Stream image to {:?}", path);
+
+    let file = File::create(path)?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), size.width(), size.height());
+    encoder.set_color(color_type);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    Ok(encoder.write_header()?)
+}
+
+/// Save the normal map derived from the depth.
+pub fn save_normal_image(texture: &Texture, path: &str, normal_z: f32) -> anyhow::Result<()> {
+    info!("Save normals to {:?}", path);
+
+    let size = texture.get_size();
+    let normals = calculate_normals(size, texture.get_depth_data(), normal_z);
+    let normal_data = convert_normals(&normals);
+
+    save_buffer(path, &normal_data, size, image::ColorType::Rgb8)
+}
+
+/// Exports the depth as a grid mesh in the OBJ format with a vertex per pixel.
+///
+/// The z value of each vertex is its depth multiplied by `scale`.
+/// The vertex colors are taken from the color buffer.
+pub fn export_obj(texture: &Texture, scale: f32, path: &Path) -> anyhow::Result<()> {
+    info!("Export mesh to {:?}", path);
+
+    create_parent_dir(path)?;
+
+    let file = File::create(path).context(format!("Failed to create the mesh {:?}", path))?;
+    let mut writer = BufWriter::new(file);
+    write_obj(texture, scale, &mut writer)
+        .and_then(|_| writer.flush())
+        .context(format!("Failed to export the mesh {:?}", path))
+}
+
+/// Writes the depth as a grid mesh in the OBJ format with 2 triangles between 4 neighboring pixels.
+pub fn write_obj<W: Write>(texture: &Texture, scale: f32, writer: &mut W) -> Result<()> {
+    let size = texture.get_size();
+    let depth = texture.get_depth_data();
+    let colors = texture.get_color_data();
+
+    for y in 0..size.height() {
+        for x in 0..size.width() {
+            let index = size.convert_x_y(x, y);
+            let color = colors[index];
+
+            writeln!(
+                writer,
+                "v {} {} {} {} {} {}",
+                x,
+                y,
+                depth[index] as f32 * scale,
+                color.r() as f32 / 255.0,
+                color.g() as f32 / 255.0,
+                color.b() as f32 / 255.0,
+            )?;
+        }
+    }
+
+    let width = size.width() as usize;
+
+    for y in 1..size.height() {
+        for x in 1..size.width() {
+            // OBJ indices start at 1
+            let top_left = size.convert_x_y(x - 1, y - 1) + 1;
+            let top_right = top_left + 1;
+            let bottom_left = top_left + width;
+            let bottom_right = bottom_left + 1;
+
+            writeln!(writer, "f {} {} {}", top_left, bottom_left, top_right)?;
+            writeln!(writer, "f {} {} {}", top_right, bottom_left, bottom_right)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Calculates the normal of each pixel from the depth like
+/// [`Lighting`](crate::generation::process::lighting::Lighting).
+///
+/// Its normals point up the slope, so their x & y are inverted for the normal map.
+/// The x-axis points to the right & the y-axis points down.
+pub fn calculate_normals(size: &Size, depth: &[u8], normal_z: f32) -> Vec<Vector3> {
+    let mut normals = Vec::with_capacity(size.len());
+
+    for y in 0..size.height() {
+        for x in 0..size.width() {
+            let normal = calculate_normal_from_heightmap(size, depth, x, y, normal_z);
+            normals.push(Vector3::new(-normal.x, -normal.y, normal.z));
+        }
+    }
+
+    normals
+}
+
+/// Converts normals to RGB values by mapping each axis from [-1,1] to [0,255].
+pub fn convert_normals(normals: &[Vector3]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(normals.len() * 3);
+
+    for normal in normals {
+        data.push(convert_axis(normal.x));
+        data.push(convert_axis(normal.y));
+        data.push(convert_axis(normal.z));
+    }
+
+    data
+}
+
+fn convert_axis(value: f32) -> u8 {
+    ((value * 0.5 + 0.5) * 255.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::color::{RED, WHITE};
+    use crate::math::point::Point;
+    use assert_approx_eq::assert_approx_eq;
+    use image::GenericImageView;
+    use std::f32::consts::FRAC_1_SQRT_2;
+
+    #[test]
+    fn test_normals_of_flat_depth() {
+        let size = Size::new(3, 2);
+        let normals = calculate_normals(&size, &[50; 6], 10.0);
+
+        assert_eq!(normals, vec![Vector3::new(0.0, 0.0, 1.0); 6]);
+        assert_eq!(convert_normals(&normals[0..1]), vec![127, 127, 255]);
+    }
+
+    #[test]
+    fn test_normals_of_ramp() {
+        let size = Size::new(4, 3);
+        #[rustfmt::skip]
+        let depth = vec![
+            0, 10, 20, 30,
+            0, 10, 20, 30,
+            0, 10, 20, 30,
+        ];
+
+        let normals = calculate_normals(&size, &depth, 20.0);
+        let normal = normals[size.convert_x_y(1, 1)];
+
+        assert_approx_eq!(normal.x, -FRAC_1_SQRT_2);
+        assert_approx_eq!(normal.y, 0.0);
+        assert_approx_eq!(normal.z, FRAC_1_SQRT_2);
+
+        let rgb = convert_normals(&[normal]);
+
+        assert!(rgb[0] < 127);
+        assert_eq!(rgb[1], 127);
+        assert!(rgb[2] < 255);
+    }
+
+    #[test]
+    fn test_write_obj() {
+        let mut texture = Texture::new(Size::square(2), WHITE);
+        texture.set(&Point::new(1, 1), &RED, 100);
+        let mut output = Vec::new();
+
+        write_obj(&texture, 0.5, &mut output).unwrap();
+
+        let string = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = string.lines().collect();
+
+        assert_eq!(lines.iter().filter(|l| l.starts_with("v ")).count(), 4);
+        assert_eq!(lines.iter().filter(|l| l.starts_with("f ")).count(), 2);
+        assert_eq!(lines[0], "v 0 0 0 1 1 1");
+        assert_eq!(lines[3], "v 1 1 50 1 0 0");
+        assert_eq!(lines[4], "f 1 3 2");
+        assert_eq!(lines[5], "f 2 3 4");
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("bmp".parse(), Ok(ImageFormat::Bmp));
+        assert_eq!("JPEG".parse(), Ok(ImageFormat::Jpeg));
+        assert_eq!("jpg".parse(), Ok(ImageFormat::Jpeg));
+        assert_eq!("png".parse(), Ok(ImageFormat::Png));
+        assert_eq!("tga".parse(), Ok(ImageFormat::Tga));
+        assert!("gif".parse::<ImageFormat>().is_err());
+    }
+
+    #[test]
+    fn test_extension_matches_format() {
+        for format in &FORMATS {
+            let extension = format.get_extension();
+
+            assert_eq!(extension.parse(), Ok(*format));
+            assert_eq!(
+                ImageFormat::from_path(&format!("a/b.{}", extension)),
+                *format
+            );
+        }
+    }
+
+    #[test]
+    fn test_save_and_decode() {
+        let texture = Texture::new(Size::new(3, 2), RED);
+
+        for format in &FORMATS {
+            let path = std::env::temp_dir().join(format!("io_test.{}", format.get_extension()));
+            let path = path.to_str().unwrap();
+
+            save_color_image(&texture, path).unwrap();
+            let color_image = image::open(path).unwrap();
+            save_depth_image(&texture, path).unwrap();
+            let depth_image = image::open(path).unwrap();
+
+            assert_eq!(color_image.width(), 3);
+            assert_eq!(color_image.height(), 2);
+            assert_eq!(depth_image.width(), 3);
+            assert_eq!(depth_image.height(), 2);
+        }
+    }
+
+    #[test]
+    fn test_load_grayscale_image() {
+        let mut texture = Texture::new(Size::new(3, 1), RED);
+        texture.get_depth_data_mut().copy_from_slice(&[0, 100, 200]);
+        let path = std::env::temp_dir().join("io_test_grayscale.png");
+
+        save_depth_image(&texture, path.to_str().unwrap()).unwrap();
+
+        assert_eq!(
+            load_grayscale_image(&path).unwrap(),
+            (Size::new(3, 1), vec![0, 100, 200])
+        );
+    }
+
+    #[test]
+    fn test_load_color_image() {
+        let texture = Texture::new(Size::new(2, 1), RED);
+        let path = std::env::temp_dir().join("io_test_color.png");
+
+        save_color_image(&texture, path.to_str().unwrap()).unwrap();
+
+        assert_eq!(
+            load_color_image(&path).unwrap(),
+            (Size::new(2, 1), vec![RED, RED])
+        );
+    }
+
+    #[test]
+    fn test_load_missing_grayscale_image() {
+        assert!(load_grayscale_image(Path::new("missing_heightmap.png")).is_err());
+    }
+
+    #[test]
+    fn test_save_creates_missing_directories() {
+        let texture = Texture::new(Size::new(3, 2), RED);
+        let dir = std::env::temp_dir().join("io_test_missing");
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("a").join("b").join("color.png");
+        let path = path.to_str().unwrap();
+
+        save_color_image(&texture, path).unwrap();
+
+        assert!(Path::new(path).exists());
+    }
+
+    #[test]
+    fn test_save_to_invalid_path() {
+        let texture = Texture::new(Size::new(3, 2), RED);
+        let file = std::env::temp_dir().join("io_test_file.png");
+        save_color_image(&texture, file.to_str().unwrap()).unwrap();
+        let path = file.join("color.png");
+
+        assert!(save_color_image(&texture, path.to_str().unwrap()).is_err());
+        assert!(save_depth_image(&texture, path.to_str().unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_export_obj_creates_missing_directories() {
+        let texture = Texture::new(Size::new(3, 2), RED);
+        let dir = std::env::temp_dir().join("io_test_obj_missing");
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("a").join("mesh.obj");
+
+        export_obj(&texture, 0.5, &path).unwrap();
+
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn test_export_obj_to_invalid_path() {
+        let texture = Texture::new(Size::new(3, 2), RED);
+        let file = std::env::temp_dir().join("io_test_obj_file.obj");
+        export_obj(&texture, 0.5, &file).unwrap();
+        let path = file.join("mesh.obj");
+
+        let error = export_obj(&texture, 0.5, &path).unwrap_err();
+
+        assert!(format!("{:?}", error).contains("io_test_obj_file.obj"));
+    }
+
+    const FORMATS: [ImageFormat; 4] = [
+        ImageFormat::Bmp,
+        ImageFormat::Jpeg,
+        ImageFormat::Png,
+        ImageFormat::Tga,
+    ];
 }
@@ -1,17 +1,26 @@
 use crate::generation::component::rendering::RenderingComponent;
 use crate::generation::component::Component;
+use crate::generation::io::save_png_streaming;
+use crate::generation::process::PostProcess;
+use crate::generation::stats::{count_covered_pixels, GenerationStats};
 use crate::math::aabb::AABB;
 use crate::math::color::{Color, PINK};
+use crate::math::point::Point;
 use crate::math::size::Size;
 use crate::utils::resource::Resource;
+use anyhow::{bail, Result};
 use data::texture::Texture;
 use data::Data;
+use std::path::Path;
+use std::time::Instant;
 
+pub mod atlas;
 pub mod component;
 pub mod data;
 pub mod io;
 pub mod process;
 pub mod random;
+pub mod stats;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct TextureGenerator {
@@ -36,10 +45,97 @@ impl TextureGenerator {
         }
     }
 
+    /// Gets the [`Size`] of the generated texture.
+    pub fn get_size(&self) -> &Size {
+        &self.size
+    }
+
+    /// Gets the [`Color`] of the background.
+    pub fn get_background(&self) -> &Color {
+        &self.background
+    }
+
     /// Generates the texture with a specific size.
     pub fn generate(&self) -> Texture {
+        self.generate_with_dithering(false)
+    }
+
+    /// Generates the texture like [`TextureGenerator::generate()`], but can dither the depth values.
+    pub fn generate_with_dithering(&self, dithering: bool) -> Texture {
+        self.generate_with_base_depth(0, dithering)
+    }
+
+    /// Generates the texture, applies the post processes & measures both.
+    pub fn generate_with_stats(
+        &self,
+        dithering: bool,
+        post_processes: &[PostProcess],
+    ) -> (Texture, GenerationStats) {
+        let start = Instant::now();
+        let mut texture = self.generate_with_dithering(dithering);
+        let generation = start.elapsed();
+        let covered_pixels = count_covered_pixels(&texture);
+
+        let start = Instant::now();
+        texture.apply(post_processes);
+        let post_processing = start.elapsed();
+
+        let stats = GenerationStats {
+            generation,
+            post_processing,
+            covered_pixels,
+        };
+
+        (texture, stats)
+    }
+
+    /// Generates the texture like [`TextureGenerator::generate_with_dithering()`],
+    /// but adds the base depth to all rendered depth values.
+    pub fn generate_with_base_depth(&self, base_depth: u8, dithering: bool) -> Texture {
         let aabb = AABB::with_size(self.size);
-        let mut texture = Texture::new(self.size, self.background);
+        let mut texture = Texture::with_depth(self.size, self.background, base_depth);
+        texture.set_dithering(dithering);
+        let data = Data::for_texture(aabb);
+
+        self.component.generate(&mut texture, &data);
+
+        texture
+    }
+
+    /// Generates the texture & saves it as PNG images block by block,
+    /// so that very large textures never need to be in memory at once.
+    ///
+    /// Fails for components & post processes that are not pixel-local.
+    pub fn generate_streaming(
+        &self,
+        color_path: &Path,
+        depth_path: &Path,
+        rows_per_block: u32,
+        post_processes: &[PostProcess],
+    ) -> Result<()> {
+        if !self.component.is_streamable() {
+            bail!("The texture '{}' can't be streamed", self.name);
+        } else if let Some(post_process) = post_processes.iter().find(|p| !p.is_pixel_local()) {
+            bail!("The post process {:?} can't be streamed", post_process);
+        }
+
+        save_png_streaming(
+            &self.size,
+            rows_per_block,
+            color_path,
+            depth_path,
+            |start_y, rows| {
+                let mut texture = self.generate_rows(start_y, rows);
+                texture.apply(post_processes);
+                texture
+            },
+        )
+    }
+
+    /// Generates only some rows of the texture by shifting the [`AABB`] of the whole texture.
+    fn generate_rows(&self, start_y: u32, rows: u32) -> Texture {
+        let aabb = AABB::new(Point::new(0, -(start_y as i32)), self.size);
+        let mut texture = Texture::new(Size::new(self.size.width(), rows), self.background);
         let data = Data::for_texture(aabb);
 
         self.component.generate(&mut texture, &data);
@@ -71,8 +167,14 @@ impl Resource for TextureGenerator {
 #[cfg(test)]
 mod tests {
     use crate::generation::component::border::BorderComponent;
+    use crate::generation::component::layout::repeat::RepeatLayout;
+    use crate::generation::component::layout::LayoutComponent;
+    use crate::generation::component::outline::OutlineComponent;
+    use crate::generation::component::rendering::color::factory::ColorFactory;
+    use crate::generation::component::rendering::depth_factory::DepthFactory;
     use crate::generation::component::rendering::RenderingComponent;
-    use crate::math::color::{GREEN, RED};
+    use crate::math::color::{convert, BLUE, GREEN, RED};
+    use crate::math::shape_factory::ShapeFactory;
 
     use super::*;
 
@@ -99,4 +201,80 @@ mod tests {
 
         assert_eq!(data.get_color_data(), &result);
     }
+
+    #[test]
+    fn test_stats_of_full_fill() {
+        let rendering = RenderingComponent::new_fill_area(RED, 10);
+        let component = Component::Rendering(Box::new(rendering));
+        let generator = TextureGenerator::new("test", Size::new(5, 7), GREEN, component);
+
+        let (texture, stats) = generator.generate_with_stats(false, &[PostProcess::Mock(1)]);
+
+        assert_eq!(stats.covered_pixels, 5 * 7);
+        assert_eq!(stats.total(), stats.generation + stats.post_processing);
+        assert_eq!(texture.get_color_data(), &vec![RED; 35]);
+    }
+
+    #[test]
+    fn test_stats_of_partial_fill() {
+        let rendering = RenderingComponent::new_fill_area(RED, 200);
+        let border = BorderComponent::new_uniform(1, Component::Rendering(Box::new(rendering)));
+        let component = Component::Border(Box::new(border));
+        let generator = TextureGenerator::new("test", Size::new(5, 7), GREEN, component);
+
+        let (_texture, stats) = generator.generate_with_stats(false, &[]);
+
+        assert_eq!(stats.covered_pixels, 3 * 5);
+    }
+
+    #[test]
+    fn test_streamed_image_matches_in_memory() {
+        let color = ColorFactory::Sequence(vec![RED, GREEN, BLUE]);
+        let depth = DepthFactory::new_dome(200, 100);
+        let shape = RenderingComponent::new_shape_with_depth(ShapeFactory::Circle, color, depth);
+        let row = RepeatLayout::new(true, 5, Component::Rendering(Box::new(shape))).unwrap();
+        let row = Component::Layout(Box::new(LayoutComponent::Repeat(row)));
+        let border = BorderComponent::new_uniform(1, row);
+        let component = Component::Border(Box::new(border));
+        let generator = TextureGenerator::new("test", Size::new(22, 17), GREEN, component);
+        let dir = std::env::temp_dir();
+        let color_path = dir.join("streaming_test-color.png");
+        let depth_path = dir.join("streaming_test-depth.png");
+
+        generator
+            .generate_streaming(&color_path, &depth_path, 4, &[])
+            .unwrap();
+
+        let texture = generator.generate();
+        let color_image = image::open(&color_path).unwrap().to_rgb8();
+        let depth_image = image::open(&depth_path).unwrap().to_luma8();
+
+        assert_eq!(color_image.into_raw(), convert(texture.get_color_data()));
+        assert_eq!(depth_image.into_raw(), texture.get_depth_data().to_vec());
+    }
+
+    #[test]
+    fn test_outline_is_not_streamable() {
+        let rendering = RenderingComponent::new_fill_area(RED, 200);
+        let outline = OutlineComponent::new(1, BLUE, Component::Rendering(Box::new(rendering)));
+        let component = Component::Outline(Box::new(outline));
+        let generator = TextureGenerator::new("test", Size::new(5, 7), GREEN, component);
+        let path = std::env::temp_dir().join("streaming_outline.png");
+
+        assert!(generator.generate_streaming(&path, &path, 4, &[]).is_err());
+    }
+
+    #[test]
+    fn test_grain_is_not_streamable() {
+        let generator = TextureGenerator::default();
+        let grain = PostProcess::Grain {
+            amount: 10,
+            seed: 42,
+        };
+        let path = std::env::temp_dir().join("streaming_grain.png");
+
+        assert!(generator
+            .generate_streaming(&path, &path, 4, &[grain])
+            .is_err());
+    }
 }
@@ -29,7 +29,7 @@ impl AmbientOcclusion {
         blur_left_up(size, &mut depth, self.step);
 
         for y in 0..size.height() {
-            info!("Line {}/{}", y + 1, size.height());
+            trace!("Line {}/{}", y + 1, size.height());
 
             let mut index = size.convert_x_y(0, y);
 
@@ -0,0 +1,69 @@
+use crate::generation::data::texture::Texture;
+
+/// Highlights & shadows each pixel based on the derivative of the depth along a direction.
+/// The `angle` of the direction is in degrees & 0 is along the x-axis.
+pub fn emboss(data: &mut Texture, angle: f32, strength: f32) {
+    info!("Post Processing: Emboss");
+
+    let size = *data.get_size();
+    let radians = angle.to_radians();
+    let direction_x = radians.cos();
+    let direction_y = radians.sin();
+    let depth = data.get_depth_data().to_owned();
+    let mut index = 0;
+
+    for y in 0..size.height() {
+        for x in 0..size.width() {
+            let index_left = size.convert_x_y(if x > 0 { x - 1 } else { x }, y);
+            let index_right = size.convert_x_y(if x < size.width() - 1 { x + 1 } else { x }, y);
+            let index_down = size.convert_x_y(x, if y > 0 { y - 1 } else { y });
+            let index_up = size.convert_x_y(x, if y < size.height() - 1 { y + 1 } else { y });
+
+            let diff_x = depth[index_right] as f32 - depth[index_left] as f32;
+            let diff_y = depth[index_up] as f32 - depth[index_down] as f32;
+            let derivative = diff_x * direction_x + diff_y * direction_y;
+
+            data.get_color_data_mut()[index] *= 1.0 + strength * derivative / 255.0;
+
+            index += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::color::Color;
+    use crate::math::point::Point;
+    use crate::math::size::Size;
+
+    #[test]
+    fn test_bright_then_dark() {
+        let gray = Color::gray(100);
+        let mut texture = Texture::new(Size::new(6, 1), gray);
+        texture.set(&Point::new(2, 0), &gray, 100);
+        texture.set(&Point::new(3, 0), &gray, 100);
+
+        emboss(&mut texture, 0.0, 1.0);
+
+        let bright = Color::gray(139);
+        let dark = Color::gray(60);
+
+        assert_eq!(
+            texture.get_color_data(),
+            &vec![gray, bright, bright, dark, dark, gray]
+        );
+    }
+
+    #[test]
+    fn test_edge_parallel_to_angle() {
+        let gray = Color::gray(100);
+        let mut texture = Texture::new(Size::new(6, 1), gray);
+        texture.set(&Point::new(2, 0), &gray, 100);
+        texture.set(&Point::new(3, 0), &gray, 100);
+
+        emboss(&mut texture, 90.0, 1.0);
+
+        assert_eq!(texture.get_color_data(), &vec![gray; 6]);
+    }
+}
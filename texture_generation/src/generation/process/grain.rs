@@ -0,0 +1,113 @@
+use crate::generation::data::texture::Texture;
+use crate::math::color::Color;
+use crate::utils::hash::StableHasher;
+
+/// Adds deterministic noise between `-amount` & `amount` to each channel of each pixel.
+pub fn add_grain(data: &mut Texture, amount: u8, seed: u64) {
+    info!("Post Processing: Grain");
+
+    if amount == 0 {
+        return;
+    }
+
+    let size = *data.get_size();
+
+    for (index, color) in data.get_color_data_mut().iter_mut().enumerate() {
+        let x = size.to_x(index);
+        let y = size.to_y(index);
+
+        *color = Color::from_rgba(
+            add_noise(color.r(), amount, seed, x, y, 0),
+            add_noise(color.g(), amount, seed, x, y, 1),
+            add_noise(color.b(), amount, seed, x, y, 2),
+            color.a(),
+        );
+    }
+}
+
+fn add_noise(value: u8, amount: u8, seed: u64, x: i32, y: i32, channel: u8) -> u8 {
+    let range = 2 * amount as u64 + 1;
+    let noise = (hash(seed, x, y, channel) % range) as i32 - amount as i32;
+
+    (value as i32 + noise).clamp(0, 255) as u8
+}
+
+/// Calculates a hash, which is stable across runs & platforms.
+fn hash(seed: u64, x: i32, y: i32, channel: u8) -> u64 {
+    let mut hasher = StableHasher::new();
+
+    hasher.write(&seed.to_le_bytes());
+    hasher.write(&x.to_le_bytes());
+    hasher.write(&y.to_le_bytes());
+    hasher.write(&[channel]);
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::size::Size;
+
+    const SIZE: Size = Size::new(4, 3);
+    const GRAY: Color = Color::gray(128);
+
+    #[test]
+    fn test_same_seed_is_identical() {
+        let mut texture0 = Texture::new(SIZE, GRAY);
+        let mut texture1 = Texture::new(SIZE, GRAY);
+
+        add_grain(&mut texture0, 20, 42);
+        add_grain(&mut texture1, 20, 42);
+
+        assert_eq!(texture0.get_color_data(), texture1.get_color_data());
+        assert_ne!(texture0.get_color_data(), &vec![GRAY; SIZE.len()]);
+    }
+
+    #[test]
+    fn test_different_seeds() {
+        let mut texture0 = Texture::new(SIZE, GRAY);
+        let mut texture1 = Texture::new(SIZE, GRAY);
+
+        add_grain(&mut texture0, 20, 42);
+        add_grain(&mut texture1, 20, 43);
+
+        assert_ne!(texture0.get_color_data(), texture1.get_color_data());
+    }
+
+    #[test]
+    fn test_zero_amount_is_no_op() {
+        let mut texture = Texture::new(SIZE, GRAY);
+
+        add_grain(&mut texture, 0, 42);
+
+        assert_eq!(texture.get_color_data(), &vec![GRAY; SIZE.len()]);
+    }
+
+    #[test]
+    fn test_alpha_is_preserved() {
+        let color = Color::from_rgba(128, 128, 128, 77);
+        let mut texture = Texture::new(SIZE, color);
+
+        add_grain(&mut texture, 20, 42);
+
+        assert!(texture.get_color_data().iter().all(|c| c.a() == 77));
+    }
+
+    #[test]
+    fn test_hash_is_stable() {
+        assert_eq!(hash(0, 0, 0, 0), 0x4dfa_4cff_d1f7_979f);
+        assert_eq!(hash(0, 0, 0, 1), 0x4dfa_4bff_d1f7_95ec);
+        assert_ne!(hash(0, 1, 0, 0), hash(0, 0, 1, 0));
+    }
+
+    #[test]
+    fn test_noise_is_clamped() {
+        for x in 0..10 {
+            for y in 0..10 {
+                assert!(add_noise(250, 20, 1, x, y, 0) >= 230);
+                assert!(add_noise(5, 20, 1, x, y, 0) <= 25);
+            }
+        }
+    }
+}
@@ -1,33 +1,88 @@
 use crate::generation::data::texture::Texture;
+use crate::math::color::{Color, WHITE};
 use crate::math::size::Size;
 use crate::math::vector3::Vector3;
 
+/// A directional light with a [`Color`] & an intensity.
 #[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Light {
+    direction: Vector3,
+    color: Color,
+    intensity: f32,
+}
+
+impl Light {
+    pub fn new(mut direction: Vector3, color: Color, intensity: f32) -> Light {
+        direction.normalize();
+        Light {
+            direction,
+            color,
+            intensity,
+        }
+    }
+
+    /// Returns a white light with intensity 1.
+    pub fn white(direction: Vector3) -> Light {
+        Self::new(direction, WHITE, 1.0)
+    }
+}
+
+/// The parameters of the specular highlight of the Blinn-Phong model.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SpecularParams {
+    shininess: f32,
+    intensity: f32,
+}
+
+impl SpecularParams {
+    pub fn new(shininess: f32, intensity: f32) -> SpecularParams {
+        SpecularParams {
+            shininess,
+            intensity,
+        }
+    }
+}
+
+/// Lights the texture with the Blinn-Phong model & normals derived from the depth.
+#[derive(Clone, Debug, PartialEq)]
 pub struct Lighting {
-    light_direction: Vector3,
+    lights: Vec<Light>,
     normal_z: f32,
-    shininess: i32,
+    /// Is added to the light of each pixel.
+    ambient: f32,
+    specular: Option<SpecularParams>,
 }
 
 impl Lighting {
-    pub fn new(mut light_direction: Vector3, normal_z: u32, shininess: u32) -> Lighting {
-        light_direction.normalize();
+    pub fn new(
+        lights: Vec<Light>,
+        normal_z: u32,
+        ambient: f32,
+        specular: Option<SpecularParams>,
+    ) -> Lighting {
         Lighting {
-            light_direction,
+            lights,
             normal_z: normal_z as f32,
-            shininess: shininess as i32,
+            ambient,
+            specular,
         }
     }
 
     pub fn process(&self, data: &mut Texture) {
-        info!("Post Processing: Lighting");
+        info!(
+            "Post Processing: Lighting with {} lights",
+            self.lights.len()
+        );
 
         let size = *data.get_size();
         let mut index = 0;
-        let ambient = 0.1;
 
         let view_direction = Vector3::new(0.0, 0.0, 1.0);
-        let half_direction = (view_direction + self.light_direction).get_normalized();
+        let half_directions: Vec<Vector3> = self
+            .lights
+            .iter()
+            .map(|light| (view_direction + light.direction).get_normalized())
+            .collect();
 
         for y in 0..size.height() {
             for x in 0..size.width() {
@@ -38,11 +93,32 @@ impl Lighting {
                     y,
                     self.normal_z,
                 );
+                let mut factors = [self.ambient; 3];
+
+                for (light, half_direction) in self.lights.iter().zip(half_directions.iter()) {
+                    let diffuse = light.direction.dot(&normal).max(0.0);
+                    let specular = self.specular.map_or(0.0, |specular| {
+                        half_direction
+                            .dot(&normal)
+                            .max(0.0)
+                            .powf(specular.shininess)
+                            * specular.intensity
+                    });
+                    let contribution = (diffuse + specular) * light.intensity / 255.0;
 
-                let diffuse = self.light_direction.dot(&normal).max(0.0);
-                let specular = half_direction.dot(&normal).max(0.0).powi(self.shininess);
+                    factors[0] += contribution * light.color.r() as f32;
+                    factors[1] += contribution * light.color.g() as f32;
+                    factors[2] += contribution * light.color.b() as f32;
+                }
 
-                data.get_color_data_mut()[index] *= ambient + diffuse + specular;
+                let color = data.get_color_data()[index];
+
+                data.get_color_data_mut()[index] = Color::from_rgba(
+                    apply_factor(color.r(), factors[0]),
+                    apply_factor(color.g(), factors[1]),
+                    apply_factor(color.b(), factors[2]),
+                    color.a(),
+                );
 
                 index += 1;
             }
@@ -50,6 +126,10 @@ impl Lighting {
     }
 }
 
+fn apply_factor(value: u8, factor: f32) -> u8 {
+    (value as f32 * factor).clamp(0.0, 255.0) as u8
+}
+
 pub fn calculate_normal_from_heightmap(
     size: &Size,
     depth: &[u8],
@@ -82,3 +162,103 @@ pub fn calculate_normal(
     let diff_y = depth_up as f32 - depth_down as f32;
     Vector3::new(diff_x, diff_y, normal_z).get_normalized()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::point::Point;
+
+    const GRAY: Color = Color::gray(100);
+    const AMBIENT: Color = Color::gray(10);
+    const SPECULAR: Option<SpecularParams> = Some(SpecularParams {
+        shininess: 32.0,
+        intensity: 1.0,
+    });
+
+    #[test]
+    fn test_one_light_lights_one_side_of_bump() {
+        let mut texture = create_bump();
+        let lighting = Lighting::new(
+            vec![Light::white(Vector3::new(1.0, 0.0, 1.0))],
+            10,
+            0.1,
+            SPECULAR,
+        );
+
+        lighting.process(&mut texture);
+
+        assert!(texture.get_color_data()[1].r() > AMBIENT.r());
+        assert_eq!(texture.get_color_data()[3], AMBIENT);
+    }
+
+    #[test]
+    fn test_opposing_lights_light_both_sides_of_bump() {
+        let mut texture = create_bump();
+        let lights = vec![
+            Light::white(Vector3::new(1.0, 0.0, 1.0)),
+            Light::white(Vector3::new(-1.0, 0.0, 1.0)),
+        ];
+        let lighting = Lighting::new(lights, 10, 0.1, SPECULAR);
+
+        lighting.process(&mut texture);
+
+        assert!(texture.get_color_data()[1].r() > AMBIENT.r());
+        assert!(texture.get_color_data()[3].r() > AMBIENT.r());
+    }
+
+    #[test]
+    fn test_colored_light() {
+        let mut texture = Texture::new(Size::new(1, 1), GRAY);
+        let light = Light::new(Vector3::new(0.0, 0.0, 1.0), Color::from_rgb(255, 0, 0), 1.0);
+        let lighting = Lighting::new(vec![light], 10, 0.1, SPECULAR);
+
+        lighting.process(&mut texture);
+
+        let color = texture.get_color_data()[0];
+        assert!(color.r() > 200);
+        assert_eq!(color.g(), AMBIENT.g());
+        assert_eq!(color.b(), AMBIENT.b());
+    }
+
+    #[test]
+    fn test_alpha_is_preserved() {
+        let mut texture = Texture::new(Size::new(1, 1), Color::from_rgba(100, 100, 100, 77));
+        let light = Light::white(Vector3::new(0.0, 0.0, 1.0));
+
+        Lighting::new(vec![light], 10, 0.1, SPECULAR).process(&mut texture);
+
+        assert_eq!(texture.get_color_data()[0].a(), 77);
+    }
+
+    #[test]
+    fn test_ambient_raises_dark_areas() {
+        let light = Light::white(Vector3::new(1.0, 0.0, 1.0));
+        let mut without_ambient = create_bump();
+        let mut with_ambient = create_bump();
+
+        Lighting::new(vec![light], 10, 0.0, None).process(&mut without_ambient);
+        Lighting::new(vec![light], 10, 0.2, None).process(&mut with_ambient);
+
+        assert_eq!(without_ambient.get_color_data()[3], Color::gray(0));
+        assert_eq!(with_ambient.get_color_data()[3], Color::gray(20));
+    }
+
+    #[test]
+    fn test_highlight_facing_the_light() {
+        let light = Light::new(Vector3::new(0.0, 0.0, 1.0), WHITE, 0.5);
+        let mut diffuse_only = Texture::new(Size::new(1, 1), GRAY);
+        let mut with_highlight = Texture::new(Size::new(1, 1), GRAY);
+
+        Lighting::new(vec![light], 10, 0.0, None).process(&mut diffuse_only);
+        Lighting::new(vec![light], 10, 0.0, SPECULAR).process(&mut with_highlight);
+
+        assert_eq!(diffuse_only.get_color_data()[0], Color::gray(50));
+        assert!(with_highlight.get_color_data()[0].r() > 90);
+    }
+
+    fn create_bump() -> Texture {
+        let mut texture = Texture::new(Size::new(5, 1), GRAY);
+        texture.set(&Point::new(2, 0), &GRAY, 100);
+        texture
+    }
+}
@@ -1,23 +1,78 @@
 use crate::generation::data::texture::Texture;
 use crate::generation::process::ambient_occlusion::AmbientOcclusion;
+use crate::generation::process::emboss::emboss;
+use crate::generation::process::grain::add_grain;
 use crate::generation::process::lighting::Lighting;
+use crate::generation::process::normalize_depth::normalize_depth;
+use crate::generation::process::sharpen::sharpen;
+use crate::generation::process::tileable::make_tileable;
+use crate::generation::process::tint::tint;
+use crate::math::color::Color;
 
 pub mod ambient_occlusion;
+pub mod emboss;
+pub mod grain;
 pub mod lighting;
+pub mod normalize_depth;
+pub mod sharpen;
+pub mod tileable;
+pub mod tint;
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum PostProcess {
     AmbientOcclusion(AmbientOcclusion),
+    /// Highlights & shadows pixels based on the derivative of the depth in the direction of `angle`.
+    Emboss {
+        angle: f32,
+        strength: f32,
+    },
+    /// Adds deterministic noise to each pixel.
+    Grain {
+        amount: u8,
+        seed: u64,
+    },
     Lighting(Lighting),
+    /// Cross-fades the opposite edges, so that the texture tiles without seams.
+    MakeTileable {
+        blend: u32,
+    },
     Mock(u8),
+    /// Rescales the depth buffer's actual minimum & maximum to the target range.
+    NormalizeDepth {
+        target_min: u8,
+        target_max: u8,
+    },
+    /// Sharpens the colors with an unsharp mask.
+    Sharpen {
+        amount: f32,
+    },
+    /// Blends each pixel towards a [`Color`] by a factor.
+    Tint {
+        color: Color,
+        factor: f32,
+    },
 }
 
 impl PostProcess {
+    /// Returns true, if each pixel is processed independent of its neighbors & position.
+    pub fn is_pixel_local(&self) -> bool {
+        matches!(self, PostProcess::Mock(_) | PostProcess::Tint { .. })
+    }
+
     pub fn process(&self, data: &mut Texture) {
         match self {
             PostProcess::AmbientOcclusion(occlusion) => occlusion.process(data),
+            PostProcess::Emboss { angle, strength } => emboss(data, *angle, *strength),
+            PostProcess::Grain { amount, seed } => add_grain(data, *amount, *seed),
             PostProcess::Lighting(lighting) => lighting.process(data),
+            PostProcess::MakeTileable { blend } => make_tileable(data, *blend),
             PostProcess::Mock(id) => info!("Process mock {}", *id),
+            PostProcess::NormalizeDepth {
+                target_min,
+                target_max,
+            } => normalize_depth(data, *target_min, *target_max),
+            PostProcess::Sharpen { amount } => sharpen(data, *amount),
+            PostProcess::Tint { color, factor } => tint(data, color, *factor),
         }
     }
 }
@@ -0,0 +1,61 @@
+use crate::generation::data::texture::Texture;
+
+/// Rescales the depth buffer, so that its minimum & maximum match the target range.
+/// A depth buffer with a single depth value is unchanged.
+pub fn normalize_depth(data: &mut Texture, target_min: u8, target_max: u8) {
+    info!("Post Processing: Normalize Depth");
+
+    let depth_data = data.get_depth_data_mut();
+    let min = depth_data.iter().copied().min().unwrap_or_default();
+    let max = depth_data.iter().copied().max().unwrap_or_default();
+
+    if min == max {
+        return;
+    }
+
+    let factor = (target_max as f32 - target_min as f32) / (max - min) as f32;
+
+    for depth in depth_data.iter_mut() {
+        *depth = (target_min as f32 + (*depth - min) as f32 * factor).round() as u8;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::color::WHITE;
+    use crate::math::size::Size;
+
+    #[test]
+    fn test_stretch_depth() {
+        let mut texture = create_texture(&[10, 20, 30, 50]);
+
+        normalize_depth(&mut texture, 0, 255);
+
+        assert_eq!(texture.get_depth_data(), &[0, 64, 128, 255]);
+    }
+
+    #[test]
+    fn test_compress_depth() {
+        let mut texture = create_texture(&[0, 100, 200]);
+
+        normalize_depth(&mut texture, 50, 150);
+
+        assert_eq!(texture.get_depth_data(), &[50, 100, 150]);
+    }
+
+    #[test]
+    fn test_uniform_depth_is_unchanged() {
+        let mut texture = create_texture(&[30, 30]);
+
+        normalize_depth(&mut texture, 0, 255);
+
+        assert_eq!(texture.get_depth_data(), &[30, 30]);
+    }
+
+    fn create_texture(depths: &[u8]) -> Texture {
+        let mut texture = Texture::new(Size::new(depths.len() as u32, 1), WHITE);
+        texture.get_depth_data_mut().copy_from_slice(depths);
+        texture
+    }
+}
@@ -0,0 +1,95 @@
+use crate::generation::data::texture::Texture;
+use crate::math::color::Color;
+use crate::math::size::Size;
+
+/// Sharpens the colors with an unsharp mask: `original + amount * (original - blurred)`.
+pub fn sharpen(data: &mut Texture, amount: f32) {
+    info!("Post Processing: Sharpen");
+
+    let size = *data.get_size();
+    let blurred = blur(&size, data.get_color_data());
+
+    for (color, blurred) in data.get_color_data_mut().iter_mut().zip(blurred.iter()) {
+        *color = Color::from_rgba(
+            unsharp(color.r(), blurred[0], amount),
+            unsharp(color.g(), blurred[1], amount),
+            unsharp(color.b(), blurred[2], amount),
+            color.a(),
+        );
+    }
+}
+
+/// Blurs the colors with a 3x3 box kernel. Only neighbors inside the texture are used.
+pub fn blur(size: &Size, colors: &[Color]) -> Vec<[f32; 3]> {
+    let mut blurred = Vec::with_capacity(size.len());
+
+    for y in 0..size.height() {
+        for x in 0..size.width() {
+            let mut sum = [0.0; 3];
+            let mut count = 0.0;
+
+            for neighbor_y in y.saturating_sub(1)..(y + 2).min(size.height()) {
+                for neighbor_x in x.saturating_sub(1)..(x + 2).min(size.width()) {
+                    let color = colors[size.convert_x_y(neighbor_x, neighbor_y)];
+                    sum[0] += color.r() as f32;
+                    sum[1] += color.g() as f32;
+                    sum[2] += color.b() as f32;
+                    count += 1.0;
+                }
+            }
+
+            blurred.push([sum[0] / count, sum[1] / count, sum[2] / count]);
+        }
+    }
+
+    blurred
+}
+
+fn unsharp(original: u8, blurred: f32, amount: f32) -> u8 {
+    let original = original as f32;
+    (original + amount * (original - blurred)).clamp(0.0, 255.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hard_edge_gains_overshoot() {
+        let dark = Color::gray(50);
+        let bright = Color::gray(150);
+        let mut texture = Texture::new(Size::new(6, 1), dark);
+        texture.get_color_data_mut()[3..].copy_from_slice(&[bright, bright, bright]);
+
+        sharpen(&mut texture, 1.0);
+
+        let darker = Color::gray(16);
+        let brighter = Color::gray(183);
+
+        assert_eq!(
+            texture.get_color_data(),
+            &vec![dark, dark, darker, brighter, bright, bright]
+        );
+    }
+
+    #[test]
+    fn test_flat_region_is_unchanged() {
+        let color = Color::from_rgb(10, 100, 200);
+        let mut texture = Texture::new(Size::new(3, 4), color);
+
+        sharpen(&mut texture, 2.0);
+
+        assert_eq!(texture.get_color_data(), &vec![color; 12]);
+    }
+
+    #[test]
+    fn test_alpha_is_preserved() {
+        let mut texture = Texture::new(Size::new(6, 1), Color::from_rgba(50, 50, 50, 128));
+        texture.get_color_data_mut()[3] = Color::from_rgba(150, 150, 150, 64);
+
+        sharpen(&mut texture, 1.0);
+
+        let alphas: Vec<u8> = texture.get_color_data().iter().map(|c| c.a()).collect();
+        assert_eq!(alphas, vec![128, 128, 128, 64, 128, 128]);
+    }
+}
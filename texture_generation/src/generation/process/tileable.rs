@@ -0,0 +1,107 @@
+use crate::generation::data::texture::Texture;
+use crate::math::interpolate::lerp;
+use crate::math::point::Point;
+
+/// Cross-fades the opposite edges of the colors & depth, so that the texture tiles without seams.
+/// The `blend` is the width of the faded area at each edge.
+pub fn make_tileable(data: &mut Texture, blend: u32) {
+    info!("Post Processing: Make Tileable");
+
+    let size = *data.get_size();
+    let blend_x = blend.min(size.width() / 2);
+    let blend_y = blend.min(size.height() / 2);
+
+    for y in 0..size.height() {
+        for i in 0..blend_x {
+            let left = size.convert_x_y(i, y);
+            let right = size.convert_x_y(size.width() - 1 - i, y);
+            cross_fade(data, left, right, i, blend_x);
+        }
+    }
+
+    for x in 0..size.width() {
+        for i in 0..blend_y {
+            let top = size.convert_x_y(x, i);
+            let bottom = size.convert_x_y(x, size.height() - 1 - i);
+            cross_fade(data, top, bottom, i, blend_y);
+        }
+    }
+}
+
+/// Blends 2 pixels with the same distance `i` to opposite edges.
+/// Pixels at the edges become equal & the effect fades with the distance.
+fn cross_fade(data: &mut Texture, index0: usize, index1: usize, i: u32, blend: u32) {
+    let factor = 0.5 * (1.0 - i as f32 / blend as f32);
+
+    let color0 = data.get_color_data()[index0];
+    let color1 = data.get_color_data()[index1];
+    data.get_color_data_mut()[index0] = color0.lerp(&color1, factor);
+    data.get_color_data_mut()[index1] = color0.lerp(&color1, 1.0 - factor);
+
+    let depth0 = data.get_depth_data()[index0];
+    let depth1 = data.get_depth_data()[index1];
+    data.get_depth_data_mut()[index0] = lerp(depth0, depth1, factor);
+    data.get_depth_data_mut()[index1] = lerp(depth0, depth1, 1.0 - factor);
+}
+
+/// Checks if the opposite edges of the colors & depth are equal.
+pub fn is_tileable(data: &Texture) -> bool {
+    let size = data.get_size();
+    let max_x = size.width() as i32 - 1;
+    let max_y = size.height() as i32 - 1;
+    let is_equal = |point0: Point, point1: Point| {
+        data.get_color(&point0) == data.get_color(&point1)
+            && data.get_depth(&point0) == data.get_depth(&point1)
+    };
+
+    (0..=max_y).all(|y| is_equal(Point::new(0, y), Point::new(max_x, y)))
+        && (0..=max_x).all(|x| is_equal(Point::new(x, 0), Point::new(x, max_y)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::color::Color;
+    use crate::math::size::Size;
+
+    #[test]
+    fn test_gradient_becomes_tileable() {
+        let size = Size::new(8, 3);
+        let mut texture = Texture::new(size, Color::gray(0));
+
+        for x in 0..size.width() {
+            for y in 0..size.height() {
+                let value = (x * 30 + y) as u8;
+                texture.set(&Point::new(x as i32, y as i32), &Color::gray(value), value);
+            }
+        }
+
+        assert!(!is_tileable(&texture));
+
+        make_tileable(&mut texture, 2);
+
+        let colors = texture.get_color_data();
+        let depth = texture.get_depth_data();
+
+        for y in 0..size.height() {
+            let left = size.convert_x_y(0, y);
+            let right = size.convert_x_y(size.width() - 1, y);
+
+            assert_eq!(colors[left], colors[right]);
+            assert_eq!(depth[left], depth[right]);
+        }
+
+        assert!(is_tileable(&texture));
+    }
+
+    #[test]
+    fn test_zero_blend_is_no_op() {
+        let mut texture = Texture::new(Size::new(3, 2), Color::gray(10));
+        texture.set(&Point::new(0, 0), &Color::gray(200), 5);
+
+        make_tileable(&mut texture, 0);
+
+        assert_eq!(texture.get_color_data()[0], Color::gray(200));
+        assert_eq!(texture.get_depth_data()[0], 5);
+    }
+}
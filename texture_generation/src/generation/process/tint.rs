@@ -0,0 +1,37 @@
+use crate::generation::data::texture::Texture;
+use crate::math::color::Color;
+
+/// Blends each pixel of the color buffer towards a [`Color`] by a factor.
+pub fn tint(data: &mut Texture, color: &Color, factor: f32) {
+    info!("Post Processing: Tint");
+
+    for pixel in data.get_color_data_mut().iter_mut() {
+        *pixel = pixel.lerp(color, factor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::color::{RED, WHITE};
+    use crate::math::size::Size;
+
+    #[test]
+    fn test_tint_white_towards_red() {
+        let mut texture = Texture::new(Size::new(2, 1), WHITE);
+
+        tint(&mut texture, &RED, 0.5);
+
+        let pink = Color::from_rgb(255, 128, 128);
+        assert_eq!(texture.get_color_data(), &vec![pink, pink]);
+    }
+
+    #[test]
+    fn test_zero_factor() {
+        let mut texture = Texture::new(Size::new(2, 1), WHITE);
+
+        tint(&mut texture, &RED, 0.0);
+
+        assert_eq!(texture.get_color_data(), &vec![WHITE, WHITE]);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::generation::data::Data;
+use anyhow::{bail, Result};
 use std::collections::hash_map::DefaultHasher;
 use std::fmt::Debug;
 use std::hash::Hasher;
@@ -10,6 +11,8 @@ const MAX_FOR_F32: u64 = 1000000;
 #[derive(Clone, Debug, PartialEq)]
 pub enum Random {
     Hash,
+    /// Like [`Random::Hash`], but also hashes a seed.
+    Seed(u64),
     Mock(Vec<u64>),
 }
 
@@ -43,6 +46,13 @@ impl Random {
                 hasher.write_u32(index);
                 hasher.finish()
             }
+            Random::Seed(seed) => {
+                let mut hasher = DefaultHasher::new();
+                hasher.write_u64(*seed);
+                hasher.write_usize(data.get_instance_id());
+                hasher.write_u32(index);
+                hasher.finish()
+            }
             Random::Mock(numbers) => {
                 let index = data.get_instance_id() + index as usize;
                 numbers[index % numbers.len()]
@@ -50,3 +60,107 @@ impl Random {
         }
     }
 }
+
+#[derive(Clone, Debug, PartialEq)]
+/// Chooses an index with a probability proportional to its weight.
+pub struct WeightedChoice {
+    thresholds: Vec<usize>,
+}
+
+impl WeightedChoice {
+    pub fn new(weights: &[usize]) -> Result<WeightedChoice> {
+        if weights.is_empty() {
+            bail!("WeightedChoice requires at least 1 weight");
+        }
+
+        let mut thresholds = Vec::with_capacity(weights.len());
+        let mut threshold = 0;
+
+        for (i, weight) in weights.iter().enumerate() {
+            if *weight == 0 {
+                bail!("{}.weight of WeightedChoice is 0", i + 1);
+            }
+
+            threshold += *weight;
+            thresholds.push(threshold);
+        }
+
+        Ok(WeightedChoice { thresholds })
+    }
+
+    /// Returns the sum of all weights.
+    pub fn get_total(&self) -> usize {
+        self.thresholds[self.thresholds.len() - 1]
+    }
+
+    /// Returns the index chosen by `id`, which is wrapped around the total weight.
+    pub fn choose(&self, id: usize) -> usize {
+        let id = id % self.get_total();
+
+        self.thresholds
+            .iter()
+            .position(|threshold| id < *threshold)
+            .unwrap_or(0)
+    }
+
+    /// Returns a reproducible index based on [`Data`]'s `instance_id` & `index`.
+    pub fn choose_instance(&self, random: &Random, data: &Data, index: u32) -> usize {
+        self.choose(random.get_random_instance_usize(data, self.get_total(), index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_without_weights() {
+        assert!(WeightedChoice::new(&[]).is_err());
+    }
+
+    #[test]
+    fn test_new_with_zero_weight() {
+        assert!(WeightedChoice::new(&[1, 0, 3]).is_err());
+    }
+
+    #[test]
+    fn test_get_total() {
+        assert_eq!(WeightedChoice::new(&[1, 2, 3]).unwrap().get_total(), 6);
+    }
+
+    #[test]
+    fn test_choose() {
+        let choice = WeightedChoice::new(&[1, 2, 3]).unwrap();
+
+        #[rustfmt::skip]
+        let expected = vec![
+            0, 1, 1, 2, 2, 2,
+            0, 1, 1, 2, 2, 2,
+        ];
+
+        for (id, index) in expected.into_iter().enumerate() {
+            assert_eq!(choice.choose(id), index);
+        }
+    }
+
+    #[test]
+    fn test_seed() {
+        let data = Data::only_instance_id(3);
+        let random0 = Random::Seed(0);
+        let random1 = Random::Seed(1);
+
+        assert_eq!(random0.next(&data, 7), random0.next(&data, 7));
+        assert_ne!(random0.next(&data, 7), random1.next(&data, 7));
+    }
+
+    #[test]
+    fn test_choose_instance() {
+        let choice = WeightedChoice::new(&[3, 1]).unwrap();
+        let random = Random::Mock(vec![2, 3, 4, 5]);
+
+        for (instance_id, index, expected) in [(0, 0, 0), (1, 0, 1), (2, 0, 0), (0, 1, 1)] {
+            let data = Data::only_instance_id(instance_id);
+            assert_eq!(choice.choose_instance(&random, &data, index), expected);
+        }
+    }
+}
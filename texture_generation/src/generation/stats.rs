@@ -0,0 +1,40 @@
+use crate::generation::data::texture::Texture;
+use std::fmt;
+use std::time::Duration;
+
+/// Statistics of the generation of a [`Texture`] for profiling.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GenerationStats {
+    /// The time spent generating the components.
+    pub generation: Duration,
+    /// The time spent applying the post processes.
+    pub post_processing: Duration,
+    /// The number of pixels with a depth above 0, which were written by a component.
+    pub covered_pixels: usize,
+}
+
+impl GenerationStats {
+    /// Returns the total time.
+    pub fn total(&self) -> Duration {
+        self.generation + self.post_processing
+    }
+}
+
+impl fmt::Display for GenerationStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Generated in {:?} & post processed in {:?}. Covered pixels={}",
+            self.generation, self.post_processing, self.covered_pixels
+        )
+    }
+}
+
+/// Counts the pixels with a depth above 0.
+pub fn count_covered_pixels(texture: &Texture) -> usize {
+    texture
+        .get_depth_data()
+        .iter()
+        .filter(|depth| **depth > 0)
+        .count()
+}
@@ -114,12 +114,87 @@ impl AxisAlignedBoundingBox {
             && point.y < self.end.y
     }
 
+    /// Do both axis aligned bounding boxes share at least 1 point?
+    ///
+    /// ```
+    ///# use texture_generation::math::aabb::AxisAlignedBoundingBox;
+    ///# use texture_generation::math::point::Point;
+    ///# use texture_generation::math::size::Size;
+    /// let aabb0 = AxisAlignedBoundingBox::new(Point::new(0, 0), Size::new(2, 3));
+    /// let aabb1 = AxisAlignedBoundingBox::new(Point::new(1, 2), Size::new(2, 2));
+    /// let aabb2 = AxisAlignedBoundingBox::new(Point::new(2, 0), Size::new(2, 2));
+    ///
+    /// assert!(aabb0.overlaps(&aabb1));
+    /// assert!(aabb1.overlaps(&aabb0));
+    /// assert!(!aabb0.overlaps(&aabb2));
+    /// ```
+    pub fn overlaps(&self, other: &AABB) -> bool {
+        self.start.x < other.end.x
+            && other.start.x < self.end.x
+            && self.start.y < other.end.y
+            && other.start.y < self.end.y
+    }
+
+    /// Returns the area covered by both axis aligned bounding boxes or None, if they don't overlap.
+    ///
+    /// ```
+    ///# use texture_generation::math::aabb::AxisAlignedBoundingBox;
+    ///# use texture_generation::math::point::Point;
+    ///# use texture_generation::math::size::Size;
+    /// let aabb0 = AxisAlignedBoundingBox::new(Point::new(0, 0), Size::new(4, 3));
+    /// let aabb1 = AxisAlignedBoundingBox::new(Point::new(2, 1), Size::new(5, 5));
+    /// let aabb2 = AxisAlignedBoundingBox::new(Point::new(1, 1), Size::new(2, 1));
+    /// let aabb3 = AxisAlignedBoundingBox::new(Point::new(4, 0), Size::new(2, 2));
+    /// let overlap = AxisAlignedBoundingBox::new(Point::new(2, 1), Size::new(2, 2));
+    ///
+    /// assert_eq!(aabb0.intersection(&aabb1), Some(overlap));
+    /// assert_eq!(aabb1.intersection(&aabb0), Some(overlap));
+    /// assert_eq!(aabb0.intersection(&aabb2), Some(aabb2));
+    /// assert_eq!(aabb0.intersection(&aabb3), None);
+    /// ```
+    pub fn intersection(&self, other: &AABB) -> Option<AABB> {
+        if !self.overlaps(other) {
+            return None;
+        }
+
+        let start = self.start.max(&other.start);
+        let end = self.end.min(&other.end);
+
+        Some(AABB::between(start, end))
+    }
+
+    /// Returns the smallest axis aligned bounding box containing both.
+    ///
+    /// ```
+    ///# use texture_generation::math::aabb::AxisAlignedBoundingBox;
+    ///# use texture_generation::math::point::Point;
+    ///# use texture_generation::math::size::Size;
+    /// let aabb0 = AxisAlignedBoundingBox::new(Point::new(0, 0), Size::new(4, 3));
+    /// let aabb1 = AxisAlignedBoundingBox::new(Point::new(2, 1), Size::new(5, 5));
+    /// let aabb2 = AxisAlignedBoundingBox::new(Point::new(1, 1), Size::new(2, 1));
+    /// let aabb3 = AxisAlignedBoundingBox::new(Point::new(5, 0), Size::new(2, 2));
+    ///
+    /// assert_eq!(aabb0.union(&aabb1), AxisAlignedBoundingBox::new(Point::new(0, 0), Size::new(7, 6)));
+    /// assert_eq!(aabb0.union(&aabb2), aabb0);
+    /// assert_eq!(aabb0.union(&aabb3), AxisAlignedBoundingBox::new(Point::new(0, 0), Size::new(7, 3)));
+    /// ```
+    pub fn union(&self, other: &AABB) -> AABB {
+        let start = self.start.min(&other.start);
+        let end = self.end.max(&other.end);
+
+        AABB::between(start, end)
+    }
+
+    fn between(start: Point, end: Point) -> AABB {
+        let size = Size::new((end.x - start.x) as u32, (end.y - start.y) as u32);
+        AABB { start, end, size }
+    }
+
     /// Limit the other aabb to this one.
     pub fn limit(&self, other: &AABB) -> AABB {
         let start = self.limit_to(&other.start);
         let end = self.limit_to(&other.end);
-        let size = Size::new((end.x - start.x) as u32, (end.y - start.y) as u32);
-        AABB { start, end, size }
+        AABB::between(start, end)
     }
 
     /// Limit a [`Point`] to this aabb.
@@ -0,0 +1,26 @@
+use crate::math::point::Point;
+use serde::{Deserialize, Serialize};
+
+/// The x-axis or y-axis of the 2d space.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+impl Axis {
+    /// Reflects the point along the axis, where `sum` is the sum of the first & last coordinate.
+    ///
+    /// ```
+    ///# use texture_generation::math::axis::Axis;
+    ///# use texture_generation::math::point::Point;
+    /// assert_eq!(Axis::X.mirror(&Point::new(2, 3), 10), Point::new(8, 3));
+    /// assert_eq!(Axis::Y.mirror(&Point::new(2, 3), 10), Point::new(2, 7));
+    /// ```
+    pub fn mirror(&self, point: &Point, sum: i32) -> Point {
+        match self {
+            Axis::X => Point::new(sum - point.x, point.y),
+            Axis::Y => Point::new(point.x, sum - point.y),
+        }
+    }
+}
@@ -3,7 +3,7 @@ use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
 use std::ops::{Add, Mul, MulAssign};
 
-/// Represents a color with the RGB color model.
+/// Represents a color with the RGB color model and an alpha channel.
 ///
 /// See [Wikipedia](https://en.wikipedia.org/wiki/RGB_color_model).
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -11,50 +11,87 @@ pub struct Color {
     r: u8,
     g: u8,
     b: u8,
+    #[serde(default = "opaque")]
+    a: u8,
+}
+
+const OPAQUE: u8 = 255;
+
+fn opaque() -> u8 {
+    OPAQUE
 }
 
 impl Color {
-    /// Returns a new color.
+    /// Returns a new opaque color.
     pub const fn from_rgb(r: u8, g: u8, b: u8) -> Color {
-        Color { r, g, b }
+        Self::from_rgba(r, g, b, OPAQUE)
     }
 
-    /// Converts a string to a color, if possible:
+    /// Returns a new color with an alpha value, where 0 is fully transparent & 255 is opaque.
+    pub const fn from_rgba(r: u8, g: u8, b: u8, a: u8) -> Color {
+        Color { r, g, b, a }
+    }
+
+    /// Converts a string to a color, if possible.
+    ///
+    /// Supports hex codes with 6, 8 (with alpha) or 3 digits & an optional leading #,
+    /// and a few named colors like *red* or *orange*:
     ///
     /// ```
-    /// use texture_generation::math::color::{Color, ORANGE};
+    /// use texture_generation::math::color::{Color, ORANGE, WHITE};
     /// assert_eq!(Color::convert("#FFA500").unwrap(), ORANGE);
+    /// assert_eq!(Color::convert("#FFA50080").unwrap(), Color::from_rgba(255, 165, 0, 128));
+    /// assert_eq!(Color::convert("FFA500").unwrap(), ORANGE);
+    /// assert_eq!(Color::convert("#FFF").unwrap(), WHITE);
+    /// assert_eq!(Color::convert("orange").unwrap(), ORANGE);
     /// ```
-    pub fn convert(hex_code: &str) -> Result<Color> {
-        if !hex_code.starts_with('#') {
-            bail!("'{}' needs to start with # to be a color", hex_code);
-        } else if hex_code.len() != 7 {
-            bail!("'{}' needs to be 7 characters long to be a color", hex_code);
+    pub fn convert(text: &str) -> Result<Color> {
+        if let Some(color) = convert_name(text) {
+            return Ok(color);
         }
 
-        let r: u8 = u8::from_str_radix(&hex_code[1..3], 16).context(format!(
-            "Failed to parse the value of red from '{}'",
-            hex_code
-        ))?;
-        let g: u8 = u8::from_str_radix(&hex_code[3..5], 16).context(format!(
-            "Failed to parse the value of green from '{}'",
-            hex_code
-        ))?;
-        let b: u8 = u8::from_str_radix(&hex_code[5..7], 16).context(format!(
-            "Failed to parse the value of blue from '{}'",
-            hex_code
-        ))?;
+        if text.starts_with('$') {
+            bail!(
+                "'{}' references a palette color, which wasn't resolved",
+                text
+            );
+        }
 
-        Ok(Color::from_rgb(r, g, b))
+        let hex_code = text.strip_prefix('#').unwrap_or(text);
+
+        if let Some(c) = hex_code.chars().find(|c| !c.is_ascii_hexdigit()) {
+            bail!(
+                "'{}' is neither a known color name nor a hex code, because of '{}'",
+                text,
+                c
+            );
+        }
+
+        let hex_code = match hex_code.len() {
+            3 => hex_code.chars().map(|c| format!("{}{}", c, c)).collect(),
+            6 | 8 => hex_code.to_string(),
+            n => bail!(
+                "'{}' needs 3, 6 or 8 hex digits to be a color, but has {}",
+                text,
+                n
+            ),
+        };
+
+        let r = parse_channel(&hex_code[0..2], "red", text)?;
+        let g = parse_channel(&hex_code[2..4], "green", text)?;
+        let b = parse_channel(&hex_code[4..6], "blue", text)?;
+        let a = if hex_code.len() == 8 {
+            parse_channel(&hex_code[6..8], "alpha", text)?
+        } else {
+            OPAQUE
+        };
+
+        Ok(Color::from_rgba(r, g, b, a))
     }
 
     /// Returns a new gray color.
     pub const fn gray(value: u8) -> Color {
-        Color {
-            r: value,
-            g: value,
-            b: value,
-        }
+        Color::from_rgb(value, value, value)
     }
 
     /// Returns the red component.
@@ -87,6 +124,57 @@ impl Color {
         self.b
     }
 
+    /// Returns the alpha component.
+    ///
+    /// ```
+    ///# use texture_generation::math::color::Color;
+    /// assert_eq!(Color::from_rgb(0, 1, 2).a(), 255);
+    /// assert_eq!(Color::from_rgba(0, 1, 2, 3).a(), 3);
+    /// ```
+    pub fn a(&self) -> u8 {
+        self.a
+    }
+
+    /// Blends the color over a background color based on its alpha value.
+    ///
+    /// ```
+    ///# use texture_generation::math::color::{Color, BLUE, RED};
+    /// let half_red = Color::from_rgba(255, 0, 0, 128);
+    ///
+    /// assert_eq!(RED.blend(&BLUE), RED);
+    /// assert_eq!(half_red.blend(&BLUE), Color::from_rgb(128, 0, 127));
+    /// ```
+    pub fn blend(&self, background: &Color) -> Color {
+        if self.a == OPAQUE {
+            return *self;
+        }
+
+        let alpha = self.a as u32;
+        let mix = |front: u8, back: u8| {
+            ((front as u32 * alpha + back as u32 * (255 - alpha) + 127) / 255) as u8
+        };
+
+        Color::from_rgba(
+            mix(self.r, background.r),
+            mix(self.g, background.g),
+            mix(self.b, background.b),
+            mix(OPAQUE, background.a),
+        )
+    }
+
+    /// Returns the luma of the color with the weights of ITU-R BT.601.
+    ///
+    /// ```
+    ///# use texture_generation::math::color::Color;
+    /// assert_eq!(Color::from_rgb(0, 0, 0).luma(), 0);
+    /// assert_eq!(Color::from_rgb(255, 255, 255).luma(), 255);
+    /// ```
+    pub fn luma(&self) -> u8 {
+        (0.299 * self.r as f32 + 0.587 * self.g as f32 + 0.114 * self.b as f32)
+            .round()
+            .min(255.0) as u8
+    }
+
     /// Interpolates linearly with another color.
     ///
     /// ```
@@ -95,15 +183,152 @@ impl Color {
     /// let color1 = Color::from_rgb(200, 75, 220);
     /// let result = Color::from_rgb(100, 50, 170);
     ///
+    /// assert_eq!(color0.lerp(&color1, 0.0), color0);
     /// assert_eq!(color0.lerp(&color1, 0.5), result);
+    /// assert_eq!(color0.lerp(&color1, 1.0), color1);
     /// ```
     pub fn lerp(&self, other: &Color, factor: f32) -> Color {
         Color {
             r: lerp(self.r, other.r, factor),
             g: lerp(self.g, other.g, factor),
             b: lerp(self.b, other.b, factor),
+            a: lerp(self.a, other.a, factor),
         }
     }
+
+    /// Multiplies each channel with the one of another color, where 255 is 1.
+    ///
+    /// ```
+    ///# use texture_generation::math::color::{Color, BLACK, WHITE};
+    /// let color = Color::from_rgb(10, 100, 255);
+    ///
+    /// assert_eq!(color.multiply(&WHITE), color);
+    /// assert_eq!(color.multiply(&BLACK), BLACK);
+    /// assert_eq!(color.multiply(&Color::gray(128)), Color::from_rgb(5, 50, 128));
+    /// ```
+    pub fn multiply(&self, other: &Color) -> Color {
+        let mul = |a: u8, b: u8| ((a as u32 * b as u32 + 127) / 255) as u8;
+
+        Color::from_rgba(
+            mul(self.r, other.r),
+            mul(self.g, other.g),
+            mul(self.b, other.b),
+            self.a,
+        )
+    }
+
+    /// Adds each channel of another color and clamps the result at 255.
+    ///
+    /// ```
+    ///# use texture_generation::math::color::Color;
+    /// let a = Color::from_rgb(10, 100, 255);
+    /// let b = Color::from_rgb(5, 200, 100);
+    ///
+    /// assert_eq!(a.add_clamped(&b), Color::from_rgb(15, 255, 255));
+    /// ```
+    pub fn add_clamped(&self, other: &Color) -> Color {
+        Color::from_rgba(
+            self.r.saturating_add(other.r),
+            self.g.saturating_add(other.g),
+            self.b.saturating_add(other.b),
+            self.a,
+        )
+    }
+
+    /// Creates a color from hue in degrees and saturation & value between 0 and 1.
+    ///
+    /// ```
+    ///# use texture_generation::math::color::{Color, BLUE, GREEN, RED};
+    /// assert_eq!(Color::from_hsv(0.0, 1.0, 1.0), RED);
+    /// assert_eq!(Color::from_hsv(120.0, 1.0, 1.0), GREEN);
+    /// assert_eq!(Color::from_hsv(600.0, 1.0, 1.0), BLUE);
+    /// ```
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Color {
+        let hue = hue.rem_euclid(360.0) / 60.0;
+        let chroma = value * saturation;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+        let m = value - chroma;
+
+        let (r, g, b) = match hue as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        let convert = |c: f32| ((c + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+
+        Color::from_rgb(convert(r), convert(g), convert(b))
+    }
+
+    /// Returns hue in degrees and saturation & value between 0 and 1.
+    ///
+    /// ```
+    ///# use texture_generation::math::color::{Color, BLACK, BLUE};
+    /// assert_eq!(BLUE.to_hsv(), (240.0, 1.0, 1.0));
+    /// assert_eq!(BLACK.to_hsv(), (0.0, 0.0, 0.0));
+    /// ```
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let r = self.r as f32 / 255.0;
+        let g = self.g as f32 / 255.0;
+        let b = self.b as f32 / 255.0;
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        let saturation = if max == 0.0 { 0.0 } else { delta / max };
+
+        (hue, saturation, max)
+    }
+
+    /// Rotates the hue by degrees & offsets the value, while keeping the alpha.
+    ///
+    /// ```
+    ///# use texture_generation::math::color::{Color, GREEN, RED};
+    /// assert_eq!(RED.shift_hsv(0.0, 0.0), RED);
+    /// assert_eq!(RED.shift_hsv(120.0, 0.0), GREEN);
+    /// assert_eq!(RED.shift_hsv(0.0, -0.5), Color::from_rgb(128, 0, 0));
+    /// ```
+    pub fn shift_hsv(&self, hue: f32, value: f32) -> Color {
+        if hue == 0.0 && value == 0.0 {
+            return *self;
+        }
+
+        let (h, s, v) = self.to_hsv();
+        let color = Color::from_hsv(h + hue, s, (v + value).clamp(0.0, 1.0));
+
+        Color::from_rgba(color.r, color.g, color.b, self.a)
+    }
+}
+
+fn convert_name(name: &str) -> Option<Color> {
+    Some(match name.to_lowercase().as_str() {
+        "black" => BLACK,
+        "blue" => BLUE,
+        "green" => GREEN,
+        "orange" => ORANGE,
+        "pink" => PINK,
+        "red" => RED,
+        "white" => WHITE,
+        _ => return None,
+    })
+}
+
+fn parse_channel(hex_code: &str, channel: &str, text: &str) -> Result<u8> {
+    u8::from_str_radix(hex_code, 16).context(format!(
+        "Failed to parse the value of {} from '{}'",
+        channel, text
+    ))
 }
 
 impl Default for Color {
@@ -154,11 +379,7 @@ impl Add<Color> for Color {
     type Output = Color;
 
     fn add(self, other: Color) -> Color {
-        Color::from_rgb(
-            self.r.saturating_add(other.r),
-            self.g.saturating_add(other.g),
-            self.b.saturating_add(other.b),
-        )
+        self.add_clamped(&other)
     }
 }
 
@@ -176,10 +397,11 @@ impl Mul<f32> for Color {
     type Output = Self;
 
     fn mul(self, value: f32) -> Color {
-        Color::from_rgb(
+        Color::from_rgba(
             (self.r as f32 * value) as u8,
             (self.g as f32 * value) as u8,
             (self.b as f32 * value) as u8,
+            self.a,
         )
     }
 }
@@ -223,8 +445,8 @@ mod tests {
     }
 
     #[test]
-    fn test_from_string_invalid_start() {
-        assert!(Color::convert("FFA500").is_err());
+    fn test_from_string_without_hash() {
+        assert_eq!(Color::convert("FFA500").unwrap(), ORANGE);
     }
 
     #[test]
@@ -233,6 +455,58 @@ mod tests {
         assert!(Color::convert("#FF").is_err());
         assert!(Color::convert("#FFA5").is_err());
         assert!(Color::convert("#FFA50").is_err());
+        assert!(Color::convert("#FFA5001").is_err());
+    }
+
+    #[test]
+    fn test_from_string_with_alpha() {
+        assert_eq!(
+            Color::convert("#FFA50080").unwrap(),
+            Color::from_rgba(255, 165, 0, 128)
+        );
+        assert_eq!(Color::convert("#FFA500FF").unwrap(), ORANGE);
+        assert_eq!(Color::convert("#FFA500").unwrap().a(), 255);
+    }
+
+    #[test]
+    fn test_from_shorthand() {
+        assert_eq!(Color::convert("#FFF").unwrap(), WHITE);
+        assert_eq!(Color::convert("#f00").unwrap(), RED);
+        assert_eq!(Color::convert("0F0").unwrap(), GREEN);
+        assert_eq!(
+            Color::convert("#1A9").unwrap(),
+            Color::from_rgb(0x11, 0xAA, 0x99)
+        );
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Color::convert("black").unwrap(), BLACK);
+        assert_eq!(Color::convert("blue").unwrap(), BLUE);
+        assert_eq!(Color::convert("green").unwrap(), GREEN);
+        assert_eq!(Color::convert("orange").unwrap(), ORANGE);
+        assert_eq!(Color::convert("pink").unwrap(), PINK);
+        assert_eq!(Color::convert("Red").unwrap(), RED);
+        assert_eq!(Color::convert("WHITE").unwrap(), WHITE);
+    }
+
+    #[test]
+    fn test_from_invalid_string() {
+        let error = Color::convert("purple").unwrap_err();
+        assert!(error.to_string().contains("'purple'"));
+
+        let error = Color::convert("#GG0000").unwrap_err();
+        assert!(error.to_string().contains("'#GG0000'"));
+
+        assert!(Color::convert("#FFÄ").is_err());
+        assert!(Color::convert("##FFF").is_err());
+    }
+
+    #[test]
+    fn test_luma() {
+        assert_eq!(RED.luma(), 76);
+        assert_eq!(GREEN.luma(), 150);
+        assert_eq!(BLUE.luma(), 29);
     }
 
     #[test]
@@ -1,6 +1,8 @@
 pub mod aabb;
+pub mod axis;
 pub mod color;
 pub mod interpolate;
+pub mod noise;
 pub mod occupancy;
 pub mod point;
 pub mod shape;
@@ -0,0 +1,247 @@
+use anyhow::{bail, Result};
+use noise::{NoiseFn, Perlin, Seedable, SuperSimplex, Value};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// Selects the algorithm of a [`Noise`].
+pub enum NoiseFunction {
+    /// Interpolates between random values on a grid.
+    Value,
+    /// Smooth gradient noise.
+    Perlin,
+    /// Smooth gradient noise with fewer directional artifacts. The default.
+    Simplex,
+}
+
+impl NoiseFunction {
+    /// Creates a deterministic [`Noise`] for the seed.
+    pub fn create(&self, seed: u32) -> Noise {
+        match self {
+            NoiseFunction::Value => Noise::Value(Value::new().set_seed(seed)),
+            NoiseFunction::Perlin => Noise::Perlin(Perlin::new().set_seed(seed)),
+            NoiseFunction::Simplex => Noise::Simplex(SuperSimplex::new().set_seed(seed)),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+/// A seeded noise function.
+pub enum Noise {
+    Value(Value),
+    Perlin(Perlin),
+    Simplex(SuperSimplex),
+}
+
+impl Noise {
+    /// Returns the noise value at the position, which is roughly between -1 & 1.
+    pub fn get(&self, x: f64, y: f64) -> f64 {
+        match self {
+            Noise::Value(noise) => noise.get([x, y]),
+            Noise::Perlin(noise) => noise.get([x, y]),
+            Noise::Simplex(noise) => noise.get([x, y]),
+        }
+    }
+
+    /// Returns the sum of several octaves of the noise, which is normalized to the range of a single octave.
+    ///
+    /// Each octave multiplies the amplitude with *persistence* & the frequency with *lacunarity*.
+    pub fn get_octaves(
+        &self,
+        x: f64,
+        y: f64,
+        octaves: u32,
+        persistence: f32,
+        lacunarity: f32,
+    ) -> f64 {
+        let mut sum = 0.0;
+        let mut total_amplitude = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+
+        for _ in 0..octaves.max(1) {
+            sum += self.get(x * frequency, y * frequency) * amplitude;
+            total_amplitude += amplitude;
+            amplitude *= persistence as f64;
+            frequency *= lacunarity as f64;
+        }
+
+        sum / total_amplitude
+    }
+}
+
+/// Makes a noise function tileable by blending it with its copies shifted by *width* & *height*.
+/// So the result at *x* equals the result at *x + width*, which lets the pattern repeat without seams.
+///
+/// ```
+///# use texture_generation::math::noise::{wrap, NoiseFunction};
+/// let noise = NoiseFunction::Perlin.create(3);
+/// let get = |x, y| noise.get(x, y);
+///
+/// assert_eq!(wrap(0.0, 0.7, 4.0, 2.0, get), wrap(4.0, 0.7, 4.0, 2.0, get));
+/// assert_eq!(wrap(1.3, 0.0, 4.0, 2.0, get), wrap(1.3, 2.0, 4.0, 2.0, get));
+/// ```
+pub fn wrap<F: Fn(f64, f64) -> f64>(x: f64, y: f64, width: f64, height: f64, get: F) -> f64 {
+    let x = x.rem_euclid(width);
+    let y = y.rem_euclid(height);
+    let (inv_x, inv_y) = (width - x, height - y);
+
+    (get(x, y) * inv_x * inv_y
+        + get(x - width, y) * x * inv_y
+        + get(x, y - height) * inv_x * y
+        + get(x - width, y - height) * x * y)
+        / (width * height)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Sums several octaves of a [`NoiseFunction`]. See [`Noise::get_octaves()`].
+pub struct FractalNoise {
+    function: NoiseFunction,
+    octaves: u32,
+    persistence: f32,
+    lacunarity: f32,
+}
+
+impl FractalNoise {
+    pub fn new(
+        function: NoiseFunction,
+        octaves: u32,
+        persistence: f32,
+        lacunarity: f32,
+    ) -> Result<FractalNoise> {
+        if octaves == 0 {
+            bail!("Argument 'octaves' needs to be greater than 0");
+        } else if persistence <= 0.0 {
+            bail!("Argument 'persistence' needs to be greater than 0");
+        } else if lacunarity < 1.0 {
+            bail!("Argument 'lacunarity' needs to be 1 or greater");
+        }
+
+        Ok(FractalNoise {
+            function,
+            octaves,
+            persistence,
+            lacunarity,
+        })
+    }
+
+    /// Creates a deterministic [`Noise`] for the seed.
+    pub fn create(&self, seed: u32) -> Noise {
+        self.function.create(seed)
+    }
+
+    /// Returns the sum of the octaves of a [`Noise`] created by [`FractalNoise::create()`].
+    pub fn get(&self, noise: &Noise, x: f64, y: f64) -> f64 {
+        noise.get_octaves(x, y, self.octaves, self.persistence, self.lacunarity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_perlin_is_continuous() {
+        assert_continuous(NoiseFunction::Perlin);
+    }
+
+    #[test]
+    fn test_simplex_is_continuous() {
+        assert_continuous(NoiseFunction::Simplex);
+    }
+
+    #[test]
+    fn test_same_seed_is_deterministic() {
+        for function in &[
+            NoiseFunction::Value,
+            NoiseFunction::Perlin,
+            NoiseFunction::Simplex,
+        ] {
+            let noise0 = function.create(42);
+            let noise1 = function.create(42);
+
+            for i in 0..100 {
+                let x = i as f64 * 0.37;
+                let y = i as f64 * 0.19;
+                assert_eq!(noise0.get(x, y), noise1.get(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_one_octave_is_identical_to_noise() {
+        let noise = NoiseFunction::Simplex.create(3);
+
+        for i in 0..100 {
+            let x = i as f64 * 0.37;
+            let y = i as f64 * 0.19;
+            assert_eq!(noise.get_octaves(x, y, 1, 0.5, 2.0), noise.get(x, y));
+        }
+    }
+
+    #[test]
+    fn test_more_octaves_reduce_variance() {
+        let noise = NoiseFunction::Perlin.create(11);
+
+        let variance1 = calculate_variance(&noise, 1);
+        let variance4 = calculate_variance(&noise, 4);
+
+        assert!(variance4 < variance1);
+    }
+
+    #[test]
+    fn test_wrap_is_continuous() {
+        let noise = NoiseFunction::Simplex.create(5);
+        let get = |x, y| noise.get(x, y);
+        let step = 0.01;
+
+        for i in 0..400 {
+            let x = i as f64 * step;
+            let value = wrap(x, 0.5, 4.0, 4.0, get);
+
+            assert!((wrap(x + step, 0.5, 4.0, 4.0, get) - value).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn test_new_fractal_noise() {
+        let function = NoiseFunction::Value;
+
+        assert!(FractalNoise::new(function, 1, 0.5, 2.0).is_ok());
+        assert!(FractalNoise::new(function, 0, 0.5, 2.0).is_err());
+        assert!(FractalNoise::new(function, 4, 0.0, 2.0).is_err());
+        assert!(FractalNoise::new(function, 4, 0.5, 0.5).is_err());
+    }
+
+    fn calculate_variance(noise: &Noise, octaves: u32) -> f64 {
+        let mut values = Vec::new();
+
+        for y in 0..100 {
+            for x in 0..100 {
+                let px = x as f64 * 0.23;
+                let py = y as f64 * 0.23;
+                values.push(noise.get_octaves(px, py, octaves, 0.5, 2.0));
+            }
+        }
+
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n
+    }
+
+    fn assert_continuous(function: NoiseFunction) {
+        let noise = function.create(7);
+        let step = 0.01;
+
+        for y in 0..20 {
+            for x in 0..200 {
+                let px = x as f64 * step;
+                let py = y as f64 * 0.5;
+                let value = noise.get(px, py);
+
+                assert!((noise.get(px + step, py) - value).abs() < 0.1);
+                assert!((noise.get(px, py + step) - value).abs() < 0.1);
+            }
+        }
+    }
+}
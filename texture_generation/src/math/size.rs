@@ -25,7 +25,7 @@ use std::ops::Mul;
 ///
 /// A size with width 2 & height 3.
 /// The numbers are indices of each cell.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct Size {
     width: u32,
     height: u32,
@@ -69,13 +69,27 @@ impl Size {
 
     /// Returns the number of cells covered by this size.
     ///
+    /// Panics, if the number doesn't fit into an u32. See [`Size::checked_len()`].
+    ///
     /// ```
     ///# use texture_generation::math::size::Size;
     /// let size = Size::new(2, 3);
     /// assert_eq!(size.len(), 6);
     /// ```
     pub fn len(&self) -> usize {
-        (self.width * self.height) as usize
+        self.checked_len()
+            .unwrap_or_else(|| panic!("Size {}x{} covers too many cells!", self.width, self.height))
+    }
+
+    /// Returns the number of cells covered by this size or None, if the number doesn't fit into an u32.
+    ///
+    /// ```
+    ///# use texture_generation::math::size::Size;
+    /// assert_eq!(Size::new(2, 3).checked_len(), Some(6));
+    /// assert_eq!(Size::new(u32::MAX, 2).checked_len(), None);
+    /// ```
+    pub fn checked_len(&self) -> Option<usize> {
+        self.width.checked_mul(self.height).map(|len| len as usize)
     }
 
     /// Returns the size along the x-axis.
@@ -115,7 +129,10 @@ impl Size {
     /// assert!(!size.is_inside(&negative));
     /// ```
     pub fn is_inside(&self, point: &Point) -> bool {
-        point.x >= 0 && point.y >= 0 && point.x < self.width as i32 && point.y < self.height as i32
+        point.x >= 0
+            && point.y >= 0
+            && (point.x as u32) < self.width
+            && (point.y as u32) < self.height
     }
 
     /// Is the area inside?
@@ -132,12 +149,17 @@ impl Size {
     /// assert!(size.is_area_inside(&inside, &area_size));
     /// assert!(!size.is_area_inside(&outside, &area_size));
     /// assert!(!size.is_area_inside(&negative, &area_size));
+    /// assert!(!size.is_area_inside(&inside, &Size::new(u32::MAX, 1)));
     /// ```
     pub fn is_area_inside(&self, start: &Point, size: &Size) -> bool {
-        start.x >= 0
-            && start.y >= 0
-            && start.x as u32 + size.width <= self.width
-            && start.y as u32 + size.height <= self.height
+        let is_inside = |start: i32, length: u32, max: u32| {
+            start >= 0
+                && (start as u32)
+                    .checked_add(length)
+                    .is_some_and(|end| end <= max)
+        };
+
+        is_inside(start.x, size.width, self.width) && is_inside(start.y, size.height, self.height)
     }
 
     /// Is the [`AABB`] inside?
@@ -195,6 +217,7 @@ impl Size {
     }
 
     /// Converts a [`Point`] to the equivalent index, if it is inside.
+    /// Never overflows, even for huge sizes or coordinates.
     ///
     /// ```
     ///# use texture_generation::math::point::Point;
@@ -203,14 +226,19 @@ impl Size {
     /// let inside = Point::new(1, 2);
     /// let outside = Point::new(4, 5);
     /// let negative = Point::new(1, -1);
+    /// let overflow = Point::new(i32::MAX, i32::MAX);
+    /// let huge = Size::new(u32::MAX, 2);
     ///
     /// assert_eq!(size.to_index(&inside), Some(5));
     /// assert_eq!(size.to_index(&outside), None);
     /// assert_eq!(size.to_index(&negative), None);
+    /// assert_eq!(size.to_index(&overflow), None);
+    /// assert_eq!(huge.to_index(&Point::new(i32::MAX, 0)), Some(i32::MAX as usize));
+    /// assert_eq!(huge.to_index(&Point::new(i32::MIN, 0)), None);
     /// ```
     pub fn to_index(&self, point: &Point) -> Option<usize> {
         if self.is_inside(point) {
-            return Some(self.to_index_risky(point));
+            return Some(point.y as usize * self.width as usize + point.x as usize);
         }
 
         None
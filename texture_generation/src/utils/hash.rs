@@ -0,0 +1,63 @@
+const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const PRIME: u64 = 0x0100_0000_01b3;
+
+/// Calculates a FNV-1a hash, which is stable across runs & platforms
+/// unlike [`std::collections::hash_map::DefaultHasher`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StableHasher {
+    hash: u64,
+}
+
+impl StableHasher {
+    pub fn new() -> StableHasher {
+        StableHasher { hash: OFFSET_BASIS }
+    }
+
+    /// Adds the bytes to the hash.
+    ///
+    /// ```
+    ///# use texture_generation::utils::hash::StableHasher;
+    /// let mut hasher = StableHasher::new();
+    /// hasher.write(b"a");
+    /// assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+    /// ```
+    pub fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.hash ^= *byte as u64;
+            self.hash = self.hash.wrapping_mul(PRIME);
+        }
+    }
+
+    /// Returns the hash of all the bytes written so far.
+    pub fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        StableHasher::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(StableHasher::new().finish(), OFFSET_BASIS);
+    }
+
+    #[test]
+    fn test_split_writes_are_identical() {
+        let mut hasher0 = StableHasher::new();
+        let mut hasher1 = StableHasher::new();
+
+        hasher0.write(&[1, 2, 3]);
+        hasher1.write(&[1]);
+        hasher1.write(&[2, 3]);
+
+        assert_eq!(hasher0.finish(), hasher1.finish());
+    }
+}
@@ -1,9 +1,16 @@
+use crate::math::size::Size;
 use chrono::Local;
 use env_logger::Builder;
+use log::LevelFilter;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::io::Write;
 
-pub fn init_logging() {
-    Builder::from_env("RUST_LOG")
+/// Initializes the logging with a level, which can be overridden by the environment variable RUST_LOG.
+pub fn init_logging(level: LevelFilter) {
+    Builder::new()
+        .filter_level(level)
+        .parse_env("RUST_LOG")
         .format(|buf, record| {
             writeln!(
                 buf,
@@ -15,3 +22,127 @@ pub fn init_logging() {
         })
         .init();
 }
+
+/// Maps the number of verbose flags to a level. The quiet flag only logs errors.
+pub fn get_log_level(verbose: u8, quiet: bool) -> LevelFilter {
+    if quiet {
+        return LevelFilter::Error;
+    }
+
+    match verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+/// The key events of a pipeline, which can be logged in a machine-readable way.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum LogEvent {
+    Load { path: String },
+    Generate { name: String, size: Size },
+    PostProcess { count: usize },
+    Save { path: String },
+    Valid { path: String },
+    Hash { path: String, hash: String },
+    Error { message: String },
+    Finished,
+}
+
+impl fmt::Display for LogEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogEvent::Load { path } => write!(f, "Load {:?}", path),
+            LogEvent::Generate { name, size } => write!(
+                f,
+                "Generate '{}' with size {}x{}",
+                name,
+                size.width(),
+                size.height()
+            ),
+            LogEvent::PostProcess { count } => write!(f, "Post processing. N={}", count),
+            LogEvent::Save { path } => write!(f, "Save {:?}", path),
+            LogEvent::Valid { path } => write!(f, "{:?} is valid", path),
+            LogEvent::Hash { path, hash } => write!(f, "Hash of {:?} is {}", path, hash),
+            LogEvent::Error { message } => write!(f, "Error: {}", message),
+            LogEvent::Finished => write!(f, "Finished"),
+        }
+    }
+}
+
+/// Logs [`LogEvent`]s either as human-readable text or as JSON lines.
+pub enum EventLogger<W: Write> {
+    /// Uses the normal logging initialized by [`init_logging`].
+    Text,
+    /// Writes one JSON object per line.
+    Json(W),
+}
+
+impl<W: Write> EventLogger<W> {
+    pub fn log(&mut self, event: LogEvent) {
+        match self {
+            EventLogger::Text => match event {
+                LogEvent::Error { .. } => error!("{}", event),
+                _ => info!("{}", event),
+            },
+            EventLogger::Json(writer) => match serde_json::to_string(&event) {
+                Ok(line) => {
+                    if let Err(error) = writeln!(writer, "{}", line) {
+                        warn!("Couldn't write event {:?}, because of {:?}", event, error);
+                    }
+                }
+                Err(error) => warn!(
+                    "Couldn't serialize event {:?}, because of {:?}",
+                    event, error
+                ),
+            },
+        }
+    }
+
+    /// Returns the writer of the JSON lines, if there is one.
+    pub fn get_writer(&self) -> Option<&W> {
+        match self {
+            EventLogger::Text => None,
+            EventLogger::Json(writer) => Some(writer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_log_level() {
+        assert_eq!(get_log_level(0, false), LevelFilter::Warn);
+        assert_eq!(get_log_level(1, false), LevelFilter::Info);
+        assert_eq!(get_log_level(2, false), LevelFilter::Debug);
+        assert_eq!(get_log_level(3, false), LevelFilter::Trace);
+    }
+
+    #[test]
+    fn test_quiet_overrides_verbose() {
+        assert_eq!(get_log_level(0, true), LevelFilter::Error);
+        assert_eq!(get_log_level(2, true), LevelFilter::Error);
+    }
+
+    #[test]
+    fn test_json_line() {
+        let mut logger = EventLogger::Json(Vec::new());
+
+        logger.log(LogEvent::Generate {
+            name: "test".to_string(),
+            size: Size::new(2, 3),
+        });
+        logger.log(LogEvent::Finished);
+
+        let output = String::from_utf8(logger.get_writer().unwrap().clone()).unwrap();
+
+        assert_eq!(
+            output,
+            "{\"event\":\"generate\",\"name\":\"test\",\"size\":{\"width\":2,\"height\":3}}\n{\"event\":\"finished\"}\n"
+        );
+    }
+}
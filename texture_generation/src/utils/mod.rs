@@ -1,3 +1,4 @@
 pub mod error;
+pub mod hash;
 pub mod logging;
 pub mod resource;
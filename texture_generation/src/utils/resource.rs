@@ -1,4 +1,5 @@
 use anyhow::Result;
+use std::collections::HashMap;
 
 pub trait Resource: Default {
     fn get_name(&self) -> &str;
@@ -13,11 +14,32 @@ pub trait ResourceDefinition {
 pub struct ResourceManager<T: Resource> {
     default: T,
     resources: Vec<T>,
+    /// Maps the name of each resource to its id. The first resource wins, if names are duplicated.
+    ids: HashMap<String, usize>,
 }
 
 impl<T: Resource> ResourceManager<T> {
     pub fn new(resources: Vec<T>, default: T) -> ResourceManager<T> {
-        ResourceManager { default, resources }
+        let mut ids = HashMap::new();
+
+        for (id, resource) in resources.iter().enumerate() {
+            let name = resource.get_name();
+
+            if let Some(first_id) = ids.get(name) {
+                warn!(
+                    "Resource '{}' with id {} is shadowed by id {} with the same name!",
+                    name, id, first_id
+                );
+            } else {
+                ids.insert(name.to_string(), id);
+            }
+        }
+
+        ResourceManager {
+            default,
+            resources,
+            ids,
+        }
     }
 
     pub fn is_empty(&self) -> bool {
@@ -28,16 +50,24 @@ impl<T: Resource> ResourceManager<T> {
         self.resources.len()
     }
 
+    /// Returns the resource with the id or the default resource, if the id is unknown.
     pub fn get(&self, id: usize) -> &T {
         self.resources.get(id).unwrap_or(&self.default)
     }
 
+    /// Do at least 2 resources share the same name?
+    pub fn has_duplicates(&self) -> bool {
+        self.ids.len() != self.resources.len()
+    }
+
+    /// Returns the id of the resource with the name.
     pub fn get_id(&self, name: &str) -> Option<usize> {
-        self.resources
-            .iter()
-            .enumerate()
-            .find(|(_i, r)| r.get_name().eq(name))
-            .map(|(i, _r)| i)
+        self.ids.get(name).copied()
+    }
+
+    /// Returns the resource with the name.
+    pub fn get_by_name(&self, name: &str) -> Option<&T> {
+        self.get_id(name).and_then(|id| self.resources.get(id))
     }
 
     pub fn get_names(&self) -> Vec<&str> {
@@ -71,3 +101,82 @@ pub fn into_manager<T: ResourceDefinition>(
 
     ResourceManager::new(resources, T::R::default())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct TestResource(String);
+
+    impl Resource for TestResource {
+        fn get_name(&self) -> &str {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        let manager = create_manager();
+
+        assert_eq!(manager.get(0), &resource("a"));
+        assert_eq!(manager.get(1), &resource("b"));
+    }
+
+    #[test]
+    fn test_get_unknown_id_returns_default() {
+        assert_eq!(create_manager().get(2), &resource("default"));
+    }
+
+    #[test]
+    fn test_get_id() {
+        let manager = create_manager();
+
+        assert_eq!(manager.get_id("a"), Some(0));
+        assert_eq!(manager.get_id("b"), Some(1));
+    }
+
+    #[test]
+    fn test_get_id_of_unknown_name() {
+        assert_eq!(create_manager().get_id("c"), None);
+    }
+
+    #[test]
+    fn test_get_by_name() {
+        let manager = create_manager();
+
+        assert_eq!(manager.get_by_name("a"), Some(&resource("a")));
+        assert_eq!(manager.get_by_name("b"), Some(&resource("b")));
+    }
+
+    #[test]
+    fn test_get_by_unknown_name() {
+        assert_eq!(create_manager().get_by_name("c"), None);
+    }
+
+    #[test]
+    fn test_has_duplicates() {
+        let manager = ResourceManager::new(
+            vec![resource("a"), resource("b"), resource("a")],
+            resource("default"),
+        );
+
+        assert!(manager.has_duplicates());
+        assert_eq!(manager.len(), 3);
+        assert_eq!(manager.get_id("a"), Some(0));
+        assert_eq!(manager.get_id("b"), Some(1));
+    }
+
+    #[test]
+    fn test_has_no_duplicates() {
+        assert!(!create_manager().has_duplicates());
+    }
+
+    fn create_manager() -> ResourceManager<TestResource> {
+        ResourceManager::new(vec![resource("a"), resource("b")], resource("default"))
+    }
+
+    fn resource(name: &str) -> TestResource {
+        TestResource(name.to_string())
+    }
+}
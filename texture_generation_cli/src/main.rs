@@ -1,81 +1,783 @@
 #[macro_use]
 extern crate log;
 
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::io;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use structopt::StructOpt;
 use texture_generation::definition::generation::process::PostProcessDefinition;
 use texture_generation::definition::generation::TextureDefinition;
-use texture_generation::definition::read;
-use texture_generation::generation::io::{save_color_image, save_depth_image};
+use texture_generation::definition::{read, read_dir, read_from_reader};
+use texture_generation::generation::atlas::Atlas;
+use texture_generation::generation::io::{
+    export_obj, save_color_image, save_depth_image, save_luma_image, save_normal_image, ImageFormat,
+};
 use texture_generation::generation::process::PostProcess;
 use texture_generation::generation::TextureGenerator;
-use texture_generation::utils::logging::init_logging;
-use texture_generation::utils::resource::ResourceDefinition;
+use texture_generation::math::color::Color;
+use texture_generation::math::size::Size;
+use texture_generation::utils::logging::{get_log_level, init_logging, EventLogger, LogEvent};
+use texture_generation::utils::resource::{Resource, ResourceDefinition, ResourceManager};
 
 #[derive(StructOpt)]
 #[structopt(name = "texture_generator")]
 /// The arguments of the application.
 struct Cli {
-    /// The path of the texture definition.
+    /// The path of the texture definition or the directory of definitions in batch mode.
+    /// Reads the definition from stdin, if it is '-'.
     #[structopt(parse(from_os_str))]
     input: PathBuf,
 
-    /// The path of the output images.
+    /// The path of the output images or the output directory in batch mode.
     output: String,
 
-    /// The path of the post processing definition.
+    /// The path of the post processing definition. No post processing is applied, if it is empty.
     #[structopt(default_value = "")]
     post_processing: PathBuf,
 
-    /// The size of the output images.
-    #[structopt(default_value = "1024")]
+    /// The size of the output images. Repeat it to generate the texture in several sizes.
+    #[structopt(long = "size", default_value = "1024", number_of_values = 1)]
+    sizes: Vec<u32>,
+
+    /// Writes the key events as JSON lines to stdout.
+    #[structopt(long)]
+    json_logs: bool,
+
+    /// Also saves a normal map derived from the depth.
+    #[structopt(long)]
+    normal: bool,
+
+    /// Also exports the depth as a mesh in the OBJ format.
+    #[structopt(long)]
+    obj: bool,
+
+    /// Saves the luma of the colors as a grayscale image instead of the colors.
+    #[structopt(long)]
+    grayscale: bool,
+
+    /// Dithers the depth values to reduce banding.
+    #[structopt(long)]
+    dither: bool,
+
+    /// The format of the output images: png, tga, bmp or jpeg.
+    #[structopt(long, default_value = "png")]
+    format: ImageFormat,
+
+    /// Generates a texture for each definition in the input directory.
+    #[structopt(long)]
+    batch: bool,
+
+    /// Renders all definitions of the input directory into one image with this number of columns.
+    /// Each cell has the first size. The names of the cells are saved in a text file.
+    #[structopt(long)]
+    atlas: Option<u32>,
+
+    /// Logs a hash of the colors & depth values of each texture to detect changes.
+    #[structopt(long)]
+    hash: bool,
+
+    /// Only reads & converts the definitions without generating or saving textures.
+    #[structopt(long)]
+    check: bool,
+
+    /// Logs more details. Repeat it for even more details.
+    #[structopt(short, long, parse(from_occurrences))]
+    verbose: u8,
+
+    /// Only logs errors.
+    #[structopt(short, long)]
+    quiet: bool,
+
+    /// Overrides the background color of the definitions, e.g. #FF8000.
+    #[structopt(long)]
+    background: Option<String>,
+}
+
+/// The z value of the normals relative to the depth gradients.
+const NORMAL_Z: f32 = 10.0;
+/// The factor between depth & the z value of the vertices of the mesh.
+const OBJ_DEPTH_SCALE: f32 = 1.0;
+
+fn load_post_processing<W: Write>(
+    path: &Path,
+    logger: &mut EventLogger<W>,
+) -> Result<Vec<PostProcess>> {
+    if path.as_os_str().is_empty() {
+        return Ok(Vec::default());
+    } else if !path.exists() {
+        bail!("The post processing definition {:?} doesn't exist", path);
+    }
+
+    logger.log(LogEvent::Load {
+        path: path.display().to_string(),
+    });
+    let definition = PostProcessDefinition::read(path)?;
+    definition.iter().map(|d| d.convert()).collect()
+}
+
+/// Fails for an invalid background override, before anything is generated.
+fn validate_background(args: &Cli) -> Result<()> {
+    if let Some(background) = &args.background {
+        Color::convert(background)
+            .context(format!("Failed to convert the background {:?}", background))?;
+    }
+
+    Ok(())
+}
+
+/// Returns the directory of the definitions, which can be referenced by name.
+fn get_reference_dir(args: &Cli) -> &Path {
+    if args.batch || args.atlas.is_some() {
+        &args.input
+    } else {
+        match args.input.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        }
+    }
+}
+
+fn convert_definition(
+    args: &Cli,
+    definition: &TextureDefinition,
+    size: u32,
+) -> Result<TextureGenerator> {
+    convert_named_definition(args, definition, "texture", size)
+}
+
+fn convert_named_definition(
+    args: &Cli,
+    definition: &TextureDefinition,
+    name: &str,
+    size: u32,
+) -> Result<TextureGenerator> {
+    let mut definition = definition.resolve_references(get_reference_dir(args))?;
+
+    if let Some(background) = &args.background {
+        definition.set_background(background.clone());
+    }
+
+    definition.convert(name, size)
+}
+
+/// Converts the definition for each size without generating anything.
+fn check_definition(args: &Cli, definition: &TextureDefinition) -> Result<()> {
+    for size in &args.sizes {
+        convert_definition(args, definition, *size)?;
+    }
+
+    Ok(())
+}
+
+/// Generates & saves the texture for each size.
+/// The size is appended to the output path, if there are several sizes.
+fn generate_and_save<W: Write>(
+    args: &Cli,
+    definition: TextureDefinition,
+    output: &str,
+    post_processes: &[PostProcess],
+    logger: &mut EventLogger<W>,
+) -> Result<()> {
+    for size in &args.sizes {
+        let output = if args.sizes.len() > 1 {
+            format!("{}-{}", output, size)
+        } else {
+            output.to_string()
+        };
+
+        generate_and_save_size(args, &definition, *size, &output, post_processes, logger)?;
+    }
+
+    Ok(())
+}
+
+fn generate_and_save_size<W: Write>(
+    args: &Cli,
+    definition: &TextureDefinition,
     size: u32,
+    output: &str,
+    post_processes: &[PostProcess],
+    logger: &mut EventLogger<W>,
+) -> Result<()> {
+    let generator = convert_definition(args, definition, size)?;
+    let extension = args.format.get_extension();
+    let color_path = format!("{}-color.{}", output, extension);
+    let depth_path = format!("{}-depth.{}", output, extension);
+
+    logger.log(LogEvent::Generate {
+        name: generator.get_name().to_string(),
+        size: *generator.get_size(),
+    });
+
+    logger.log(LogEvent::PostProcess {
+        count: post_processes.len(),
+    });
+
+    let (data, stats) = generator.generate_with_stats(args.dither, post_processes);
+
+    info!("{}", stats);
+
+    if args.hash {
+        logger.log(LogEvent::Hash {
+            path: output.to_string(),
+            hash: format!("{:016x}", data.calculate_hash()),
+        });
+    }
+
+    logger.log(LogEvent::Save {
+        path: color_path.clone(),
+    });
+    if args.grayscale {
+        save_luma_image(&data, &color_path)?;
+    } else {
+        save_color_image(&data, &color_path)?;
+    }
+    logger.log(LogEvent::Save {
+        path: depth_path.clone(),
+    });
+    save_depth_image(&data, &depth_path)?;
+
+    if args.normal {
+        let normal_path = format!("{}-normal.{}", output, extension);
+        logger.log(LogEvent::Save {
+            path: normal_path.clone(),
+        });
+        save_normal_image(&data, &normal_path, NORMAL_Z)?;
+    }
+
+    if args.obj {
+        let obj_path = format!("{}.obj", output);
+        logger.log(LogEvent::Save {
+            path: obj_path.clone(),
+        });
+        export_obj(&data, OBJ_DEPTH_SCALE, Path::new(&obj_path))?;
+    }
+
+    Ok(())
 }
 
-fn load_post_processing(path: &Path) -> Result<Vec<PostProcess>> {
-    if path.exists() {
-        let definition = PostProcessDefinition::read(path)?;
-        let post_processes = definition.into_iter().map(|d| d.into()).collect();
-        Ok(post_processes)
+/// Reads the definition from the path or from stdin, if the path is '-'.
+fn read_definition(path: &Path) -> Result<TextureDefinition> {
+    if path == Path::new("-") {
+        read_from_reader(io::stdin())
     } else {
-        Ok(Vec::default())
+        read(path)
     }
 }
 
-fn main() -> Result<()> {
-    init_logging();
+fn run_single<W: Write>(args: &Cli, logger: &mut EventLogger<W>) -> Result<()> {
+    logger.log(LogEvent::Load {
+        path: args.input.display().to_string(),
+    });
+
+    let definition = read_definition(&args.input)?;
+    let post_processes: Vec<PostProcess> = load_post_processing(&args.post_processing, logger)?;
+
+    if args.check {
+        check_definition(args, &definition)?;
+        logger.log(LogEvent::Valid {
+            path: args.input.display().to_string(),
+        });
+        return Ok(());
+    }
 
+    generate_and_save(args, definition, &args.output, &post_processes, logger)
+}
+
+/// Generates a texture for each yaml file in the input directory.
+/// Errors of individual definitions are logged & skipped.
+/// In check mode the run fails, if any definition is invalid.
+fn run_batch<W: Write>(args: &Cli, logger: &mut EventLogger<W>) -> Result<()> {
+    logger.log(LogEvent::Load {
+        path: args.input.display().to_string(),
+    });
+
+    let post_processes: Vec<PostProcess> = load_post_processing(&args.post_processing, logger)?;
+
+    if !args.check {
+        fs::create_dir_all(&args.output).context(format!(
+            "Failed to create output directory {:?}",
+            args.output
+        ))?;
+    }
+
+    let mut definitions: Vec<(String, TextureDefinition)> = read_dir(&args.input)
+        .into_iter()
+        .filter(|(filename, _)| filename.ends_with(".yaml"))
+        .collect();
+    definitions.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut invalid = 0;
+
+    for (filename, definition) in definitions {
+        if args.check {
+            match check_definition(args, &definition) {
+                Ok(()) => logger.log(LogEvent::Valid { path: filename }),
+                Err(error) => {
+                    invalid += 1;
+                    logger.log(LogEvent::Error {
+                        message: format!("{:?} is invalid, because of {:?}", filename, error),
+                    });
+                }
+            }
+            continue;
+        }
+
+        let name = filename.trim_end_matches(".yaml");
+        let output = Path::new(&args.output).join(name);
+        let output = output.display().to_string();
+
+        if let Err(error) = generate_and_save(args, definition, &output, &post_processes, logger) {
+            logger.log(LogEvent::Error {
+                message: format!("Skip {:?}, because of {:?}", filename, error),
+            });
+        }
+    }
+
+    if invalid > 0 {
+        bail!("{} definitions are invalid", invalid);
+    }
+
+    Ok(())
+}
+
+/// Renders all definitions of the input directory into a grid.
+/// Errors of individual definitions are logged & skipped.
+fn run_atlas<W: Write>(args: &Cli, columns: u32, logger: &mut EventLogger<W>) -> Result<()> {
+    logger.log(LogEvent::Load {
+        path: args.input.display().to_string(),
+    });
+
+    let size = args.sizes[0];
+    let mut definitions: Vec<(String, TextureDefinition)> = read_dir(&args.input)
+        .into_iter()
+        .filter(|(filename, _)| filename.ends_with(".yaml"))
+        .collect();
+    definitions.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut generators = Vec::with_capacity(definitions.len());
+
+    for (filename, definition) in definitions {
+        let name = filename.trim_end_matches(".yaml");
+
+        match convert_named_definition(args, &definition, name, size) {
+            Ok(generator) => generators.push(generator),
+            Err(error) => logger.log(LogEvent::Error {
+                message: format!("Skip {:?}, because of {:?}", filename, error),
+            }),
+        }
+    }
+
+    let manager = ResourceManager::new(generators, TextureGenerator::default());
+    let atlas = Atlas::from_manager(&manager, Size::square(size), columns)?;
+    let color_path = format!("{}-color.{}", args.output, args.format.get_extension());
+    let labels_path = format!("{}-labels.txt", args.output);
+
+    logger.log(LogEvent::Save {
+        path: color_path.clone(),
+    });
+    save_color_image(atlas.get_texture(), &color_path)?;
+    logger.log(LogEvent::Save {
+        path: labels_path.clone(),
+    });
+    fs::write(&labels_path, atlas.format_labels())
+        .context(format!("Failed to save the labels {:?}", labels_path))?;
+
+    Ok(())
+}
+
+fn run<W: Write>(args: &Cli, logger: &mut EventLogger<W>) -> Result<()> {
+    validate_background(args)?;
+
+    if let Some(columns) = args.atlas {
+        run_atlas(args, columns, logger)?;
+    } else if args.batch {
+        run_batch(args, logger)?;
+    } else {
+        run_single(args, logger)?;
+    }
+
+    logger.log(LogEvent::Finished);
+
+    Ok(())
+}
+
+fn run_and_log_error<W: Write>(args: &Cli, logger: &mut EventLogger<W>) -> Result<()> {
+    let result = run(args, logger);
+
+    if let Err(error) = &result {
+        logger.log(LogEvent::Error {
+            message: format!("{:?}", error),
+        });
+    }
+
+    result
+}
+
+fn main() -> Result<()> {
     let args = Cli::from_args();
 
+    init_logging(get_log_level(args.verbose, args.quiet));
+
     info!(
-        "size={} input={:?} output={:?} post_processing={:?}",
-        args.size, args.input, args.output, args.post_processing
+        "sizes={:?} input={:?} output={:?} post_processing={:?}",
+        args.sizes, args.input, args.output, args.post_processing
     );
 
-    info!("Load texture definition");
+    if args.json_logs {
+        run_and_log_error(&args, &mut EventLogger::Json(io::stdout()))
+    } else {
+        run_and_log_error(&args, &mut EventLogger::<io::Stdout>::Text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use texture_generation::generation::io::{load_color_image, load_grayscale_image};
+    use texture_generation::math::color::GREEN;
+    use texture_generation::math::point::Point;
+
+    #[test]
+    fn test_json_events_of_generation() {
+        let output = std::env::temp_dir().join("texture_generator_json_logs");
+        let output = output.to_str().unwrap().to_string();
+        let args = test_args("../resources/textures/brick.yaml", &output);
+        let mut logger = EventLogger::Json(Vec::new());
 
-    let definition: TextureDefinition = read(&args.input)?;
-    let generator: TextureGenerator = definition.convert("texture", args.size)?;
-    let color_path = format!("{}-color.png", args.output);
-    let depth_path = format!("{}-depth.png", args.output);
+        run_and_log_error(&args, &mut logger).unwrap();
 
-    info!("Load post processing definition");
+        let lines = String::from_utf8(logger.get_writer().unwrap().clone()).unwrap();
+        let events: Vec<LogEvent> = lines
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
 
-    let post_processes: Vec<PostProcess> = load_post_processing(&args.post_processing)?;
+        assert_eq!(
+            events,
+            vec![
+                LogEvent::Load {
+                    path: "../resources/textures/brick.yaml".to_string()
+                },
+                LogEvent::Generate {
+                    name: "texture".to_string(),
+                    size: Size::square(16)
+                },
+                LogEvent::PostProcess { count: 0 },
+                LogEvent::Save {
+                    path: format!("{}-color.png", output)
+                },
+                LogEvent::Save {
+                    path: format!("{}-depth.png", output)
+                },
+                LogEvent::Finished,
+            ]
+        );
+    }
 
-    info!("Texture generation");
+    #[test]
+    fn test_json_error_event() {
+        let args = test_args("unknown.yaml", "unknown");
+        let mut logger = EventLogger::Json(Vec::new());
 
-    let mut data = generator.generate();
+        assert!(run_and_log_error(&args, &mut logger).is_err());
 
-    info!("Post processing. N={}", post_processes.len());
+        let lines = String::from_utf8(logger.get_writer().unwrap().clone()).unwrap();
+        let events: Vec<LogEvent> = lines
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
 
-    data.apply(&post_processes);
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[1], LogEvent::Error { .. }));
+    }
 
-    save_color_image(&data, &color_path);
-    save_depth_image(&data, &depth_path);
+    #[test]
+    fn test_batch_generation() {
+        let dir = std::env::temp_dir().join("texture_generator_batch");
+        let input = dir.join("input");
+        let output = dir.join("output");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&input).unwrap();
 
-    info!("Finished");
+        for name in &["brick.yaml", "sett.yaml"] {
+            fs::copy(
+                Path::new("../resources/textures").join(name),
+                input.join(name),
+            )
+            .unwrap();
+        }
 
-    Ok(())
+        fs::write(input.join("invalid.yaml"), "invalid").unwrap();
+
+        let args = Cli {
+            json_logs: false,
+            batch: true,
+            ..test_args(input.clone(), output.to_str().unwrap())
+        };
+
+        run_and_log_error(&args, &mut EventLogger::<Vec<u8>>::Text).unwrap();
+
+        let mut color_files: Vec<String> = fs::read_dir(&output)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_str().unwrap().to_string())
+            .filter(|filename| filename.ends_with("-color.png"))
+            .collect();
+        color_files.sort();
+
+        assert_eq!(color_files, vec!["brick-color.png", "sett-color.png"]);
+    }
+
+    #[test]
+    fn test_atlas() {
+        let dir = std::env::temp_dir().join("texture_generator_atlas");
+        let input = dir.join("input");
+        let output = dir.join("atlas");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&input).unwrap();
+
+        for name in &["brick.yaml", "sett.yaml"] {
+            fs::copy(
+                Path::new("../resources/textures").join(name),
+                input.join(name),
+            )
+            .unwrap();
+        }
+
+        let args = Cli {
+            json_logs: false,
+            atlas: Some(1),
+            ..test_args(input, output.to_str().unwrap())
+        };
+
+        run_and_log_error(&args, &mut EventLogger::<Vec<u8>>::Text).unwrap();
+
+        let (size, _colors) = load_color_image(&dir.join("atlas-color.png")).unwrap();
+        let labels = fs::read_to_string(dir.join("atlas-labels.txt")).unwrap();
+
+        assert_eq!(size, Size::new(16, 32));
+        assert_eq!(labels, "0 0 brick\n0 16 sett\n");
+    }
+
+    #[test]
+    fn test_background_override() {
+        let args = create_args_with_background("#00FF00");
+        let definition: TextureDefinition = read(&args.input).unwrap();
+
+        let generator = convert_definition(&args, &definition, 256).unwrap();
+        let texture = generator.generate();
+
+        for corner in &[(0, 0), (255, 0), (0, 255), (255, 255)] {
+            let point = Point::new(corner.0, corner.1);
+            assert_eq!(texture.get_color(&point), Some(GREEN));
+        }
+    }
+
+    #[test]
+    fn test_invalid_background_override() {
+        let args = create_args_with_background("not a color");
+        let mut logger = EventLogger::Json(Vec::new());
+
+        assert!(run_and_log_error(&args, &mut logger).is_err());
+
+        let lines = String::from_utf8(logger.get_writer().unwrap().clone()).unwrap();
+        assert_eq!(lines.lines().count(), 1);
+        assert!(lines.contains("Failed to convert the background"));
+    }
+
+    fn create_args_with_background(background: &str) -> Cli {
+        Cli {
+            sizes: vec![256],
+            background: Some(background.to_string()),
+            ..test_args("../resources/textures/brick.yaml", "unused")
+        }
+    }
+
+    #[test]
+    fn test_multiple_sizes() {
+        let dir = std::env::temp_dir().join("texture_generator_sizes");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let output = dir.join("brick").to_str().unwrap().to_string();
+        let args = Cli {
+            sizes: vec![16, 32],
+            ..test_args("../resources/textures/brick.yaml", &output)
+        };
+        let mut logger = EventLogger::Json(Vec::new());
+
+        run_and_log_error(&args, &mut logger).unwrap();
+
+        let lines = String::from_utf8(logger.get_writer().unwrap().clone()).unwrap();
+        let sizes: Vec<Size> = lines
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .filter_map(|event| match event {
+                LogEvent::Generate { size, .. } => Some(size),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(sizes, vec![Size::square(16), Size::square(32)]);
+
+        for size in &[16, 32] {
+            let color_path = format!("{}-{}-color.png", output, size);
+            let depth_path = format!("{}-{}-depth.png", output, size);
+            let (color_size, _colors) = load_color_image(Path::new(&color_path)).unwrap();
+            let (depth_size, _depth) = load_grayscale_image(Path::new(&depth_path)).unwrap();
+
+            assert_eq!(color_size, Size::square(*size));
+            assert_eq!(depth_size, Size::square(*size));
+        }
+    }
+
+    #[test]
+    fn test_too_big_size() {
+        let args = Cli {
+            sizes: vec![70000],
+            ..create_args_with_background("#00FF00")
+        };
+        let mut logger = EventLogger::Json(Vec::new());
+
+        let error = run_and_log_error(&args, &mut logger).unwrap_err();
+
+        assert!(error.to_string().contains("too big"));
+    }
+
+    #[test]
+    fn test_missing_post_processing() {
+        let args = Cli {
+            post_processing: PathBuf::from("1024"),
+            ..create_args_with_background("#00FF00")
+        };
+        let mut logger = EventLogger::Json(Vec::new());
+
+        let error = run_and_log_error(&args, &mut logger).unwrap_err();
+
+        assert!(error.to_string().contains("doesn't exist"));
+    }
+
+    #[test]
+    fn test_check_valid_definition() {
+        let output = std::env::temp_dir().join("texture_generator_check_valid");
+        let output = output.to_str().unwrap().to_string();
+        let args = create_check_args(PathBuf::from("../resources/textures/brick.yaml"), &output);
+        let mut logger = EventLogger::Json(Vec::new());
+
+        run_and_log_error(&args, &mut logger).unwrap();
+
+        let lines = String::from_utf8(logger.get_writer().unwrap().clone()).unwrap();
+        let events: Vec<LogEvent> = lines
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(
+            events,
+            vec![
+                LogEvent::Load {
+                    path: "../resources/textures/brick.yaml".to_string()
+                },
+                LogEvent::Valid {
+                    path: "../resources/textures/brick.yaml".to_string()
+                },
+                LogEvent::Finished,
+            ]
+        );
+        assert!(!Path::new(&format!("{}-color.png", output)).exists());
+    }
+
+    #[test]
+    fn test_check_invalid_definition() {
+        let dir = std::env::temp_dir().join("texture_generator_check_invalid");
+        fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("invalid.yaml");
+        let brick = fs::read_to_string("../resources/textures/brick.yaml").unwrap();
+        fs::write(&input, brick.replace("#AAAAAA", "not a color")).unwrap();
+        let output = dir.join("invalid").to_str().unwrap().to_string();
+        let args = create_check_args(input, &output);
+        let mut logger = EventLogger::Json(Vec::new());
+
+        assert!(run_and_log_error(&args, &mut logger).is_err());
+        assert!(!Path::new(&format!("{}-color.png", output)).exists());
+    }
+
+    fn create_check_args(input: PathBuf, output: &str) -> Cli {
+        Cli {
+            check: true,
+            ..test_args(input, output)
+        }
+    }
+
+    #[test]
+    fn test_hash() {
+        let first = generate_hash(None);
+
+        assert_eq!(generate_hash(None), first);
+        assert_ne!(generate_hash(Some("#00FF00".to_string())), first);
+    }
+
+    fn generate_hash(background: Option<String>) -> String {
+        let output = std::env::temp_dir().join("texture_generator_hash");
+        let output = output.to_str().unwrap().to_string();
+        let args = Cli {
+            hash: true,
+            background,
+            ..test_args("../resources/textures/brick.yaml", &output)
+        };
+        let mut logger = EventLogger::Json(Vec::new());
+
+        run_and_log_error(&args, &mut logger).unwrap();
+
+        let lines = String::from_utf8(logger.get_writer().unwrap().clone()).unwrap();
+        lines
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .find_map(|event| match event {
+                LogEvent::Hash { path, hash } if path == output => Some(hash),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_read_definition_from_reader() {
+        let path = Path::new("../resources/textures/brick.yaml");
+        let string = fs::read_to_string(path).unwrap();
+
+        let definition: TextureDefinition =
+            read_from_reader(io::Cursor::new(string.into_bytes())).unwrap();
+
+        assert_eq!(definition, read_definition(path).unwrap());
+        assert_eq!(
+            definition.convert("texture", 16).unwrap(),
+            read_definition(path)
+                .unwrap()
+                .convert("texture", 16)
+                .unwrap()
+        );
+    }
+
+    fn test_args<P: Into<PathBuf>>(input: P, output: &str) -> Cli {
+        Cli {
+            input: input.into(),
+            output: output.to_string(),
+            post_processing: PathBuf::from(""),
+            sizes: vec![16],
+            json_logs: true,
+            normal: false,
+            obj: false,
+            grayscale: false,
+            dither: false,
+            format: ImageFormat::Png,
+            batch: false,
+            atlas: None,
+            hash: false,
+            check: false,
+            verbose: 0,
+            quiet: false,
+            background: None,
+        }
+    }
 }
@@ -17,6 +17,8 @@ pub struct FurnitureRenderer<'a> {
     furniture_map: &'a FurnitureMap2d,
     tilemap: &'a Tilemap2d,
     cell_size: Size,
+    /// Is added to the depth of each [`FurnitureStyle`](crate::rendering::style::furniture::FurnitureStyle).
+    depth_offset: u8,
 }
 
 impl<'a> FurnitureRenderer<'a> {
@@ -25,12 +27,14 @@ impl<'a> FurnitureRenderer<'a> {
         furniture_map: &'a FurnitureMap2d,
         tilemap: &'a Tilemap2d,
         tile_size: u32,
+        depth_offset: u8,
     ) -> Self {
         FurnitureRenderer {
             resources,
             furniture_map,
             tilemap,
             cell_size: Size::square(furniture_map.convert_from_tile_size(tile_size)),
+            depth_offset,
         }
     }
 
@@ -40,22 +44,33 @@ impl<'a> FurnitureRenderer<'a> {
         info!("Render {} furniture", furniture_list.len());
 
         for (id, furniture) in furniture_list {
-            let aabb = self.calculate_aabb(*id, furniture);
+            let aabb = match self.calculate_aabb(furniture) {
+                Some(aabb) => aabb,
+                None => {
+                    warn!(
+                        "Skip furniture with id '{}', because it is outside the tilemap",
+                        id
+                    );
+                    continue;
+                }
+            };
             let aabb_data = AabbData::from_two_aabb(texture.get_aabb(), aabb);
-            info!(
+            trace!(
                 "Render furniture with id '{}' & side {}",
-                id, furniture.front_side
+                id,
+                furniture.front_side
             );
             let data = Data::with_orientation(0, *id, aabb_data, furniture.front_side);
 
             self.resources
                 .furniture_styles
                 .get(furniture.style_id)
-                .render(self.resources, texture, &data);
+                .render(self.resources, texture, &data, self.depth_offset);
         }
     }
 
-    fn calculate_aabb(&self, id: usize, furniture: &Furniture) -> AABB {
+    /// Calculates the [`AABB`] of the [`Furniture`] or None, if it is partly outside the [`Tilemap2d`].
+    fn calculate_aabb(&self, furniture: &Furniture) -> Option<AABB> {
         let start_cell_xy = furniture.aabb.start();
         let start = start_cell_xy * self.cell_size;
         let size = furniture.aabb.size() * self.cell_size;
@@ -63,17 +78,13 @@ impl<'a> FurnitureRenderer<'a> {
         let start_tile_xy = self.furniture_map.convert_to_tile(start_cell_xy);
         let start_tile = self
             .tilemap
-            .get_size()
-            .to_index(&start_tile_xy)
-            .unwrap_or_else(|| panic!("Start point of furniture {} is outside tilemap!", id));
+            .tile_index(start_tile_xy.x as u32, start_tile_xy.y as u32)?;
 
         let end_cell_xy = start_cell_xy + furniture.aabb.size() - Size::square(1);
         let end_tile_xy = self.furniture_map.convert_to_tile(end_cell_xy);
         let end_tile = self
             .tilemap
-            .get_size()
-            .to_index(&end_tile_xy)
-            .unwrap_or_else(|| panic!("End point of furniture {} is outside tilemap!", id));
+            .tile_index(end_tile_xy.x as u32, end_tile_xy.y as u32)?;
 
         let top_border = self.get_border(start_cell_xy, start_tile, Top);
         let left_border = self.get_border(start_cell_xy, start_tile, Left);
@@ -86,7 +97,7 @@ impl<'a> FurnitureRenderer<'a> {
             size.height() - top_border - bottom_border,
         );
 
-        AABB::new(start, size)
+        Some(AABB::new(start, size))
     }
 
     fn get_border(&self, cell: Point, tile: usize, side: Side) -> u32 {
@@ -116,12 +127,72 @@ mod tests {
     use crate::rendering::style::wall::WallStyle;
     use crate::tilemap::border::Border;
     use crate::tilemap::tile::Tile;
+    use log::{Level, LevelFilter, Log, Metadata, Record};
+    use std::cell::RefCell;
     use texture_generation::generation::component::rendering::RenderingComponent;
     use texture_generation::generation::component::Component;
     use texture_generation::generation::data::texture::Texture;
     use texture_generation::math::color::{Color, BLACK, GREEN, RED};
     use texture_generation::utils::resource::ResourceManager;
 
+    thread_local! {
+        static RECORDS: RefCell<Vec<(Level, String)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Captures the level & target of the records per thread.
+    struct CapturingLogger;
+
+    impl Log for CapturingLogger {
+        fn enabled(&self, _metadata: &Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record) {
+            RECORDS.with(|records| {
+                records
+                    .borrow_mut()
+                    .push((record.level(), record.target().to_string()))
+            });
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: CapturingLogger = CapturingLogger;
+
+    #[test]
+    fn test_render_furniture_only_logs_phase_at_info() {
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(LevelFilter::Trace);
+
+        let resources = create_resources();
+        let size = Size::new(1, 2);
+        let tilemap = Tilemap2d::default(size, Tile::Empty);
+        let mut furniture_map = FurnitureMap2d::empty(size);
+
+        for y in 0..3 {
+            furniture_map
+                .add(Furniture::without_front(0, Point::new(y % 2, y), Size::square(1)).unwrap());
+        }
+
+        let mut texture = Texture::new(Size::new(8, 16), BLACK);
+        let renderer = FurnitureRenderer::new(&resources, &furniture_map, &tilemap, 8, 0);
+
+        RECORDS.with(|records| records.borrow_mut().clear());
+        renderer.render(&mut texture);
+
+        let info_targets: Vec<String> = RECORDS.with(|records| {
+            records
+                .borrow()
+                .iter()
+                .filter(|(level, _)| *level <= Level::Info)
+                .map(|(_, target)| target.clone())
+                .collect()
+        });
+
+        assert_eq!(info_targets, vec!["tilemap::rendering::furniture"]);
+    }
+
     #[test]
     fn test_render_furniture_covering_whole_tiles() {
         let resources = create_resources();
@@ -138,7 +209,7 @@ mod tests {
 
         let mut texture = Texture::new(Size::new(8, 16), BLACK);
 
-        let renderer = FurnitureRenderer::new(&resources, &furniture_map, &tilemap, 8);
+        let renderer = FurnitureRenderer::new(&resources, &furniture_map, &tilemap, 8, 0);
 
         renderer.render(&mut texture);
 
@@ -203,7 +274,7 @@ mod tests {
 
         let mut texture = Texture::new(Size::new(8, 8), BLACK);
 
-        let renderer = FurnitureRenderer::new(&resources, &furniture_map, &tilemap, 8);
+        let renderer = FurnitureRenderer::new(&resources, &furniture_map, &tilemap, 8, 0);
 
         renderer.render(&mut texture);
 
@@ -236,6 +307,109 @@ mod tests {
         assert_eq!(texture.get_depth_data(), &depth);
     }
 
+    #[test]
+    fn test_render_furniture_with_different_front_sides() {
+        let mut resources = Resources::empty();
+        resources.furniture_styles = ResourceManager::new(
+            vec![create_fixed_furniture("fixed", GREEN, Size::new(6, 2))],
+            FurnitureStyle::default(),
+        );
+
+        #[rustfmt::skip]
+        let right = vec![
+            BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK,
+            BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK,
+            BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK,
+            BLACK, GREEN, GREEN, GREEN, GREEN, GREEN, GREEN, BLACK,
+            BLACK, GREEN, GREEN, GREEN, GREEN, GREEN, GREEN, BLACK,
+            BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK,
+            BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK,
+            BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK,
+        ];
+
+        #[rustfmt::skip]
+        let top = vec![
+            BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK,
+            BLACK, BLACK, BLACK, GREEN, GREEN, BLACK, BLACK, BLACK,
+            BLACK, BLACK, BLACK, GREEN, GREEN, BLACK, BLACK, BLACK,
+            BLACK, BLACK, BLACK, GREEN, GREEN, BLACK, BLACK, BLACK,
+            BLACK, BLACK, BLACK, GREEN, GREEN, BLACK, BLACK, BLACK,
+            BLACK, BLACK, BLACK, GREEN, GREEN, BLACK, BLACK, BLACK,
+            BLACK, BLACK, BLACK, GREEN, GREEN, BLACK, BLACK, BLACK,
+            BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK,
+        ];
+
+        assert_eq!(
+            render_with_front_side(&resources, Right).get_color_data(),
+            &right
+        );
+        assert_eq!(
+            render_with_front_side(&resources, Left).get_color_data(),
+            &right
+        );
+        assert_eq!(
+            render_with_front_side(&resources, Top).get_color_data(),
+            &top
+        );
+        assert_eq!(
+            render_with_front_side(&resources, Bottom).get_color_data(),
+            &top
+        );
+    }
+
+    #[test]
+    fn test_render_furniture_with_different_styles() {
+        let mut resources = Resources::empty();
+        resources.furniture_styles = ResourceManager::new(
+            vec![create_furniture("f0", GREEN), create_furniture("f1", RED)],
+            FurnitureStyle::default(),
+        );
+
+        let size = Size::new(2, 1);
+        let tilemap = Tilemap2d::default(size, Tile::Empty);
+        let mut furniture_map = FurnitureMap2d::empty(size);
+        furniture_map.add(Furniture::without_front(1, Point::new(0, 0), Size::square(2)).unwrap());
+        furniture_map.add(Furniture::without_front(0, Point::new(2, 0), Size::square(2)).unwrap());
+
+        let mut texture = Texture::new(Size::new(4, 2), BLACK);
+        FurnitureRenderer::new(&resources, &furniture_map, &tilemap, 2, 0).render(&mut texture);
+
+        #[rustfmt::skip]
+        let result = vec![
+            RED, RED, GREEN, GREEN,
+            RED, RED, GREEN, GREEN,
+        ];
+
+        assert_eq!(texture.get_color_data(), &result);
+    }
+
+    #[test]
+    fn test_skip_furniture_outside_tilemap() {
+        let resources = create_resources();
+        let tilemap = Tilemap2d::default(Size::square(1), Tile::Empty);
+        let mut furniture_map = FurnitureMap2d::empty(Size::new(2, 1));
+        furniture_map.add(Furniture::without_front(0, Point::new(0, 0), Size::square(2)).unwrap());
+        furniture_map.add(Furniture::without_front(0, Point::new(1, 0), Size::square(2)).unwrap());
+        furniture_map.add(Furniture::without_front(0, Point::new(2, 0), Size::square(2)).unwrap());
+
+        let mut texture = Texture::new(Size::square(2), BLACK);
+        FurnitureRenderer::new(&resources, &furniture_map, &tilemap, 2, 0).render(&mut texture);
+
+        assert_eq!(texture.get_color_data(), &vec![GREEN; 4]);
+    }
+
+    fn render_with_front_side(resources: &Resources, side: Side) -> Texture {
+        let size = Size::square(1);
+        let tilemap = Tilemap2d::default(size, Tile::Empty);
+        let mut furniture_map = FurnitureMap2d::empty(size);
+        furniture_map.add(Furniture::new(0, Point::new(0, 0), Size::square(2), side).unwrap());
+
+        let mut texture = Texture::new(Size::square(8), BLACK);
+        FurnitureRenderer::new(resources, &furniture_map, &tilemap, 8, 0).render(&mut texture);
+
+        texture
+    }
+
     fn create_resources() -> Resources {
         let mut resources = Resources::empty();
         resources.furniture_styles = ResourceManager::new(
@@ -265,4 +439,11 @@ mod tests {
         let component = Component::Rendering(Box::new(rendering));
         FurnitureStyle::new(name, FurnitureSize::Fill, 100, component, FrontStyle::None)
     }
+
+    fn create_fixed_furniture(name: &str, color: Color, size: Size) -> FurnitureStyle {
+        let rendering = RenderingComponent::new_fill_area(color, 1);
+        let component = Component::Rendering(Box::new(rendering));
+        let size = FurnitureSize::Fixed(size);
+        FurnitureStyle::new(name, size, 100, component, FrontStyle::None)
+    }
 }
@@ -13,7 +13,7 @@ use texture_generation::generation::data::aabb::AabbData;
 use texture_generation::generation::data::texture::Texture;
 use texture_generation::generation::data::Data;
 use texture_generation::math::aabb::AABB;
-use texture_generation::math::color::BLACK;
+use texture_generation::math::color::{Color, BLACK};
 use texture_generation::math::point::Point;
 use texture_generation::math::side::Side;
 use texture_generation::math::size::Size;
@@ -22,11 +22,54 @@ pub mod furniture;
 pub mod node;
 pub mod resource;
 pub mod style;
+pub mod thumbnail;
+
+/// The default base depth of [`Tile::Floor`], which is the lowest band.
+pub const FLOOR_DEPTH: u8 = 1;
+/// The default offset added to the depth of each [`FurnitureStyle`](crate::rendering::style::furniture::FurnitureStyle),
+/// which is the band above the floor.
+pub const FURNITURE_DEPTH: u8 = 32;
+/// The default base depth of walls, doors, windows & nodes, which is the band above the furniture.
+pub const BORDER_DEPTH: u8 = 64;
+
+/// Defines the base depth of each layer of a tilemap, so that they are composited correctly.
+///
+/// Each layer adds its base depth to the depth of its components.
+/// [`Tile::Solid`] uses the wall height of the [`Renderer`] instead.
+/// With the default values each layer has its own band of 32 depth values.
+/// So borders are above furniture & furniture is above floors,
+/// as long as the components add less depth than the band. Tall walls are above all of them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DepthLayers {
+    /// The base depth of [`Tile::Floor`].
+    pub floor: u8,
+    /// The base depth of walls, doors, windows & nodes.
+    pub border: u8,
+    /// The offset added to the depth of each furniture style.
+    pub furniture: u8,
+}
+
+impl DepthLayers {
+    pub fn new(floor: u8, border: u8, furniture: u8) -> Self {
+        DepthLayers {
+            floor,
+            border,
+            furniture,
+        }
+    }
+}
+
+impl Default for DepthLayers {
+    fn default() -> Self {
+        Self::new(FLOOR_DEPTH, BORDER_DEPTH, FURNITURE_DEPTH)
+    }
+}
 
 /// Renders a [`Tilemap2d`] in a specific style.
 pub struct Renderer {
     tile_size: u32,
     wall_height: u8,
+    depth_layers: DepthLayers,
     resources: Resources,
 }
 
@@ -35,10 +78,20 @@ impl Renderer {
         Renderer {
             tile_size,
             wall_height,
+            depth_layers: DepthLayers::default(),
             resources,
         }
     }
 
+    pub fn get_depth_layers(&self) -> &DepthLayers {
+        &self.depth_layers
+    }
+
+    /// Overwrites the base depth of the layers.
+    pub fn set_depth_layers(&mut self, depth_layers: DepthLayers) {
+        self.depth_layers = depth_layers;
+    }
+
     pub fn get_resources(&self) -> &Resources {
         &self.resources
     }
@@ -51,13 +104,20 @@ impl Renderer {
     pub fn render(&self, tilemap: &Tilemap2d, furniture_map: Option<&FurnitureMap2d>) -> Texture {
         let tile_size = Size::square(self.tile_size);
         let mut texture = Texture::for_tilemap(tilemap.get_size(), tile_size, BLACK);
+        let region = Region::full(tilemap);
 
-        self.render_tiles(tilemap, tile_size, &mut texture);
-        self.render_borders(tilemap, &mut texture);
+        self.render_tiles(tilemap, &region, tile_size, &mut texture);
+        self.render_borders(tilemap, &region, &mut texture);
 
         if let Some(furniture_map) = furniture_map {
-            FurnitureRenderer::new(&self.resources, furniture_map, tilemap, self.tile_size)
-                .render(&mut texture);
+            FurnitureRenderer::new(
+                &self.resources,
+                furniture_map,
+                tilemap,
+                self.tile_size,
+                self.depth_layers.furniture,
+            )
+            .render(&mut texture);
         }
 
         texture.apply(&self.resources.post_processes);
@@ -67,7 +127,49 @@ impl Renderer {
         texture
     }
 
-    fn render_tiles(&self, tilemap: &Tilemap2d, tile_size: Size, texture: &mut Texture) {
+    /// Renders only the tiles inside the area in tile coordinates, so that huge tilemaps can be rendered in chunks.
+    ///
+    /// The area is extended by 1 tile to render the [`Border`]s on its edge correctly
+    /// & to give the post processes the neighbors of the edge pixels.
+    /// Furniture is not rendered.
+    pub fn render_region(&self, tilemap: &Tilemap2d, area: &AABB) -> Texture {
+        let map_area = AABB::with_size(tilemap.get_size());
+        let area = area.limit(&map_area);
+        let padded_start = (area.start() - 1).max(&map_area.start());
+        let padded_end = (area.end() + 1).min(&map_area.end());
+        let padded_size = Size::new(
+            (padded_end.x - padded_start.x).max(0) as u32,
+            (padded_end.y - padded_start.y).max(0) as u32,
+        );
+        let padded_area = AABB::new(padded_start, padded_size);
+        let sub_tilemap = tilemap.extract(&padded_area);
+        let region = Region {
+            start: padded_start,
+            size: tilemap.get_size(),
+        };
+        let tile_size = Size::square(self.tile_size);
+        let mut padded_texture = Texture::for_tilemap(padded_size, tile_size, BLACK);
+
+        self.render_tiles(&sub_tilemap, &region, tile_size, &mut padded_texture);
+        self.render_borders(&sub_tilemap, &region, &mut padded_texture);
+        padded_texture.apply(&self.resources.post_processes);
+
+        let offset = (area.start() - padded_start) * self.tile_size;
+        let mut texture = Texture::for_tilemap(area.size(), tile_size, BLACK);
+        copy_texture(&padded_texture, offset, &mut texture);
+
+        info!("Finished rendering region {:?}", area);
+
+        texture
+    }
+
+    fn render_tiles(
+        &self,
+        tilemap: &Tilemap2d,
+        region: &Region,
+        tile_size: Size,
+        texture: &mut Texture,
+    ) {
         info!("Render tiles");
         let tiles = tilemap.get_size();
         let mut start = Point::default();
@@ -79,12 +181,24 @@ impl Renderer {
             for _x in 0..tiles.width() {
                 let tile = tilemap.get_tile(index);
                 let aabb = AABB::new(start, tile_size);
-                let data = Data::with_global_id(index, aabb);
+                let data = Data::with_global_id(region.get_tile_index(tiles, index), aabb);
 
                 match tile {
                     Tile::Empty => {}
-                    Tile::Floor(id) => self.render_texture(texture, data, id, 1),
-                    Tile::Solid(id) => self.render_texture(texture, data, id, self.wall_height),
+                    Tile::Floor(id) => {
+                        self.render_texture(texture, data, id, self.depth_layers.floor)
+                    }
+                    Tile::Solid(id) => {
+                        self.render_texture(texture, data, id, self.wall_height);
+
+                        if let Some(cap) = self.get_cap(tilemap, index) {
+                            render_cap(texture, &aabb, cap);
+                        }
+                    }
+                    Tile::Ramp { id, direction } => {
+                        self.render_texture(texture, data, id, self.depth_layers.floor);
+                        self.render_ramp(texture, &aabb, direction);
+                    }
                 }
 
                 start.x += tile_size.width() as i32;
@@ -95,44 +209,85 @@ impl Renderer {
         }
     }
 
-    fn render_borders(&self, tilemap: &Tilemap2d, mut texture: &mut Texture) {
+    /// Returns the cap of the first wall around the tile, which has one.
+    fn get_cap(&self, tilemap: &Tilemap2d, index: usize) -> Option<Color> {
+        Side::iterator().find_map(|side| match tilemap.get_border(index, *side) {
+            Border::Wall(id) => self.resources.wall_styles.get(id).get_cap(),
+            _ => None,
+        })
+    }
+
+    /// Adds a depth gradient from the floor to the wall height, which rises towards `direction`.
+    fn render_ramp(&self, texture: &mut Texture, aabb: &AABB, direction: Side) {
+        let width = texture.get_size().width() as usize;
+        let start = aabb.start();
+        let size = aabb.size();
+        let rise = self.wall_height.saturating_sub(self.depth_layers.floor) as u32;
+        let steps = (self.tile_size - 1).max(1);
+        let depth = texture.get_depth_data_mut();
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                let step = match direction {
+                    Side::Top => size.height() - 1 - y,
+                    Side::Left => size.width() - 1 - x,
+                    Side::Bottom => y,
+                    Side::Right => x,
+                };
+                let index = (start.y as usize + y as usize) * width + start.x as usize + x as usize;
+                let offset = (rise * step / steps) as u8;
+
+                depth[index] = depth[index].saturating_add(offset);
+            }
+        }
+    }
+
+    fn render_borders(&self, tilemap: &Tilemap2d, region: &Region, mut texture: &mut Texture) {
         info!("Render borders");
-        texture.set_base_depth(1);
+        texture.set_base_depth(self.depth_layers.border);
         let nodes = calculate_node_styles(
             &self.resources.node_styles,
             &self.resources.wall_styles,
             tilemap,
         );
-        self.render_horizontal_borders(tilemap, &nodes, &mut texture);
-        self.render_vertical_borders(tilemap, &nodes, &mut texture);
-        self.render_nodes(tilemap, &nodes, &mut texture);
+        let spans = DoorSpans::new(tilemap);
+        self.render_horizontal_borders(tilemap, region, &nodes, &spans, &mut texture);
+        self.render_vertical_borders(tilemap, region, &nodes, &spans, &mut texture);
+        self.render_nodes(tilemap, &nodes, &spans, &mut texture);
     }
 
     fn render_horizontal_borders(
         &self,
         tilemap: &Tilemap2d,
+        region: &Region,
         nodes: &[NodeStatus],
+        spans: &DoorSpans,
         texture: &mut Texture,
     ) {
         let size = get_horizontal_borders_size(tilemap.get_size());
         let borders = tilemap.get_horizontal_borders();
         let mut start = Point::default();
         let step = self.tile_size as i32;
+        let full_size = get_horizontal_borders_size(region.size);
         let mut index = 0;
-        let mut start_instance_id = 222;
         let aabb_data = AabbData::from_one_aabb(texture.get_aabb());
 
         for y in 0..size.height() {
             start.x = 0;
 
-            for _x in 0..size.width() {
+            for x in 0..size.width() {
+                let global_id = region.get_index(size, full_size, index);
                 let data = Data::with_orientation(
-                    index,
-                    start_instance_id,
+                    global_id,
+                    222 + 1000 * global_id,
                     aabb_data.clone(),
                     Side::Right,
                 );
-                let border = borders[index];
+                let border = if spans.horizontal[index] {
+                    Border::Empty
+                } else {
+                    borders[index]
+                };
 
                 match border {
                     Border::Empty => {}
@@ -142,7 +297,17 @@ impl Renderer {
                         wall_style.get_edge_style().render(
                             &data,
                             start,
-                            self.calculate_horizontal_edge(nodes, index, y),
+                            self.calculate_horizontal_edge(nodes, index, y, 1),
+                            texture,
+                        );
+                    }
+                    Border::Railing(id) => {
+                        let wall_style = self.resources.wall_styles.get(id);
+
+                        wall_style.render_railing(
+                            &data,
+                            start,
+                            self.calculate_horizontal_edge(nodes, index, y, 1),
                             texture,
                         );
                     }
@@ -150,17 +315,19 @@ impl Renderer {
                         wall_id,
                         door_id,
                         is_front,
+                        width,
                     } => {
                         let wall_style = self.resources.wall_styles.get(wall_id);
                         let door_style = self.resources.door_styles.get(door_id);
                         let offset = door_style
                             .get_offset(wall_style.get_edge_style().get_thickness(), is_front);
                         let point = Point::new(start.x, start.y + offset);
+                        let segments = width.min(size.width() - x).max(1);
 
                         door_style.render(
                             &data,
                             point,
-                            self.calculate_horizontal_edge(nodes, index, y),
+                            self.calculate_horizontal_edge(nodes, index, y, segments),
                             is_front,
                             texture,
                         );
@@ -171,7 +338,7 @@ impl Renderer {
                         window_style.render(
                             &data,
                             start,
-                            self.calculate_horizontal_edge(nodes, index, y),
+                            self.calculate_horizontal_edge(nodes, index, y, 1),
                             texture,
                         );
                     }
@@ -179,7 +346,6 @@ impl Renderer {
 
                 start.x += step;
                 index += 1;
-                start_instance_id += 1000;
             }
 
             start.y += step;
@@ -189,27 +355,34 @@ impl Renderer {
     fn render_vertical_borders(
         &self,
         tilemap: &Tilemap2d,
+        region: &Region,
         nodes: &[NodeStatus],
+        spans: &DoorSpans,
         texture: &mut Texture,
     ) {
         let size = get_vertical_borders_size(tilemap.get_size());
         let borders = tilemap.get_vertical_borders();
         let step = self.tile_size as i32;
+        let full_size = get_vertical_borders_size(region.size);
         let mut index = 0;
-        let mut start_instance_id = 111;
         let aabb_data = AabbData::from_one_aabb(texture.get_aabb());
 
         for y in 0..size.height() {
             let mut start = Point::new(y as i32 * step, texture.get_size().width() as i32);
 
             for _x in 0..size.width() {
+                let global_id = region.get_index(size, full_size, index);
                 let data = Data::with_orientation(
-                    index,
-                    start_instance_id,
+                    global_id,
+                    111 + 1000 * global_id,
                     aabb_data.clone(),
                     Side::Bottom,
                 );
-                let border = borders[index];
+                let border = if spans.vertical[index] {
+                    Border::Empty
+                } else {
+                    borders[index]
+                };
 
                 match border {
                     Border::Empty => {}
@@ -219,7 +392,17 @@ impl Renderer {
                         wall_style.get_edge_style().render(
                             &data,
                             start,
-                            self.calculate_vertical_edge(nodes, size, index),
+                            self.calculate_vertical_edge(nodes, size, index, 1),
+                            texture,
+                        );
+                    }
+                    Border::Railing(id) => {
+                        let wall_style = self.resources.wall_styles.get(id);
+
+                        wall_style.render_railing(
+                            &data,
+                            start,
+                            self.calculate_vertical_edge(nodes, size, index, 1),
                             texture,
                         );
                     }
@@ -227,17 +410,19 @@ impl Renderer {
                         wall_id,
                         door_id,
                         is_front,
+                        width,
                     } => {
                         let wall_style = self.resources.wall_styles.get(wall_id);
                         let door_style = self.resources.door_styles.get(door_id);
                         let offset = door_style
                             .get_offset(wall_style.get_edge_style().get_thickness(), is_front);
                         let point = Point::new(start.x, start.y - offset);
+                        let segments = width.min(size.height() - y).max(1);
 
                         door_style.render(
                             &data,
                             point,
-                            self.calculate_vertical_edge(nodes, size, index),
+                            self.calculate_vertical_edge(nodes, size, index, segments),
                             is_front,
                             texture,
                         );
@@ -248,7 +433,7 @@ impl Renderer {
                         window_style.render(
                             &data,
                             start,
-                            self.calculate_vertical_edge(nodes, size, index),
+                            self.calculate_vertical_edge(nodes, size, index, 1),
                             texture,
                         );
                     }
@@ -256,12 +441,17 @@ impl Renderer {
 
                 start.y -= step;
                 index += 1;
-                start_instance_id += 1000;
             }
         }
     }
 
-    fn render_nodes(&self, tilemap: &Tilemap2d, nodes: &[NodeStatus], texture: &mut Texture) {
+    fn render_nodes(
+        &self,
+        tilemap: &Tilemap2d,
+        nodes: &[NodeStatus],
+        spans: &DoorSpans,
+        texture: &mut Texture,
+    ) {
         let size = get_nodes_size(tilemap.get_size());
         let mut point = Point::default();
         let data = Data::for_texture(texture.get_aabb());
@@ -273,7 +463,15 @@ impl Renderer {
 
             for _x in 0..size.width() {
                 if let NodeStatus::RenderNode(generator) = nodes[index] {
-                    generator.render(&data, point, texture);
+                    if !spans.nodes[index] {
+                        let sides: Vec<Side> = Side::iterator()
+                            .filter(|side| {
+                                tilemap.get_border_at_node(index, **side) != Border::Empty
+                            })
+                            .copied()
+                            .collect();
+                        generator.render_joint(&data, point, &sides, texture);
+                    }
                 }
 
                 point.x += step;
@@ -290,26 +488,46 @@ impl Renderer {
         generator.render(texture, &data);
     }
 
+    /// Calculates the edge of the horizontal [`Border`] spanning `segments` border segments.
     fn calculate_horizontal_edge(
         &self,
         nodes: &[NodeStatus],
         border_index: usize,
         y: u32,
+        segments: u32,
     ) -> (i32, u32) {
         let start_index = get_start_of_horizontal_border(border_index, y);
-        let end_index = get_end_of_horizontal_border(border_index, y);
-        self.calculate_edge(nodes, start_index, Side::Right, end_index, Side::Left)
+        let last_index = border_index + segments as usize - 1;
+        let end_index = get_end_of_horizontal_border(last_index, y);
+        self.calculate_edge(
+            nodes,
+            start_index,
+            Side::Right,
+            end_index,
+            Side::Left,
+            segments,
+        )
     }
 
+    /// Calculates the edge of the vertical [`Border`] spanning `segments` border segments.
     fn calculate_vertical_edge(
         &self,
         nodes: &[NodeStatus],
         size: Size,
         border_index: usize,
+        segments: u32,
     ) -> (i32, u32) {
         let start_index = get_start_of_vertical_border(border_index);
-        let end_index = get_end_of_vertical_border(size, border_index);
-        self.calculate_edge(nodes, start_index, Side::Bottom, end_index, Side::Top)
+        let last_index = border_index + (segments as usize - 1) * size.width() as usize;
+        let end_index = get_end_of_vertical_border(size, last_index);
+        self.calculate_edge(
+            nodes,
+            start_index,
+            Side::Bottom,
+            end_index,
+            Side::Top,
+            segments,
+        )
     }
 
     fn calculate_edge(
@@ -319,23 +537,147 @@ impl Renderer {
         start_side: Side,
         end_index: usize,
         end_side: Side,
+        segments: u32,
     ) -> (i32, u32) {
         let start_half = nodes[start_index].calculate_half(start_side);
         let end_half = nodes[end_index].calculate_half(end_side);
-        (
-            start_half,
-            (self.tile_size as i32 - (start_half + end_half)) as u32,
-        )
+        let length = (self.tile_size * segments) as i32;
+        (start_half, (length - (start_half + end_half)) as u32)
+    }
+}
+
+/// The part of the whole [`Tilemap2d`] that is rendered.
+/// Used to keep the ids of tiles & borders, which determine random variations, independent of the region.
+struct Region {
+    /// The start of the region in tile coordinates.
+    start: Point,
+    /// The size of the whole tilemap.
+    size: Size,
+}
+
+impl Region {
+    fn full(tilemap: &Tilemap2d) -> Self {
+        Region {
+            start: Point::default(),
+            size: tilemap.get_size(),
+        }
+    }
+
+    fn get_tile_index(&self, tiles: Size, index: usize) -> usize {
+        self.get_index(tiles, self.size, index)
+    }
+
+    /// Converts an index of a grid of the region to an index of the equivalent grid of the whole tilemap.
+    fn get_index(&self, region_grid: Size, full_grid: Size, index: usize) -> usize {
+        full_grid.to_index_risky(&(region_grid.to_point(index) + self.start))
+    }
+}
+
+/// Copies the part of the source starting at `offset` into the target.
+fn copy_texture(source: &Texture, offset: Point, target: &mut Texture) {
+    let source_width = source.get_size().width() as usize;
+    let width = target.get_size().width() as usize;
+    let height = target.get_size().height() as usize;
+    let start = offset.y as usize * source_width + offset.x as usize;
+
+    for y in 0..height {
+        let source_start = start + y * source_width;
+        let target_start = y * width;
+        target.get_color_data_mut()[target_start..target_start + width]
+            .copy_from_slice(&source.get_color_data()[source_start..source_start + width]);
+        target.get_depth_data_mut()[target_start..target_start + width]
+            .copy_from_slice(&source.get_depth_data()[source_start..source_start + width]);
+    }
+}
+
+/// Colors the highest pixels inside the area, which are the top of a [`Tile::Solid`].
+fn render_cap(texture: &mut Texture, aabb: &AABB, cap: Color) {
+    let width = texture.get_size().width() as usize;
+    let indices: Vec<usize> = (aabb.start().y..aabb.end().y)
+        .flat_map(|y| (aabb.start().x..aabb.end().x).map(move |x| y as usize * width + x as usize))
+        .collect();
+    let depth = texture.get_depth_data();
+    let top = match indices.iter().map(|index| depth[*index]).max() {
+        None => return,
+        Some(top) => top,
+    };
+    let top_indices: Vec<usize> = indices
+        .into_iter()
+        .filter(|index| depth[*index] == top)
+        .collect();
+    let colors = texture.get_color_data_mut();
+
+    for index in top_indices {
+        colors[index] = cap;
+    }
+}
+
+/// Marks the borders & nodes covered by the additional segments of wide doors,
+/// so that they are not rendered inside the opening.
+struct DoorSpans {
+    horizontal: Vec<bool>,
+    vertical: Vec<bool>,
+    nodes: Vec<bool>,
+}
+
+impl DoorSpans {
+    fn new(tilemap: &Tilemap2d) -> Self {
+        let horizontal_size = get_horizontal_borders_size(tilemap.get_size());
+        let vertical_size = get_vertical_borders_size(tilemap.get_size());
+        let mut spans = DoorSpans {
+            horizontal: vec![false; horizontal_size.len()],
+            vertical: vec![false; vertical_size.len()],
+            nodes: vec![false; get_nodes_size(tilemap.get_size()).len()],
+        };
+
+        for (index, border) in tilemap.get_horizontal_borders().iter().enumerate() {
+            if let Border::Door { width, .. } = border {
+                let x = index as u32 % horizontal_size.width();
+                let y = index as u32 / horizontal_size.width();
+
+                let segments = (*width).min(horizontal_size.width() - x);
+
+                for i in 1..segments {
+                    let spanned = index + i as usize;
+                    spans.horizontal[spanned] = true;
+                    spans.nodes[get_start_of_horizontal_border(spanned, y)] = true;
+                }
+            }
+        }
+
+        for (index, border) in tilemap.get_vertical_borders().iter().enumerate() {
+            if let Border::Door { width, .. } = border {
+                let y = index as u32 / vertical_size.width();
+
+                let segments = (*width).min(vertical_size.height() - y);
+
+                for i in 1..segments {
+                    let spanned = index + (i * vertical_size.width()) as usize;
+                    spans.vertical[spanned] = true;
+                    spans.nodes[get_start_of_vertical_border(spanned)] = true;
+                }
+            }
+        }
+
+        spans
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rendering::style::door::DoorStyle;
+    use crate::rendering::style::edge::EdgeStyle;
+    use crate::rendering::style::front::FrontStyle;
+    use crate::rendering::style::furniture::{FurnitureSize, FurnitureStyle};
+    use crate::rendering::style::node::{CornerJoint, NodeStyle};
+    use crate::rendering::style::wall::WallStyle;
+    use crate::tilemap::furniture::Furniture;
     use texture_generation::generation::component::rendering::RenderingComponent;
     use texture_generation::generation::component::Component;
+    use texture_generation::generation::process::PostProcess;
     use texture_generation::generation::TextureGenerator;
-    use texture_generation::math::color::{Color, BLACK, BLUE, PINK, RED};
+    use texture_generation::math::color::{Color, BLACK, BLUE, GREEN, PINK, RED, YELLOW};
     use texture_generation::utils::resource::ResourceManager;
 
     #[test]
@@ -383,6 +725,285 @@ mod tests {
         assert_eq!(data.get_depth_data(), &depth);
     }
 
+    #[test]
+    fn test_furniture_between_floor_and_wall() {
+        let mut resources = Resources::empty();
+        let floor = create_texture("floor", RED, 0);
+        let wall = create_texture("wall", BLUE, 0);
+        resources.textures = ResourceManager::new(vec![floor, wall], TextureGenerator::default());
+        let rendering = RenderingComponent::new_fill_area(GREEN, 0);
+        let component = Component::Rendering(Box::new(rendering));
+        let furniture = FurnitureStyle::new(
+            "furniture",
+            FurnitureSize::Fill,
+            100,
+            component,
+            FrontStyle::None,
+        );
+        resources.furniture_styles =
+            ResourceManager::new(vec![furniture], FurnitureStyle::default());
+        let renderer = Renderer::new(2, 200, resources);
+        let tiles = vec![Tile::Floor(0), Tile::Solid(1)];
+        let tilemap = Tilemap2d::new(Size::new(2, 1), tiles).unwrap();
+        let mut furniture_map = FurnitureMap2d::empty(tilemap.get_size());
+        furniture_map.add(Furniture::without_front(0, Point::new(0, 0), Size::square(1)).unwrap());
+
+        let data = renderer.render(&tilemap, Some(&furniture_map));
+        let depth = data.get_depth_data();
+        let furniture_depth = depth[0];
+        let floor_depth = depth[1];
+        let wall_depth = depth[2];
+
+        assert_eq!(floor_depth, FLOOR_DEPTH);
+        assert!(floor_depth < furniture_depth);
+        assert!(furniture_depth < wall_depth);
+    }
+
+    #[test]
+    fn test_render_ramps() {
+        let mut resources = Resources::empty();
+        let floor = create_texture("floor", RED, 0);
+        resources.textures = ResourceManager::new(vec![floor], TextureGenerator::default());
+        let renderer = Renderer::new(4, 101, resources);
+        let tiles = vec![
+            Tile::Ramp {
+                id: 0,
+                direction: Side::Right,
+            },
+            Tile::Ramp {
+                id: 0,
+                direction: Side::Top,
+            },
+        ];
+        let tilemap = Tilemap2d::new(Size::new(2, 1), tiles).unwrap();
+
+        let data = renderer.render(&tilemap, None);
+
+        #[rustfmt::skip]
+        let depth = vec![
+            1, 34, 67, 101,  101, 101, 101, 101,
+            1, 34, 67, 101,   67,  67,  67,  67,
+            1, 34, 67, 101,   34,  34,  34,  34,
+            1, 34, 67, 101,    1,   1,   1,   1,
+        ];
+
+        assert_eq!(data.get_depth_data(), &depth);
+    }
+
+    #[test]
+    fn test_render_wide_door() {
+        let mut resources = Resources::empty();
+        let wall_rendering = RenderingComponent::new_fill_area(RED, 5);
+        let wall_edge = EdgeStyle::new_solid(2, wall_rendering).unwrap();
+        let wall = WallStyle::new("wall", wall_edge, None, None);
+        resources.wall_styles = ResourceManager::new(vec![wall], WallStyle::default(1));
+        let door_rendering = RenderingComponent::new_fill_area(GREEN, 1);
+        let door_edge = EdgeStyle::new_solid(2, door_rendering).unwrap();
+        let door = DoorStyle::new("door", door_edge, None, true);
+        resources.door_styles = ResourceManager::new(vec![door], DoorStyle::default(1));
+        let renderer = Renderer::new(4, 200, resources);
+        let mut tilemap = Tilemap2d::default(Size::new(3, 1), Tile::Empty);
+        tilemap.set_border(0, Side::Top, Border::new_wide_door(0, 0, true, 2));
+        tilemap.set_border(1, Side::Top, Border::Wall(0));
+        tilemap.set_border(2, Side::Top, Border::Wall(0));
+
+        let data = renderer.render(&tilemap, None);
+        let first_row = &data.get_color_data()[0..12];
+
+        assert!(first_row[1..7].iter().all(|color| *color == GREEN));
+        assert!(first_row[9..12].iter().all(|color| *color == RED));
+    }
+
+    #[test]
+    fn test_render_regions_match_full_render() {
+        assert_render_regions_match_full_render(Vec::new());
+    }
+
+    #[test]
+    fn test_render_regions_with_post_processes_match_full_render() {
+        assert_render_regions_match_full_render(vec![
+            PostProcess::Emboss {
+                angle: 45.0,
+                strength: 2.0,
+            },
+            PostProcess::Sharpen { amount: 1.0 },
+        ]);
+    }
+
+    fn assert_render_regions_match_full_render(post_processes: Vec<PostProcess>) {
+        let mut resources = Resources::empty();
+        let floor = create_texture("floor", RED, 0);
+        let solid = create_texture("solid", BLUE, 0);
+        resources.textures = ResourceManager::new(vec![floor, solid], TextureGenerator::default());
+        let wall_rendering = RenderingComponent::new_fill_area(GREEN, 5);
+        let wall_edge = EdgeStyle::new_solid(2, wall_rendering).unwrap();
+        let wall = WallStyle::new("wall", wall_edge, None, None);
+        resources.wall_styles = ResourceManager::new(vec![wall], WallStyle::default(1));
+        resources.post_processes = post_processes;
+        let renderer = Renderer::new(4, 200, resources);
+        let mut tilemap = Tilemap2d::default(Size::new(4, 2), Tile::Floor(0));
+        tilemap.set_tile(2, Tile::Solid(1));
+        tilemap.set_border(1, Side::Right, Border::Wall(0));
+        tilemap.set_border(5, Side::Top, Border::Wall(0));
+        tilemap.set_border(6, Side::Top, Border::Wall(0));
+
+        let full = renderer.render(&tilemap, None);
+        let left = renderer.render_region(&tilemap, &AABB::new(Point::new(0, 0), Size::new(2, 2)));
+        let right = renderer.render_region(&tilemap, &AABB::new(Point::new(2, 0), Size::new(2, 2)));
+
+        assert_eq!(left.get_size(), &Size::new(8, 8));
+        assert_eq!(right.get_size(), &Size::new(8, 8));
+
+        for y in 0..8 {
+            let full_row = y * 16;
+            let row = y * 8;
+
+            for (region, offset) in &[(&left, 0), (&right, 8)] {
+                assert_eq!(
+                    &full.get_color_data()[full_row + offset..full_row + offset + 8],
+                    &region.get_color_data()[row..row + 8]
+                );
+                assert_eq!(
+                    &full.get_depth_data()[full_row + offset..full_row + offset + 8],
+                    &region.get_depth_data()[row..row + 8]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_l_corner_with_butt_and_round_joint() {
+        let butt = render_l_corner(CornerJoint::Butt);
+        let round = render_l_corner(CornerJoint::Round);
+
+        // the inside of the corner
+        assert_eq!(butt.get_color_data()[27], RED);
+        assert_eq!(round.get_color_data()[27], RED);
+        // the outside of the corner
+        assert_eq!(butt.get_color_data()[36], BLACK);
+        assert_eq!(round.get_color_data()[36], RED);
+    }
+
+    #[test]
+    fn test_render_solid_tile_with_cap() {
+        let without_cap = render_solid_tile_next_to_wall(None);
+        let with_cap = render_solid_tile_next_to_wall(Some(YELLOW));
+
+        for y in 0..4 {
+            for x in 0..4 {
+                let index = y * 8 + x;
+                assert_eq!(without_cap.get_color_data()[index], BLUE);
+                assert_eq!(with_cap.get_color_data()[index], YELLOW);
+                assert_eq!(with_cap.get_depth_data()[index], 201);
+            }
+
+            for texture in &[&without_cap, &with_cap] {
+                assert_eq!(texture.get_color_data()[y * 8 + 4], GREEN);
+
+                for x in 5..8 {
+                    assert_eq!(texture.get_color_data()[y * 8 + x], RED);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_border_above_floor() {
+        let mut resources = Resources::empty();
+        let floor = create_texture("floor", RED, 20);
+        resources.textures = ResourceManager::new(vec![floor], TextureGenerator::default());
+        let wall_rendering = RenderingComponent::new_fill_area(GREEN, 5);
+        let wall_edge = EdgeStyle::new_solid(2, wall_rendering).unwrap();
+        let wall = WallStyle::new("wall", wall_edge, None, None);
+        resources.wall_styles = ResourceManager::new(vec![wall], WallStyle::default(1));
+        let renderer = Renderer::new(4, 200, resources);
+        let mut tilemap = Tilemap2d::default(Size::new(1, 2), Tile::Floor(0));
+        tilemap.set_border(0, Side::Bottom, Border::Wall(0));
+
+        let data = renderer.render(&tilemap, None);
+
+        for index in &[13, 14, 17, 18] {
+            assert_eq!(data.get_color_data()[*index], GREEN);
+            assert_eq!(data.get_depth_data()[*index], BORDER_DEPTH + 5);
+        }
+
+        assert_eq!(data.get_color_data()[1], RED);
+        assert_eq!(data.get_depth_data()[1], FLOOR_DEPTH + 20);
+    }
+
+    #[test]
+    fn test_render_railing_lower_than_wall() {
+        let wall = render_border_between_floors(Border::Wall(0));
+        let railing = render_border_between_floors(Border::Railing(0));
+
+        for index in &[13, 14, 17, 18] {
+            assert_eq!(wall.get_depth_data()[*index], BORDER_DEPTH + 10);
+            assert_eq!(railing.get_depth_data()[*index], BORDER_DEPTH + 5);
+            assert_eq!(railing.get_color_data()[*index], GREEN);
+        }
+
+        assert_eq!(railing.get_depth_data()[1], FLOOR_DEPTH);
+    }
+
+    #[test]
+    fn test_render_unknown_texture_as_default() {
+        let renderer = Renderer::new(2, 101, Resources::empty());
+        let tilemap = Tilemap2d::default(Size::new(2, 1), Tile::Floor(5));
+
+        let data = renderer.render(&tilemap, None);
+
+        assert_eq!(data.get_color_data(), &vec![PINK; 8]);
+    }
+
+    fn render_l_corner(joint: CornerJoint) -> Texture {
+        let mut resources = Resources::empty();
+        let wall_rendering = RenderingComponent::new_fill_area(GREEN, 5);
+        let wall_edge = EdgeStyle::new_solid(2, wall_rendering).unwrap();
+        let wall = WallStyle::new("wall", wall_edge, None, Some(0));
+        resources.wall_styles = ResourceManager::new(vec![wall], WallStyle::default(1));
+        let node_rendering = RenderingComponent::new_fill_area(RED, 5);
+        let node = NodeStyle::with_joint("node", 2, joint, node_rendering);
+        resources.node_styles = ResourceManager::new(vec![node], NodeStyle::default());
+        let renderer = Renderer::new(4, 200, resources);
+        let mut tilemap = Tilemap2d::default(Size::new(2, 2), Tile::Empty);
+        tilemap.set_border(0, Side::Bottom, Border::Wall(0));
+        tilemap.set_border(0, Side::Right, Border::Wall(0));
+
+        renderer.render(&tilemap, None)
+    }
+
+    fn render_solid_tile_next_to_wall(cap: Option<Color>) -> Texture {
+        let mut resources = Resources::empty();
+        let floor = create_texture("floor", RED, 1);
+        let solid = create_texture("solid", BLUE, 1);
+        resources.textures = ResourceManager::new(vec![floor, solid], TextureGenerator::default());
+        let wall_rendering = RenderingComponent::new_fill_area(GREEN, 5);
+        let wall_edge = EdgeStyle::new_solid(2, wall_rendering).unwrap();
+        let wall = WallStyle::with_cap("wall", wall_edge, None, None, cap);
+        resources.wall_styles = ResourceManager::new(vec![wall], WallStyle::default(1));
+        let renderer = Renderer::new(4, 200, resources);
+        let tiles = vec![Tile::Solid(1), Tile::Floor(0)];
+        let mut tilemap = Tilemap2d::new(Size::new(2, 1), tiles).unwrap();
+        tilemap.set_border(0, Side::Right, Border::Wall(0));
+
+        renderer.render(&tilemap, None)
+    }
+
+    fn render_border_between_floors(border: Border) -> Texture {
+        let mut resources = Resources::empty();
+        let floor = create_texture("floor", RED, 0);
+        resources.textures = ResourceManager::new(vec![floor], TextureGenerator::default());
+        let wall_rendering = RenderingComponent::new_fill_area(GREEN, 10);
+        let wall_edge = EdgeStyle::new_solid(2, wall_rendering).unwrap();
+        let wall = WallStyle::new("wall", wall_edge, None, None);
+        resources.wall_styles = ResourceManager::new(vec![wall], WallStyle::default(1));
+        let renderer = Renderer::new(4, 200, resources);
+        let mut tilemap = Tilemap2d::default(Size::new(1, 2), Tile::Floor(0));
+        tilemap.set_border(0, Side::Bottom, border);
+
+        renderer.render(&tilemap, None)
+    }
+
     fn create_texture(name: &str, color: Color, depth: u8) -> TextureGenerator {
         let rendering = RenderingComponent::new_fill_area(color, depth);
         let component = Component::Rendering(Box::new(rendering));
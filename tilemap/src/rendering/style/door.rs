@@ -2,9 +2,18 @@ use crate::rendering::style::edge::EdgeStyle;
 use crate::rendering::style::handle::HandleStyle;
 use texture_generation::generation::data::texture::Texture;
 use texture_generation::generation::data::Data;
+use texture_generation::math::aabb::AABB;
+use texture_generation::math::color::{Color, BLACK};
 use texture_generation::math::point::Point;
+use texture_generation::math::side::Side;
+use texture_generation::math::size::Size;
 use texture_generation::utils::resource::Resource;
 
+/// The color of the swing arc of doors.
+pub const SWING_COLOR: Color = BLACK;
+/// The depth of the swing arc of doors, which is added to the base depth.
+pub const SWING_DEPTH: u8 = 1;
+
 #[derive(Clone, Debug, PartialEq)]
 /// Determines how a door is rendered.
 pub struct DoorStyle {
@@ -13,6 +22,11 @@ pub struct DoorStyle {
     edge_style: EdgeStyle,
     handle_style: Option<HandleStyle>,
     is_centered: bool,
+    /// Draws a quarter circle to show how the door opens.
+    show_swing: bool,
+    /// The side of the door with the swing arc. The door is rendered horizontally,
+    /// so [`Side::Left`] is treated like [`Side::Top`] & [`Side::Right`] like [`Side::Bottom`].
+    swing_side: Side,
 }
 
 impl DoorStyle {
@@ -30,12 +44,32 @@ impl DoorStyle {
         edge_style: EdgeStyle,
         handle_style: Option<HandleStyle>,
         is_centered: bool,
+    ) -> DoorStyle {
+        Self::with_swing(
+            name,
+            edge_style,
+            handle_style,
+            is_centered,
+            false,
+            Side::Bottom,
+        )
+    }
+
+    pub fn with_swing<S: Into<String>>(
+        name: S,
+        edge_style: EdgeStyle,
+        handle_style: Option<HandleStyle>,
+        is_centered: bool,
+        show_swing: bool,
+        swing_side: Side,
     ) -> DoorStyle {
         DoorStyle {
             name: name.into(),
             edge_style,
             handle_style,
             is_centered,
+            show_swing,
+            swing_side,
         }
     }
 
@@ -69,8 +103,52 @@ impl DoorStyle {
             handle.render(data, node, edge, is_front, texture);
         }
 
+        if self.show_swing {
+            self.render_swing(data, node, edge, texture);
+        }
+
         self.edge_style.render(data, node, edge, texture);
     }
+
+    /// Renders a thin quarter circle around the hinge at the start of the door.
+    fn render_swing(&self, data: &Data, node: Point, edge: (i32, u32), texture: &mut Texture) {
+        let (start, length) = edge;
+        let radius = length as i32;
+        let hinge = Point::new(node.x + start, node.y);
+        let quarter_y = match self.swing_side {
+            Side::Top | Side::Left => hinge.y - radius,
+            Side::Bottom | Side::Right => hinge.y,
+        };
+        let square = AABB::new(hinge - radius, Size::square(2 * length));
+        let quarter = AABB::new(Point::new(hinge.x, quarter_y), Size::square(length));
+        let hinge = to_texture_space(data, square).center();
+        let quarter = match to_texture_space(data, quarter).intersection(&texture.get_aabb()) {
+            None => return,
+            Some(quarter) => quarter,
+        };
+        let depth = texture.get_base_depth().saturating_add(SWING_DEPTH);
+        let outer = length as f32;
+        let inner = outer - 1.0;
+
+        for y in quarter.start().y..quarter.end().y {
+            for x in quarter.start().x..quarter.end().x {
+                let dx = x as f32 + 0.5 - hinge.x as f32;
+                let dy = y as f32 + 0.5 - hinge.y as f32;
+                let distance = (dx * dx + dy * dy).sqrt();
+
+                if distance > inner && distance <= outer {
+                    texture.set(&Point::new(x, y), &SWING_COLOR, depth);
+                }
+            }
+        }
+    }
+}
+
+fn to_texture_space(data: &Data, aabb: AABB) -> AABB {
+    *data
+        .transform(aabb)
+        .get_aabbs_in_texture_space()
+        .get_inner()
 }
 
 impl Default for DoorStyle {
@@ -84,3 +162,79 @@ impl Resource for DoorStyle {
         &self.name
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use texture_generation::generation::component::rendering::RenderingComponent;
+    use texture_generation::math::color::{GREEN, WHITE};
+
+    #[test]
+    fn test_render_swing_on_top() {
+        let texture = render_door_with_swing(Side::Top);
+
+        assert_swing(&texture, 5, 1);
+        assert_no_swing(&texture, 6, 9);
+    }
+
+    #[test]
+    fn test_render_swing_on_bottom() {
+        let texture = render_door_with_swing(Side::Bottom);
+
+        assert_no_swing(&texture, 0, 3);
+        assert_swing(&texture, 5, 8);
+    }
+
+    #[test]
+    fn test_render_without_swing() {
+        let edge_style = EdgeStyle::new_solid(2, RenderingComponent::new_fill_area(GREEN, 9));
+        let door_style = DoorStyle::new("door", edge_style.unwrap(), None, true);
+        let mut texture = Texture::new(Size::square(10), WHITE);
+
+        door_style.render(
+            &Data::for_texture(texture.get_aabb()),
+            Point::new(0, 5),
+            (1, 6),
+            true,
+            &mut texture,
+        );
+
+        assert_no_swing(&texture, 0, 3);
+        assert_no_swing(&texture, 6, 9);
+    }
+
+    fn render_door_with_swing(side: Side) -> Texture {
+        let edge_style = EdgeStyle::new_solid(2, RenderingComponent::new_fill_area(GREEN, 9));
+        let door_style = DoorStyle::with_swing("door", edge_style.unwrap(), None, true, true, side);
+        let mut texture = Texture::new(Size::square(10), WHITE);
+
+        door_style.render(
+            &Data::for_texture(texture.get_aabb()),
+            Point::new(0, 5),
+            (1, 6),
+            true,
+            &mut texture,
+        );
+
+        texture
+    }
+
+    fn assert_swing(texture: &Texture, x: i32, y: i32) {
+        let point = Point::new(x, y);
+
+        assert_eq!(texture.get_color(&point), Some(SWING_COLOR));
+        assert_eq!(texture.get_depth(&point), Some(SWING_DEPTH));
+    }
+
+    /// Checks that the rows from `start_y` to `end_y` are untouched.
+    fn assert_no_swing(texture: &Texture, start_y: i32, end_y: i32) {
+        for y in start_y..=end_y {
+            for x in 0..10 {
+                let point = Point::new(x, y);
+
+                assert_eq!(texture.get_color(&point), Some(WHITE));
+                assert_eq!(texture.get_depth(&point), Some(0));
+            }
+        }
+    }
+}
@@ -55,6 +55,15 @@ impl EdgeStyle {
         }
     }
 
+    /// Returns the area of the edge in texture space.
+    pub fn get_aabb(&self, data: &Data, node: Point, edge: (i32, u32)) -> AABB {
+        let aabb = calculate_aabb(node, edge, self.get_thickness());
+        *data
+            .transform(aabb)
+            .get_aabbs_in_texture_space()
+            .get_inner()
+    }
+
     pub fn render(&self, data: &Data, node: Point, edge: (i32, u32), texture: &mut Texture) {
         match self {
             EdgeStyle::Layout {
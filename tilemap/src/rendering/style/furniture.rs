@@ -46,8 +46,15 @@ impl FurnitureStyle {
     }
 
     /// Renders the furniture into the [`Texture`] in the area defined by [`Data`].
-    pub fn render(&self, resources: &Resources, texture: &mut Texture, data: &Data) {
-        texture.set_base_depth(self.depth);
+    /// The `depth_offset` is added to the depth of the furniture.
+    pub fn render(
+        &self,
+        resources: &Resources,
+        texture: &mut Texture,
+        data: &Data,
+        depth_offset: u8,
+    ) {
+        texture.set_base_depth(depth_offset.saturating_add(self.depth));
 
         let aabb = self.calculate_aabb(data);
         let new_data = data.transform(aabb);
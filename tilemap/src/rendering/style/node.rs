@@ -2,15 +2,32 @@ use texture_generation::generation::component::rendering::RenderingComponent;
 use texture_generation::generation::data::texture::Texture;
 use texture_generation::generation::data::Data;
 use texture_generation::math::aabb::AABB;
+use texture_generation::math::color::{Color, BLACK};
 use texture_generation::math::point::Point;
+use texture_generation::math::shape_factory::ShapeFactory;
+use texture_generation::math::side::Side;
 use texture_generation::math::size::Size;
 use texture_generation::utils::resource::Resource;
 
+/// How the walls meeting at a node are joined.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CornerJoint {
+    /// Each wall ends at the node, which leaves a notch at the outside of corners.
+    Butt,
+    /// Fills the whole node area.
+    Miter,
+    /// Like [`CornerJoint::Butt`], but the notch is rounded.
+    Round,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct NodeStyle {
     name: String,
     size: Size,
     half: i32,
+    joint: CornerJoint,
+    /// A post is rendered on top of the walls, which are not shortened by it.
+    is_post: bool,
     component: RenderingComponent,
 }
 
@@ -20,16 +37,56 @@ impl NodeStyle {
     }
 
     pub fn new<S: Into<String>>(name: S, size: u32, component: RenderingComponent) -> NodeStyle {
+        Self::with_joint(name, size, CornerJoint::Miter, component)
+    }
+
+    pub fn with_joint<S: Into<String>>(
+        name: S,
+        size: u32,
+        joint: CornerJoint,
+        component: RenderingComponent,
+    ) -> NodeStyle {
         NodeStyle {
             name: name.into(),
             size: Size::square(size),
             half: (size / 2) as i32,
+            joint,
+            is_post: false,
             component,
         }
     }
 
+    /// Creates a post or column, which is centered on the node.
+    pub fn new_post<S: Into<String>>(
+        name: S,
+        size: u32,
+        shape_factory: ShapeFactory,
+        color: Color,
+        depth: u8,
+    ) -> NodeStyle {
+        let component = RenderingComponent::new_shape(shape_factory, color, depth);
+
+        NodeStyle {
+            is_post: true,
+            ..Self::new(name, size, component)
+        }
+    }
+
+    /// Returns how much the node shortens the walls connected to it.
     pub fn get_half(&self) -> i32 {
-        self.half
+        if self.is_post {
+            0
+        } else {
+            self.half
+        }
+    }
+
+    pub fn is_post(&self) -> bool {
+        self.is_post
+    }
+
+    pub fn get_joint(&self) -> CornerJoint {
+        self.joint
     }
 
     pub fn render(&self, data: &Data, node: Point, texture: &mut Texture) {
@@ -37,6 +94,61 @@ impl NodeStyle {
         let aabb = AABB::new(start, self.size);
         self.component.render(texture, &data.transform(aabb))
     }
+
+    /// Renders the node based on its [`CornerJoint`] & the sides with a border.
+    pub fn render_joint(&self, data: &Data, node: Point, sides: &[Side], texture: &mut Texture) {
+        if self.is_post || self.joint == CornerJoint::Miter {
+            return self.render(data, node, texture);
+        }
+
+        let start = node - self.half;
+        let mut node_texture = Texture::new(self.size, BLACK);
+        let node_data = data.transform(AABB::new(Point::default(), self.size));
+        self.component.render(&mut node_texture, &node_data);
+
+        let mut point = Point::default();
+
+        for y in 0..self.size.height() as i32 {
+            point.y = y;
+
+            for x in 0..self.size.width() as i32 {
+                point.x = x;
+
+                if !self.is_inside_joint(x - self.half, y - self.half, sides) {
+                    continue;
+                }
+
+                if let (Some(color), Some(depth)) = (
+                    node_texture.get_color(&point),
+                    node_texture.get_depth(&point),
+                ) {
+                    if depth > 0 && !texture.set_checked(&(start + point), &color, depth) {
+                        trace!("Node pixel {:?} is outside the texture", start + point);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Is the pixel relative to the node covered by one of the borders or by the rounded notch?
+    fn is_inside_joint(&self, x: i32, y: i32, sides: &[Side]) -> bool {
+        let is_covered = sides.iter().any(|side| match side {
+            Side::Top => y < 0,
+            Side::Left => x < 0,
+            Side::Bottom => y >= 0,
+            Side::Right => x >= 0,
+        });
+
+        if is_covered || self.joint != CornerJoint::Round {
+            return is_covered;
+        }
+
+        let dx = x as f32 + 0.5;
+        let dy = y as f32 + 0.5;
+        let radius = self.half as f32;
+
+        dx * dx + dy * dy <= radius * radius
+    }
 }
 
 impl Default for NodeStyle {
@@ -56,7 +168,8 @@ mod tests {
     use super::*;
     use texture_generation::generation::component::rendering::RenderingComponent;
     use texture_generation::generation::data::texture::Texture;
-    use texture_generation::math::color::{BLACK, RED};
+    use texture_generation::math::color::{BLACK, RED, WHITE};
+    use texture_generation::math::side::Side::{Bottom, Right};
 
     #[test]
     fn test_render_node() {
@@ -92,4 +205,104 @@ mod tests {
 
         assert_eq!(texture.get_depth_data(), &depth);
     }
+
+    #[test]
+    fn test_miter_joint_fills_node() {
+        let texture = render_l_corner(CornerJoint::Miter);
+
+        #[rustfmt::skip]
+        let depth = vec![
+            0, 0, 0, 0, 0, 0,
+            0, 9, 9, 9, 9, 0,
+            0, 9, 9, 9, 9, 0,
+            0, 9, 9, 9, 9, 0,
+            0, 9, 9, 9, 9, 0,
+            0, 0, 0, 0, 0, 0,
+        ];
+
+        assert_eq!(texture.get_depth_data(), &depth);
+    }
+
+    #[test]
+    fn test_butt_joint_leaves_notch() {
+        let texture = render_l_corner(CornerJoint::Butt);
+
+        #[rustfmt::skip]
+        let depth = vec![
+            0, 0, 0, 0, 0, 0,
+            0, 0, 0, 9, 9, 0,
+            0, 0, 0, 9, 9, 0,
+            0, 9, 9, 9, 9, 0,
+            0, 9, 9, 9, 9, 0,
+            0, 0, 0, 0, 0, 0,
+        ];
+
+        assert_eq!(texture.get_depth_data(), &depth);
+    }
+
+    #[test]
+    fn test_round_joint_rounds_notch() {
+        let texture = render_l_corner(CornerJoint::Round);
+
+        #[rustfmt::skip]
+        let depth = vec![
+            0, 0, 0, 0, 0, 0,
+            0, 0, 9, 9, 9, 0,
+            0, 9, 9, 9, 9, 0,
+            0, 9, 9, 9, 9, 0,
+            0, 9, 9, 9, 9, 0,
+            0, 0, 0, 0, 0, 0,
+        ];
+
+        assert_eq!(texture.get_depth_data(), &depth);
+    }
+
+    fn render_l_corner(joint: CornerJoint) -> Texture {
+        let component = RenderingComponent::new_fill_area(RED, 9);
+        let node_style = NodeStyle::with_joint("node", 4, joint, component);
+        let mut texture = Texture::new(Size::square(6), BLACK);
+
+        node_style.render_joint(
+            &Data::for_texture(texture.get_aabb()),
+            Point::new(3, 3),
+            &[Bottom, Right],
+            &mut texture,
+        );
+
+        texture
+    }
+
+    #[test]
+    fn test_post_does_not_shorten_walls() {
+        let node_style = NodeStyle::new_post("post", 24, ShapeFactory::Circle, RED, 9);
+
+        assert!(node_style.is_post());
+        assert_eq!(node_style.get_half(), 0);
+    }
+
+    #[test]
+    fn test_render_circular_post() {
+        let node_style = NodeStyle::new_post("post", 5, ShapeFactory::Circle, RED, 9);
+        let mut texture = Texture::new(Size::square(7), WHITE);
+
+        node_style.render_joint(
+            &Data::for_texture(texture.get_aabb()),
+            Point::new(3, 3),
+            &[Bottom, Right],
+            &mut texture,
+        );
+
+        #[rustfmt::skip]
+        let result = vec![
+            WHITE, WHITE, WHITE, WHITE, WHITE, WHITE, WHITE,
+            WHITE, WHITE, WHITE,   RED, WHITE, WHITE, WHITE,
+            WHITE, WHITE,   RED,   RED,   RED, WHITE, WHITE,
+            WHITE,   RED,   RED,   RED,   RED,   RED, WHITE,
+            WHITE, WHITE,   RED,   RED,   RED, WHITE, WHITE,
+            WHITE, WHITE, WHITE,   RED, WHITE, WHITE, WHITE,
+            WHITE, WHITE, WHITE, WHITE, WHITE, WHITE, WHITE,
+        ];
+
+        assert_eq!(texture.get_color_data(), &result);
+    }
 }
@@ -1,4 +1,8 @@
 use crate::rendering::style::edge::EdgeStyle;
+use texture_generation::generation::data::texture::Texture;
+use texture_generation::generation::data::Data;
+use texture_generation::math::color::Color;
+use texture_generation::math::point::Point;
 use texture_generation::utils::resource::Resource;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -11,6 +15,8 @@ pub struct WallStyle {
     node_style: Option<usize>,
     /// The style of corners.
     corner_style: Option<usize>,
+    /// The optional color of the top of [`Tile::Solid`](crate::tilemap::tile::Tile::Solid) next to the wall.
+    cap: Option<Color>,
 }
 
 impl WallStyle {
@@ -28,12 +34,23 @@ impl WallStyle {
         edge_style: EdgeStyle,
         node_style: Option<usize>,
         corner_style: Option<usize>,
+    ) -> WallStyle {
+        Self::with_cap(name, edge_style, node_style, corner_style, None)
+    }
+
+    pub fn with_cap<S: Into<String>>(
+        name: S,
+        edge_style: EdgeStyle,
+        node_style: Option<usize>,
+        corner_style: Option<usize>,
+        cap: Option<Color>,
     ) -> WallStyle {
         WallStyle {
             name: name.into(),
             edge_style,
             node_style,
             corner_style,
+            cap,
         }
     }
 
@@ -49,9 +66,77 @@ impl WallStyle {
         self.corner_style
     }
 
+    pub fn get_cap(&self) -> Option<Color> {
+        self.cap
+    }
+
     pub fn is_greater(&self, other: &WallStyle) -> bool {
         self.edge_style.get_thickness() >= other.edge_style.get_thickness()
     }
+
+    /// Renders the edge of the wall at half its height.
+    pub fn render_railing(
+        &self,
+        data: &Data,
+        node: Point,
+        edge: (i32, u32),
+        texture: &mut Texture,
+    ) {
+        let base_depth = texture.get_base_depth();
+        let changed = self.render_tracked(data, node, edge, texture);
+
+        for (index, old_color, old_depth) in changed {
+            let depth = texture.get_depth_data()[index];
+            let half_depth = base_depth + depth.saturating_sub(base_depth) / 2;
+
+            if half_depth > old_depth {
+                texture.get_depth_data_mut()[index] = half_depth;
+            } else {
+                texture.get_color_data_mut()[index] = old_color;
+                texture.get_depth_data_mut()[index] = old_depth;
+            }
+        }
+    }
+
+    /// Renders the edge & returns the index, old color & old depth of each changed pixel.
+    fn render_tracked(
+        &self,
+        data: &Data,
+        node: Point,
+        edge: (i32, u32),
+        texture: &mut Texture,
+    ) -> Vec<(usize, Color, u8)> {
+        let aabb = match self
+            .edge_style
+            .get_aabb(data, node, edge)
+            .intersection(&texture.get_aabb())
+        {
+            None => return Vec::new(),
+            Some(aabb) => aabb,
+        };
+        let width = texture.get_size().width() as usize;
+        let before: Vec<(usize, Color, u8)> = (aabb.start().y..aabb.end().y)
+            .flat_map(|y| {
+                (aabb.start().x..aabb.end().x).map(move |x| y as usize * width + x as usize)
+            })
+            .map(|index| {
+                (
+                    index,
+                    texture.get_color_data()[index],
+                    texture.get_depth_data()[index],
+                )
+            })
+            .collect();
+
+        self.edge_style.render(data, node, edge, texture);
+
+        let depth = texture.get_depth_data();
+
+        before
+            .into_iter()
+            .filter(|(index, _, old_depth)| depth[*index] != *old_depth)
+            .collect()
+    }
 }
 
 impl Default for WallStyle {
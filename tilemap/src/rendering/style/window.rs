@@ -1,9 +1,72 @@
 use crate::rendering::style::edge::EdgeStyle;
+use anyhow::{bail, Result};
 use texture_generation::generation::data::texture::Texture;
 use texture_generation::generation::data::Data;
+use texture_generation::math::aabb::AABB;
+use texture_generation::math::color::Color;
 use texture_generation::math::point::Point;
+use texture_generation::math::size::Size;
 use texture_generation::utils::resource::Resource;
 
+#[derive(Clone, Debug, PartialEq)]
+/// The glass of a window, which is recessed inside a frame.
+pub struct GlassStyle {
+    color: Color,
+    /// The depth of the glass, which is lower than the frame.
+    sill_depth: u8,
+    /// The thickness of the frame around the glass.
+    frame_thickness: u32,
+}
+
+impl GlassStyle {
+    pub fn new(color: Color, sill_depth: u8, frame_thickness: u32) -> Result<GlassStyle> {
+        if frame_thickness == 0 {
+            bail!("Argument 'frame_thickness' needs to be greater than 0");
+        }
+
+        Ok(GlassStyle {
+            color,
+            sill_depth,
+            frame_thickness,
+        })
+    }
+
+    /// Overwrites the area inside the frame with the glass.
+    fn render(&self, aabb: AABB, texture: &mut Texture) {
+        let double_frame = 2 * self.frame_thickness;
+
+        if aabb.size().width() <= double_frame || aabb.size().height() <= double_frame {
+            return;
+        }
+
+        let inner = AABB::new(
+            aabb.start() + self.frame_thickness as i32,
+            Size::new(
+                aabb.size().width() - double_frame,
+                aabb.size().height() - double_frame,
+            ),
+        );
+        let inner = match inner.intersection(&texture.get_aabb()) {
+            None => return,
+            Some(inner) => inner,
+        };
+        let width = texture.get_size().width() as usize;
+        let depth = texture.get_base_depth().saturating_add(self.sill_depth);
+
+        for y in inner.start().y..inner.end().y {
+            let start = y as usize * width;
+            let range = start + inner.start().x as usize..start + inner.end().x as usize;
+
+            texture.get_color_data_mut()[range.clone()]
+                .iter_mut()
+                .for_each(|color| *color = self.color);
+            texture.get_depth_data_mut()[range]
+                .iter_mut()
+                .for_each(|d| *d = depth);
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 /// Determines how a door is rendered.
 pub struct WindowStyle {
@@ -12,6 +75,8 @@ pub struct WindowStyle {
     pane_style: EdgeStyle,
     /// The style of the horizontal board below the window.
     stool_style: EdgeStyle,
+    /// The optional glass inside the pane, which turns the pane into a frame.
+    glass_style: Option<GlassStyle>,
 }
 
 impl WindowStyle {
@@ -27,17 +92,31 @@ impl WindowStyle {
         name: S,
         pane_style: EdgeStyle,
         stool_style: EdgeStyle,
+    ) -> WindowStyle {
+        Self::with_glass(name, pane_style, stool_style, None)
+    }
+
+    pub fn with_glass<S: Into<String>>(
+        name: S,
+        pane_style: EdgeStyle,
+        stool_style: EdgeStyle,
+        glass_style: Option<GlassStyle>,
     ) -> WindowStyle {
         WindowStyle {
             name: name.into(),
             pane_style,
             stool_style,
+            glass_style,
         }
     }
 
     pub fn render(&self, data: &Data, node: Point, edge: (i32, u32), texture: &mut Texture) {
         self.stool_style.render(data, node, edge, texture);
         self.pane_style.render(data, node, edge, texture);
+
+        if let Some(glass_style) = &self.glass_style {
+            glass_style.render(self.pane_style.get_aabb(data, node, edge), texture);
+        }
     }
 }
 
@@ -52,3 +131,57 @@ impl Resource for WindowStyle {
         &self.name
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use texture_generation::generation::component::rendering::RenderingComponent;
+    use texture_generation::math::color::{BLACK, BLUE, GREEN};
+
+    #[test]
+    #[should_panic]
+    fn test_new_glass_with_frame_too_thin() {
+        GlassStyle::new(BLUE, 2, 0).unwrap();
+    }
+
+    #[test]
+    fn test_render_glass() {
+        let pane_component = RenderingComponent::new_fill_area(GREEN, 9);
+        let pane_style = EdgeStyle::new_solid(4, pane_component).unwrap();
+        let glass_style = GlassStyle::new(BLUE, 2, 1).unwrap();
+        let window_style =
+            WindowStyle::with_glass("window", pane_style, EdgeStyle::Mock(4), Some(glass_style));
+        let mut texture = Texture::new(Size::new(8, 6), BLACK);
+
+        window_style.render(
+            &Data::for_texture(texture.get_aabb()),
+            Point::new(0, 3),
+            (1, 6),
+            &mut texture,
+        );
+
+        #[rustfmt::skip]
+        let result = vec![
+            BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK,
+            BLACK, GREEN, GREEN, GREEN, GREEN, GREEN, GREEN, BLACK,
+            BLACK, GREEN,  BLUE,  BLUE,  BLUE,  BLUE, GREEN, BLACK,
+            BLACK, GREEN,  BLUE,  BLUE,  BLUE,  BLUE, GREEN, BLACK,
+            BLACK, GREEN, GREEN, GREEN, GREEN, GREEN, GREEN, BLACK,
+            BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK, BLACK,
+        ];
+
+        assert_eq!(texture.get_color_data(), &result);
+
+        #[rustfmt::skip]
+        let depth = vec![
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 9, 9, 9, 9, 9, 9, 0,
+            0, 9, 2, 2, 2, 2, 9, 0,
+            0, 9, 2, 2, 2, 2, 9, 0,
+            0, 9, 9, 9, 9, 9, 9, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+
+        assert_eq!(texture.get_depth_data(), &depth);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::rendering::Renderer;
+use crate::tilemap::border::Border;
+use crate::tilemap::tile::Tile;
+use crate::tilemap::tilemap2d::Tilemap2d;
+use texture_generation::generation::data::texture::Texture;
+use texture_generation::math::side::Side;
+use texture_generation::math::size::Size;
+
+/// A resource shown in a thumbnail. Doors & windows use the first wall style.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Thumbnail {
+    Texture(usize),
+    Wall(usize),
+    Door(usize),
+    Window(usize),
+}
+
+impl Thumbnail {
+    /// Creates a tilemap with 2x2 tiles, which shows the resource in its center.
+    pub fn create_tilemap(&self) -> Tilemap2d {
+        let tile = match self {
+            Thumbnail::Texture(id) => Tile::Floor(*id),
+            _ => Tile::Empty,
+        };
+        let border = match self {
+            Thumbnail::Texture(_) => Border::Empty,
+            Thumbnail::Wall(id) => Border::Wall(*id),
+            Thumbnail::Door(id) => Border::new_door(0, *id, true),
+            Thumbnail::Window(id) => Border::new_window(0, *id),
+        };
+        let mut tilemap = Tilemap2d::default(Size::square(2), tile);
+
+        tilemap.set_border(0, Side::Bottom, border);
+        tilemap.set_border(1, Side::Bottom, border);
+
+        tilemap
+    }
+}
+
+impl Renderer {
+    /// Renders a small preview of the resource, which is twice as big as a tile.
+    pub fn render_thumbnail(&self, thumbnail: Thumbnail) -> Texture {
+        self.render(&thumbnail.create_tilemap(), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rendering::resource::Resources;
+    use crate::rendering::style::edge::EdgeStyle;
+    use crate::rendering::style::wall::WallStyle;
+    use texture_generation::generation::component::rendering::RenderingComponent;
+    use texture_generation::math::color::{BLACK, GREEN};
+    use texture_generation::utils::resource::ResourceManager;
+
+    #[test]
+    fn test_render_wall_thumbnail() {
+        let mut resources = Resources::empty();
+        let wall_rendering = RenderingComponent::new_fill_area(GREEN, 5);
+        let wall_edge = EdgeStyle::new_solid(2, wall_rendering).unwrap();
+        let wall = WallStyle::new("wall", wall_edge, None, None);
+        resources.wall_styles = ResourceManager::new(vec![wall], WallStyle::default(1));
+        let renderer = Renderer::new(32, 200, resources);
+
+        let texture = renderer.render_thumbnail(Thumbnail::Wall(0));
+
+        assert_eq!(texture.get_size(), &Size::square(64));
+        assert!(texture.get_color_data().contains(&GREEN));
+        assert!(texture.get_color_data().contains(&BLACK));
+    }
+
+    #[test]
+    fn test_create_texture_tilemap() {
+        let tilemap = Thumbnail::Texture(3).create_tilemap();
+
+        assert_eq!(tilemap.get_size(), Size::square(2));
+        assert_eq!(tilemap.get_tile(0), Tile::Floor(3));
+        assert_eq!(tilemap.get_border(0, Side::Bottom), Border::Empty);
+    }
+}
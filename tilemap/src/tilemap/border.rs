@@ -12,17 +12,33 @@ pub enum Border {
         wall_id: usize,
         door_id: usize,
         is_front: bool,
+        /// The number of adjacent border segments spanned by the door.
+        /// The walls of the additional segments are replaced by the opening.
+        width: u32,
     },
     /// A wall with a window.
     Window { wall_id: usize, window_id: usize },
+    /// A low wall or railing, which is rendered at half the height of the wall.
+    Railing(usize),
 }
 
 impl Border {
     pub const fn new_door(wall_id: usize, door_id: usize, is_front: bool) -> Border {
+        Self::new_wide_door(wall_id, door_id, is_front, 1)
+    }
+
+    /// Creates a door spanning multiple border segments, like double doors.
+    pub const fn new_wide_door(
+        wall_id: usize,
+        door_id: usize,
+        is_front: bool,
+        width: u32,
+    ) -> Border {
         Border::Door {
             wall_id,
             door_id,
             is_front,
+            width,
         }
     }
 
@@ -40,6 +56,7 @@ impl Border {
             Border::Wall(id) => Some(*id),
             Border::Door { wall_id, .. } => Some(*wall_id),
             Border::Window { wall_id, .. } => Some(*wall_id),
+            Border::Railing(id) => Some(*id),
         }
     }
 
@@ -49,7 +66,8 @@ impl Border {
                 wall_id,
                 door_id,
                 is_front,
-            } => Border::new_door(*wall_id, *door_id, !*is_front),
+                width,
+            } => Border::new_wide_door(*wall_id, *door_id, !*is_front, *width),
             _ => *self,
         }
     }
@@ -58,6 +76,7 @@ impl Border {
         match self {
             Border::Empty => Border::Empty,
             Border::Wall(..) => Border::Empty,
+            Border::Railing(..) => Border::Empty,
             Border::Door { wall_id, .. } => Border::Wall(*wall_id),
             Border::Window { wall_id, .. } => Border::Wall(*wall_id),
         }
@@ -124,6 +143,14 @@ mod tests {
         assert_eq!(WINDOW.switch_is_front(), WINDOW);
     }
 
+    #[test]
+    fn test_switch_is_front_of_wide_door() {
+        assert_eq!(
+            Border::new_wide_door(42, 2, true, 3).switch_is_front(),
+            Border::new_wide_door(42, 2, false, 3)
+        );
+    }
+
     #[test]
     fn test_reduce() {
         assert_eq!(Empty.reduce(), Empty);
@@ -1,6 +1,8 @@
+use crate::tilemap::furniture::snap::SnapMode;
 use crate::tilemap::furniture::Furniture;
 use anyhow::{bail, Result};
 use std::collections::HashMap;
+use texture_generation::math::aabb::AABB;
 use texture_generation::math::point::Point;
 use texture_generation::math::side::Side;
 use texture_generation::math::size::Size;
@@ -54,6 +56,19 @@ impl FurnitureMap2d {
         cell / RESOLUTION
     }
 
+    /// Returns the cell, where a [`Furniture`] placed at the position in pixels starts.
+    pub fn get_snapped_cell(&self, point: Point, cell_size: u32, snap: SnapMode) -> Point {
+        match snap.get_cells_per_step(RESOLUTION) {
+            None => point / cell_size,
+            Some(cells) => {
+                let step = (cell_size * cells) as f32;
+                let x = (point.x as f32 / step).round() as i32;
+                let y = (point.y as f32 / step).round() as i32;
+                Point::new(x, y) * cells
+            }
+        }
+    }
+
     /// Is the side of the cell a [`Border`] of the [`Tilemap2d`] or in the middle of a [`Tile`]?
     pub fn is_border(&self, cell: Point, side: Side) -> bool {
         match side {
@@ -64,15 +79,37 @@ impl FurnitureMap2d {
         }
     }
 
+    /// Can a [`Furniture`] be placed at the [`AABB`] without being partly outside or overlapping other furniture?
+    pub fn can_place(&self, aabb: &AABB) -> bool {
+        self.size.is_aabb_inside(aabb)
+            && !self
+                .furniture
+                .values()
+                .any(|furniture| furniture.aabb.overlaps(aabb))
+    }
+
     pub fn add(&mut self, furniture: Furniture) -> Option<usize> {
         if !self.size.is_aabb_inside(&furniture.aabb) {
             return None;
         }
 
+        Some(self.insert(furniture))
+    }
+
+    /// Adds the [`Furniture`] like [`FurnitureMap2d::add()`], but also fails if it overlaps other furniture.
+    pub fn add_without_overlap(&mut self, furniture: Furniture) -> Option<usize> {
+        if !self.can_place(&furniture.aabb) {
+            return None;
+        }
+
+        Some(self.insert(furniture))
+    }
+
+    fn insert(&mut self, furniture: Furniture) -> usize {
         let id = self.next_id;
         self.furniture.insert(id, furniture);
         self.next_id += 1;
-        Some(id)
+        id
     }
 
     pub fn get_all_furniture(&self) -> &HashMap<usize, Furniture> {
@@ -84,14 +121,30 @@ impl FurnitureMap2d {
     }
 
     pub fn get_id_at(&self, position: usize) -> Option<usize> {
-        let point = self.size.to_point(position);
-        for (id, furniture) in &self.furniture {
-            if furniture.aabb.is_inside(&point) {
-                return Some(*id);
-            }
+        self.get_id_at_cell(&self.size.to_point(position))
+    }
+
+    /// Returns the id of the [`Furniture`] under the position in pixels.
+    /// Prefers the last added one, if several overlap.
+    pub fn get_furniture_at(&self, point: Point, cell_size: Size) -> Option<usize> {
+        if point.x < 0 || point.y < 0 || cell_size.width() == 0 || cell_size.height() == 0 {
+            return None;
         }
 
-        None
+        let cell = Point::new(
+            point.x / cell_size.width() as i32,
+            point.y / cell_size.height() as i32,
+        );
+
+        self.get_id_at_cell(&cell)
+    }
+
+    fn get_id_at_cell(&self, cell: &Point) -> Option<usize> {
+        self.furniture
+            .iter()
+            .filter(|(_, furniture)| furniture.aabb.is_inside(cell))
+            .map(|(id, _)| *id)
+            .max()
     }
 
     pub fn remove_furniture(&mut self, id: usize) -> bool {
@@ -171,6 +224,100 @@ mod tests {
         assert!(map.get_all_furniture().is_empty());
     }
 
+    #[test]
+    fn test_can_place() {
+        let mut map = FurnitureMap2d::empty(Size::new(2, 3));
+        map.add(furniture1());
+
+        assert!(map.can_place(&AABB::new(Point::new(0, 0), Size::new(1, 4))));
+        assert!(map.can_place(&AABB::new(Point::new(3, 0), Size::new(1, 2))));
+    }
+
+    #[test]
+    fn test_can_place_with_overlap() {
+        let mut map = FurnitureMap2d::empty(Size::new(2, 3));
+        map.add(furniture1());
+
+        assert!(!map.can_place(&AABB::new(Point::new(0, 1), Size::new(2, 2))));
+        assert!(!map.can_place(&AABB::new(Point::new(2, 1), Size::square(1))));
+    }
+
+    #[test]
+    fn test_can_place_outside() {
+        let map = FurnitureMap2d::empty(Size::new(2, 3));
+
+        assert!(!map.can_place(&AABB::new(Point::new(-1, 0), Size::square(1))));
+        assert!(!map.can_place(&AABB::new(Point::new(3, 5), Size::square(2))));
+    }
+
+    #[test]
+    fn test_add_without_overlap() {
+        let mut map = FurnitureMap2d::empty(Size::new(2, 3));
+
+        assert_eq!(map.add_without_overlap(furniture1()), Some(0));
+        assert_eq!(map.add_without_overlap(furniture1()), None);
+        assert_eq!(map.add_without_overlap(furniture0()), Some(1));
+
+        assert_eq!(map.get_all_furniture().len(), 2);
+    }
+
+    #[test]
+    fn test_get_furniture_at() {
+        let mut map = FurnitureMap2d::empty(Size::new(2, 3));
+        map.add(furniture0());
+        map.add(furniture1());
+
+        assert_eq!(
+            map.get_furniture_at(Point::new(5, 8), Size::square(10)),
+            Some(0)
+        );
+        assert_eq!(
+            map.get_furniture_at(Point::new(25, 12), Size::square(10)),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_get_furniture_at_with_overlap() {
+        let mut map = FurnitureMap2d::empty(Size::new(2, 3));
+        map.add(Furniture::new(0, Point::new(0, 0), Size::square(3), Top).unwrap());
+        map.add(furniture1());
+        map.add(furniture0());
+
+        assert_eq!(
+            map.get_furniture_at(Point::new(5, 8), Size::square(10)),
+            Some(2)
+        );
+        assert_eq!(
+            map.get_furniture_at(Point::new(15, 8), Size::square(10)),
+            Some(1)
+        );
+        assert_eq!(
+            map.get_furniture_at(Point::new(5, 25), Size::square(10)),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_get_furniture_at_miss() {
+        let mut map = FurnitureMap2d::empty(Size::new(2, 3));
+        map.add(furniture0());
+        map.add(furniture1());
+
+        assert_eq!(
+            map.get_furniture_at(Point::new(5, 15), Size::square(10)),
+            None
+        );
+        assert_eq!(
+            map.get_furniture_at(Point::new(-5, 5), Size::square(10)),
+            None
+        );
+        assert_eq!(
+            map.get_furniture_at(Point::new(35, 5), Size::square(10)),
+            None
+        );
+    }
+
     #[test]
     fn test_remove_furniture() {
         let size = Size::new(2, 3);
@@ -186,6 +333,30 @@ mod tests {
         assert_eq!(map.get_furniture(1), Some(&furniture1()));
     }
 
+    #[test]
+    fn test_get_snapped_cell_without_snapping() {
+        let map = FurnitureMap2d::empty(Size::new(2, 3));
+
+        assert_snapped(&map, 95, 130, SnapMode::None, 1, 2);
+        assert_snapped(&map, 30, 140, SnapMode::None, 0, 2);
+    }
+
+    #[test]
+    fn test_get_snapped_cell_to_tile() {
+        let map = FurnitureMap2d::empty(Size::new(2, 3));
+
+        assert_snapped(&map, 95, 130, SnapMode::Tile, 2, 2);
+        assert_snapped(&map, 30, 140, SnapMode::Tile, 0, 2);
+    }
+
+    #[test]
+    fn test_get_snapped_cell_to_half_tile() {
+        let map = FurnitureMap2d::empty(Size::new(2, 3));
+
+        assert_snapped(&map, 95, 130, SnapMode::HalfTile, 2, 3);
+        assert_snapped(&map, 30, 140, SnapMode::HalfTile, 1, 3);
+    }
+
     #[test]
     fn test_is_border() {
         let size = Size::new(2, 3);
@@ -219,6 +390,13 @@ mod tests {
         assert!(map.is_border(cell, Right));
     }
 
+    fn assert_snapped(map: &FurnitureMap2d, x: i32, y: i32, snap: SnapMode, cx: i32, cy: i32) {
+        assert_eq!(
+            map.get_snapped_cell(Point::new(x, y), 50, snap),
+            Point::new(cx, cy)
+        );
+    }
+
     fn furniture0() -> Furniture {
         Furniture::new(0, Point::new(0, 0), Size::square(1), Top).unwrap()
     }
@@ -5,6 +5,7 @@ use texture_generation::math::side::Side;
 use texture_generation::math::size::Size;
 
 pub mod map2d;
+pub mod snap;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Furniture {
@@ -0,0 +1,49 @@
+use std::fmt;
+use std::slice::Iter;
+use SnapMode::*;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+/// To which grid is the start of a [`Furniture`](crate::tilemap::furniture::Furniture) snapped during placement?
+pub enum SnapMode {
+    /// Uses the cell under the position.
+    None,
+    /// Snaps to the nearest corner of a [`Tile`](crate::tilemap::tile::Tile).
+    Tile,
+    /// Snaps to the nearest corner of half a [`Tile`](crate::tilemap::tile::Tile).
+    HalfTile,
+}
+
+impl SnapMode {
+    pub fn iterator() -> Iter<'static, SnapMode> {
+        static MODES: [SnapMode; 3] = [None, Tile, HalfTile];
+        MODES.iter()
+    }
+
+    /// Returns the number of cells between 2 grid lines or None without snapping.
+    pub fn get_cells_per_step(&self, resolution: u32) -> Option<u32> {
+        match self {
+            None => Option::None,
+            Tile => Some(resolution),
+            HalfTile => Some((resolution / 2).max(1)),
+        }
+    }
+}
+
+impl fmt::Display for SnapMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_cells_per_step() {
+        assert_eq!(None.get_cells_per_step(4), Option::None);
+        assert_eq!(Tile.get_cells_per_step(4), Some(4));
+        assert_eq!(HalfTile.get_cells_per_step(4), Some(2));
+        assert_eq!(HalfTile.get_cells_per_step(1), Some(1));
+    }
+}
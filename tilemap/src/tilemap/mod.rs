@@ -2,5 +2,6 @@ pub mod border;
 pub mod furniture;
 pub mod node;
 pub mod selector;
+pub mod stats;
 pub mod tile;
 pub mod tilemap2d;
@@ -25,7 +25,37 @@ impl Selector {
         }
 
         let tile = point / self.tile_size;
-        tilemap.get_size().to_index(&tile)
+        tilemap.tile_index(tile.x as u32, tile.y as u32)
+    }
+
+    /// Returns all tiles inside the rectangle between 2 coordinates, which are clamped to the [`Tilemap2d`].
+    pub fn get_tiles_in_rect(&self, tilemap: &Tilemap2d, start: Point, end: Point) -> Vec<usize> {
+        let size = tilemap.get_size();
+        let max_x = size.width() as i32 - 1;
+        let max_y = size.height() as i32 - 1;
+        let tile_size = self.tile_size as i32;
+        let min = Point::new(start.x.min(end.x), start.y.min(end.y));
+        let max = Point::new(start.x.max(end.x), start.y.max(end.y));
+
+        if max.x < 0 || max.y < 0 {
+            return Vec::new();
+        }
+
+        let start_x = min.x.max(0) / tile_size;
+        let start_y = min.y.max(0) / tile_size;
+        let end_x = (max.x / tile_size).min(max_x);
+        let end_y = (max.y / tile_size).min(max_y);
+        let mut tiles = Vec::new();
+
+        for y in start_y..=end_y {
+            for x in start_x..=end_x {
+                if let Some(index) = size.to_index(&Point::new(x, y)) {
+                    tiles.push(index);
+                }
+            }
+        }
+
+        tiles
     }
 
     /// Returns the tile of the coordinates for the [`FurnitureMap2d`].
@@ -42,7 +72,8 @@ impl Selector {
     /// Returns which [`Side`] of a tile the coordinates are inside or None for its center or corners.
     pub fn get_side(&self, tilemap: &Tilemap2d, point: Point, tile_index: usize) -> Option<Side> {
         let tile_size = self.tile_size;
-        let start = tilemap.get_size().to_point(tile_index) * tile_size;
+        let (x, y) = tilemap.tile_xy(tile_index)?;
+        let start = Point::new(x as i32, y as i32) * tile_size;
         let local = point - start;
         let x = local.x as f32 / tile_size as f32;
         let y = local.y as f32 / tile_size as f32;
@@ -64,6 +95,48 @@ impl Selector {
             return None;
         })
     }
+
+    /// Returns the tile & [`Side`] of the border edge nearest to the coordinates,
+    /// or None, if no edge is close enough or the coordinates are near a corner.
+    /// The outer edges of the [`Tilemap2d`] use the [`Side`]s of the tiles inside.
+    pub fn get_border(&self, tilemap: &Tilemap2d, point: Point) -> Option<(usize, Side)> {
+        let size = tilemap.get_size();
+        let tile_size = self.tile_size as i32;
+        let threshold = tile_size as f32 * 0.1;
+        let edge_x = (point.x as f32 / tile_size as f32).round() as i32;
+        let edge_y = (point.y as f32 / tile_size as f32).round() as i32;
+        let distance_x = (point.x - edge_x * tile_size).abs() as f32;
+        let distance_y = (point.y - edge_y * tile_size).abs() as f32;
+        let is_near_x = distance_x < threshold;
+        let is_near_y = distance_y < threshold;
+
+        if is_near_x == is_near_y {
+            return None;
+        }
+
+        let tile_x = point.x.div_euclid(tile_size);
+        let tile_y = point.y.div_euclid(tile_size);
+
+        let (x, y, side) = if is_near_y {
+            if edge_y == size.height() as i32 {
+                (tile_x, edge_y - 1, Bottom)
+            } else {
+                (tile_x, edge_y, Top)
+            }
+        } else if edge_x == size.width() as i32 {
+            (edge_x - 1, tile_y, Right)
+        } else {
+            (edge_x, tile_y, Left)
+        };
+
+        if x < 0 || y < 0 {
+            return None;
+        }
+
+        tilemap
+            .tile_index(x as u32, y as u32)
+            .map(|index| (index, side))
+    }
 }
 
 #[cfg(test)]
@@ -96,6 +169,41 @@ mod tests {
         assert_outside(&selector, &tilemap, 50, 350);
     }
 
+    #[test]
+    fn test_get_tiles_in_rect() {
+        let selector = Selector::new(100);
+        let tilemap = Tilemap2d::default(Size::new(3, 4), Tile::Empty);
+
+        assert_eq!(
+            selector.get_tiles_in_rect(&tilemap, Point::new(150, 250), Point::new(50, 120)),
+            vec![3, 4, 6, 7]
+        );
+    }
+
+    #[test]
+    fn test_get_tiles_in_rect_partly_outside() {
+        let selector = Selector::new(100);
+        let tilemap = Tilemap2d::default(Size::new(3, 4), Tile::Empty);
+
+        assert_eq!(
+            selector.get_tiles_in_rect(&tilemap, Point::new(-50, 320), Point::new(250, 900)),
+            vec![9, 10, 11]
+        );
+    }
+
+    #[test]
+    fn test_get_tiles_in_rect_outside() {
+        let selector = Selector::new(100);
+        let tilemap = Tilemap2d::default(Size::new(3, 4), Tile::Empty);
+
+        assert!(selector
+            .get_tiles_in_rect(&tilemap, Point::new(-50, -20), Point::new(-10, 900))
+            .is_empty());
+        assert!(selector
+            .get_tiles_in_rect(&tilemap, Point::new(400, 20), Point::new(500, 90))
+            .is_empty());
+    }
+
     #[test]
     fn test_get_furniture_tile_index() {
         let selector = Selector::new(100);
@@ -133,6 +241,39 @@ mod tests {
         assert_side(&selector, &tilemap, 95, 150, 2, Some(Right));
     }
 
+    #[test]
+    fn test_get_border_near_horizontal_edge() {
+        let selector = Selector::new(100);
+        let tilemap = Tilemap2d::default(Size::new(2, 3), Tile::Empty);
+
+        assert_border(&selector, &tilemap, 50, 103, Some((2, Top)));
+        assert_border(&selector, &tilemap, 150, 97, Some((3, Top)));
+        assert_border(&selector, &tilemap, 50, 2, Some((0, Top)));
+        assert_border(&selector, &tilemap, 150, 304, Some((5, Bottom)));
+    }
+
+    #[test]
+    fn test_get_border_near_vertical_edge() {
+        let selector = Selector::new(100);
+        let tilemap = Tilemap2d::default(Size::new(2, 3), Tile::Empty);
+
+        assert_border(&selector, &tilemap, 103, 150, Some((3, Left)));
+        assert_border(&selector, &tilemap, 97, 250, Some((5, Left)));
+        assert_border(&selector, &tilemap, -3, 50, Some((0, Left)));
+        assert_border(&selector, &tilemap, 198, 50, Some((1, Right)));
+    }
+
+    #[test]
+    fn test_get_border_without_edge() {
+        let selector = Selector::new(100);
+        let tilemap = Tilemap2d::default(Size::new(2, 3), Tile::Empty);
+
+        assert_border(&selector, &tilemap, 50, 150, None);
+        assert_border(&selector, &tilemap, 102, 98, None);
+        assert_border(&selector, &tilemap, 50, 402, None);
+        assert_border(&selector, &tilemap, -50, 103, None);
+    }
+
     fn assert_index(selector: &Selector, tilemap: &Tilemap2d, x: i32, y: i32, index: usize) {
         assert_eq!(
             selector.get_tile_index(tilemap, Point::new(x, y)),
@@ -168,4 +309,14 @@ mod tests {
     ) {
         assert_eq!(selector.get_side(tilemap, Point::new(x, y), index), result);
     }
+
+    fn assert_border(
+        selector: &Selector,
+        tilemap: &Tilemap2d,
+        x: i32,
+        y: i32,
+        result: Option<(usize, Side)>,
+    ) {
+        assert_eq!(selector.get_border(tilemap, Point::new(x, y)), result);
+    }
 }
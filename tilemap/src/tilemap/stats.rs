@@ -0,0 +1,121 @@
+use crate::tilemap::border::Border;
+use crate::tilemap::tile::Tile;
+use crate::tilemap::tilemap2d::Tilemap2d;
+use std::fmt;
+
+/// The number of each type of [`Tile`] & [`Border`] of a [`Tilemap2d`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TilemapStats {
+    pub empty_tiles: usize,
+    pub floor_tiles: usize,
+    pub solid_tiles: usize,
+    pub ramp_tiles: usize,
+    pub empty_borders: usize,
+    pub walls: usize,
+    pub doors: usize,
+    pub windows: usize,
+    pub railings: usize,
+    /// The number of rooms found by [`Tilemap2d::find_rooms()`].
+    pub rooms: usize,
+}
+
+impl TilemapStats {
+    pub fn new(tilemap: &Tilemap2d) -> TilemapStats {
+        let mut stats = TilemapStats {
+            rooms: tilemap.find_rooms().len(),
+            ..TilemapStats::default()
+        };
+
+        for tile in tilemap.get_tiles() {
+            match tile {
+                Tile::Empty => stats.empty_tiles += 1,
+                Tile::Floor(_) => stats.floor_tiles += 1,
+                Tile::Solid(_) => stats.solid_tiles += 1,
+                Tile::Ramp { .. } => stats.ramp_tiles += 1,
+            }
+        }
+
+        let borders = tilemap
+            .get_horizontal_borders()
+            .iter()
+            .chain(tilemap.get_vertical_borders().iter());
+
+        for border in borders {
+            match border {
+                Border::Empty => stats.empty_borders += 1,
+                Border::Wall(_) => stats.walls += 1,
+                Border::Door { .. } => stats.doors += 1,
+                Border::Window { .. } => stats.windows += 1,
+                Border::Railing(_) => stats.railings += 1,
+            }
+        }
+
+        stats
+    }
+}
+
+impl fmt::Display for TilemapStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Tiles: empty={} floor={} solid={} ramp={}",
+            self.empty_tiles, self.floor_tiles, self.solid_tiles, self.ramp_tiles
+        )?;
+        writeln!(
+            f,
+            "Borders: empty={} wall={} door={} window={} railing={}",
+            self.empty_borders, self.walls, self.doors, self.windows, self.railings
+        )?;
+        write!(f, "Rooms: {}", self.rooms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use texture_generation::math::side::Side::*;
+    use texture_generation::math::size::Size;
+
+    #[test]
+    fn test_stats() {
+        #[rustfmt::skip]
+        let tiles = vec![
+            Tile::Floor(0), Tile::Floor(0), Tile::Solid(1),
+            Tile::Floor(0), Tile::Ramp { id: 0, direction: Top }, Tile::Empty,
+        ];
+        let mut tilemap = Tilemap2d::new(Size::new(3, 2), tiles).unwrap();
+        tilemap.set_border(0, Right, Border::Wall(0));
+        tilemap.set_border(3, Right, Border::new_door(0, 0, true));
+        tilemap.set_border(2, Bottom, Border::new_window(0, 0));
+        tilemap.set_border(0, Top, Border::Railing(0));
+        tilemap.set_border(1, Top, Border::Wall(0));
+
+        let stats = tilemap.stats();
+
+        assert_eq!(
+            stats,
+            TilemapStats {
+                empty_tiles: 1,
+                floor_tiles: 3,
+                solid_tiles: 1,
+                ramp_tiles: 1,
+                empty_borders: 12,
+                walls: 2,
+                doors: 1,
+                windows: 1,
+                railings: 1,
+                rooms: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let tilemap = Tilemap2d::default(Size::new(2, 1), Tile::Floor(0));
+
+        assert_eq!(
+            tilemap.stats().to_string(),
+            "Tiles: empty=0 floor=2 solid=0 ramp=0\nBorders: empty=7 wall=0 door=0 window=0 railing=0\nRooms: 1"
+        );
+    }
+}
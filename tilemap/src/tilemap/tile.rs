@@ -1,3 +1,5 @@
+use texture_generation::math::side::Side;
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Tile {
     /// Empty like a hole to a lower level or around a flying island.
@@ -6,4 +8,6 @@ pub enum Tile {
     Floor(usize),
     /// Full of a solid material like earth or stone. E.g. underground
     Solid(usize),
+    /// A ramp from the floor to the height of [`Tile::Solid`], which rises towards `direction`.
+    Ramp { id: usize, direction: Side },
 }
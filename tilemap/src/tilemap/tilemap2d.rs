@@ -1,9 +1,14 @@
+use crate::rendering::resource::Resources;
 use crate::tilemap::border::{
     below_tile, get_horizontal_borders_size, get_vertical_borders_size, left_of_tile,
     right_of_tile, Border,
 };
 use crate::tilemap::node::get_nodes_size;
+use crate::tilemap::stats::TilemapStats;
 use crate::tilemap::tile::Tile;
+use std::collections::VecDeque;
+use texture_generation::math::aabb::AABB;
+use texture_generation::math::point::Point;
 use texture_generation::math::side::Side;
 use texture_generation::math::size::Size;
 use Side::*;
@@ -64,8 +69,91 @@ impl Tilemap2d {
         self.size
     }
 
+    /// Returns a copy with a new [`Size`], which is anchored at the top-left.
+    /// Overlapping [`Tile`]s & [`Border`]s are preserved and new ones are empty.
+    pub fn resize(&self, new_size: Size) -> Tilemap2d {
+        info!("Resize tilemap from {:?} to {:?}", self.size, new_size);
+
+        self.extract(&AABB::with_size(new_size))
+    }
+
+    /// Returns a new tilemap with the [`Tile`]s inside the area in tile coordinates.
+    /// The [`Border`]s on the edge of the area are included. Parts outside of this tilemap are empty.
+    pub fn extract(&self, area: &AABB) -> Tilemap2d {
+        let start = area.start();
+        let new_size = area.size();
+        let tiles = copy_grid(&self.tiles, self.size, start, new_size, Tile::Empty);
+        let horizontal_borders = copy_grid(
+            &self.horizontal_borders,
+            get_horizontal_borders_size(self.size),
+            start,
+            get_horizontal_borders_size(new_size),
+            Border::Empty,
+        );
+        let vertical_borders = copy_grid(
+            &self.vertical_borders,
+            get_vertical_borders_size(self.size),
+            start,
+            get_vertical_borders_size(new_size),
+            Border::Empty,
+        );
+
+        Tilemap2d {
+            size: new_size,
+            tiles,
+            horizontal_borders,
+            vertical_borders,
+        }
+    }
+
     // Tiles
 
+    /// Converts the coordinates of a [`Tile`] to its index or returns None, if they are outside.
+    ///
+    /// ```
+    ///# use texture_generation::math::size::Size;
+    ///# use tilemap::tilemap::tile::Tile;
+    ///# use tilemap::tilemap::tilemap2d::Tilemap2d;
+    /// let tilemap = Tilemap2d::default(Size::new(2, 3), Tile::Empty);
+    ///
+    /// assert_eq!(tilemap.tile_index(0, 0), Some(0));
+    /// assert_eq!(tilemap.tile_index(1, 0), Some(1));
+    /// assert_eq!(tilemap.tile_index(0, 2), Some(4));
+    /// assert_eq!(tilemap.tile_index(1, 2), Some(5));
+    /// assert_eq!(tilemap.tile_index(2, 0), None);
+    /// assert_eq!(tilemap.tile_index(0, 3), None);
+    /// ```
+    pub fn tile_index(&self, x: u32, y: u32) -> Option<usize> {
+        if x < self.size.width() && y < self.size.height() {
+            Some(self.size.convert_x_y(x, y))
+        } else {
+            None
+        }
+    }
+
+    /// Converts the index of a [`Tile`] to its coordinates or returns None, if it is outside.
+    ///
+    /// ```
+    ///# use texture_generation::math::size::Size;
+    ///# use tilemap::tilemap::tile::Tile;
+    ///# use tilemap::tilemap::tilemap2d::Tilemap2d;
+    /// let tilemap = Tilemap2d::default(Size::new(2, 3), Tile::Empty);
+    ///
+    /// assert_eq!(tilemap.tile_xy(0), Some((0, 0)));
+    /// assert_eq!(tilemap.tile_xy(1), Some((1, 0)));
+    /// assert_eq!(tilemap.tile_xy(4), Some((0, 2)));
+    /// assert_eq!(tilemap.tile_xy(5), Some((1, 2)));
+    /// assert_eq!(tilemap.tile_xy(6), None);
+    /// ```
+    pub fn tile_xy(&self, index: usize) -> Option<(u32, u32)> {
+        if index >= self.tiles.len() {
+            return None;
+        }
+
+        let width = self.size.width() as usize;
+        Some(((index % width) as u32, (index / width) as u32))
+    }
+
     pub fn get_tiles(&self) -> &Vec<Tile> {
         &self.tiles
     }
@@ -79,6 +167,303 @@ impl Tilemap2d {
         self.tiles[index] = tile;
     }
 
+    /// Returns a copy rotated by 90° clockwise.
+    pub fn rotate_cw(&self) -> Tilemap2d {
+        self.transform(Transformation::RotateCw)
+    }
+
+    /// Returns a copy rotated by 90° counter-clockwise.
+    pub fn rotate_ccw(&self) -> Tilemap2d {
+        self.transform(Transformation::RotateCcw)
+    }
+
+    /// Returns a copy mirrored along the x-axis, so that left & right are swapped.
+    pub fn mirror_horizontal(&self) -> Tilemap2d {
+        self.transform(Transformation::MirrorHorizontal)
+    }
+
+    /// Returns a copy mirrored along the y-axis, so that top & bottom are swapped.
+    pub fn mirror_vertical(&self) -> Tilemap2d {
+        self.transform(Transformation::MirrorVertical)
+    }
+
+    fn transform(&self, transformation: Transformation) -> Tilemap2d {
+        let size = self.size;
+        let new_size = transformation.transform_size(size);
+        let horizontal_size = get_horizontal_borders_size(size);
+        let vertical_size = get_vertical_borders_size(size);
+        let new_horizontal_size = get_horizontal_borders_size(new_size);
+        let new_vertical_size = get_vertical_borders_size(new_size);
+        let mut tiles = vec![Tile::Empty; new_size.len()];
+        let mut horizontal_borders = vec![Border::Empty; new_horizontal_size.len()];
+        let mut vertical_borders = vec![Border::Empty; new_vertical_size.len()];
+        let mut reversed_doors = Vec::new();
+
+        for (index, tile) in self.tiles.iter().enumerate() {
+            let point = transformation.transform_tile(size, size.to_point(index));
+            tiles[new_size.to_index_risky(&point)] = transformation.transform_tile_type(*tile);
+        }
+
+        let borders = self
+            .horizontal_borders
+            .iter()
+            .enumerate()
+            .map(|(i, border)| (true, horizontal_size.to_point(i), border))
+            .chain(
+                self.vertical_borders
+                    .iter()
+                    .enumerate()
+                    .map(|(i, border)| (false, vertical_size.to_point(i), border)),
+            );
+
+        for (is_horizontal, point, border) in borders {
+            let (is_new_horizontal, point) =
+                transformation.transform_border(size, is_horizontal, point);
+            let border = transformation.transform_border_type(*border, is_horizontal);
+
+            if let Border::Door { width, .. } = border {
+                let span = if is_horizontal { Right } else { Bottom };
+
+                if width > 1 && matches!(transformation.transform_side(span), Left | Top) {
+                    reversed_doors.push((is_new_horizontal, point, width));
+                }
+            }
+
+            if is_new_horizontal {
+                horizontal_borders[new_horizontal_size.to_index_risky(&point)] = border;
+            } else {
+                vertical_borders[new_vertical_size.to_index_risky(&point)] = border;
+            }
+        }
+
+        // Wide doors span towards the right or bottom, so reversed ones need a new anchor.
+        for (is_horizontal, point, width) in reversed_doors {
+            let shift = width as i32 - 1;
+
+            if is_horizontal {
+                let anchor = Point::new((point.x - shift).max(0), point.y);
+                horizontal_borders.swap(
+                    new_horizontal_size.to_index_risky(&point),
+                    new_horizontal_size.to_index_risky(&anchor),
+                );
+            } else {
+                let anchor = Point::new(point.x, (point.y - shift).max(0));
+                vertical_borders.swap(
+                    new_vertical_size.to_index_risky(&point),
+                    new_vertical_size.to_index_risky(&anchor),
+                );
+            }
+        }
+
+        Tilemap2d {
+            size: new_size,
+            tiles,
+            horizontal_borders,
+            vertical_borders,
+        }
+    }
+
+    /// Replaces all tiles connected to the start tile, which are equal to it, with a new [`Tile`].
+    /// Tiles are only connected to their 4 direct neighbors.
+    /// Returns the number of changed tiles.
+    pub fn fill_region(&mut self, start: usize, new_tile: Tile) -> usize {
+        let old_tile = self.tiles[start];
+
+        if old_tile == new_tile {
+            return 0;
+        }
+
+        info!(
+            "Fill region at index {} from {:?} to {:?}",
+            start, old_tile, new_tile
+        );
+
+        let mut queue = VecDeque::new();
+        let mut count = 0;
+
+        self.tiles[start] = new_tile;
+        queue.push_back(start);
+
+        while let Some(index) = queue.pop_front() {
+            let point = self.size.to_point(index);
+            count += 1;
+
+            for neighbor in &[
+                Point::new(point.x, point.y - 1),
+                Point::new(point.x - 1, point.y),
+                Point::new(point.x, point.y + 1),
+                Point::new(point.x + 1, point.y),
+            ] {
+                if let Some(neighbor_index) = self.size.to_index(neighbor) {
+                    if self.tiles[neighbor_index] == old_tile {
+                        self.tiles[neighbor_index] = new_tile;
+                        queue.push_back(neighbor_index);
+                    }
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Finds the rooms, which are groups of connected [`Tile::Floor`] & [`Tile::Ramp`] tiles.
+    /// Tiles are only connected to their 4 direct neighbors, if no [`Border`] is between them.
+    /// Returns the sorted tile indices of each room ordered by their first index.
+    pub fn find_rooms(&self) -> Vec<Vec<usize>> {
+        let mut is_visited = vec![false; self.tiles.len()];
+        let mut rooms = Vec::new();
+
+        for start in 0..self.tiles.len() {
+            if is_visited[start] || !is_floor(self.tiles[start]) {
+                continue;
+            }
+
+            let mut room = Vec::new();
+            let mut queue = VecDeque::new();
+
+            is_visited[start] = true;
+            queue.push_back(start);
+
+            while let Some(index) = queue.pop_front() {
+                let point = self.size.to_point(index);
+                room.push(index);
+
+                for (side, neighbor) in &[
+                    (Top, Point::new(point.x, point.y - 1)),
+                    (Left, Point::new(point.x - 1, point.y)),
+                    (Bottom, Point::new(point.x, point.y + 1)),
+                    (Right, Point::new(point.x + 1, point.y)),
+                ] {
+                    if let Some(neighbor_index) = self.size.to_index(neighbor) {
+                        if !is_visited[neighbor_index]
+                            && is_floor(self.tiles[neighbor_index])
+                            && self.get_border(index, *side) == Border::Empty
+                        {
+                            is_visited[neighbor_index] = true;
+                            queue.push_back(neighbor_index);
+                        }
+                    }
+                }
+            }
+
+            room.sort_unstable();
+            rooms.push(room);
+        }
+
+        rooms
+    }
+
+    /// Counts the tiles & borders of each type & the rooms.
+    pub fn stats(&self) -> TilemapStats {
+        TilemapStats::new(self)
+    }
+
+    /// Returns the rectangle in tile coordinates, which contains all tiles & borders that are not empty.
+    /// A border is covered by the tile below or right of it, if that tile exists.
+    /// Returns `None` for an empty tilemap.
+    pub fn content_bounds(&self) -> Option<AABB> {
+        let max_x = self.size.width() as i32 - 1;
+        let max_y = self.size.height() as i32 - 1;
+        let horizontal_size = get_horizontal_borders_size(self.size);
+        let vertical_size = get_vertical_borders_size(self.size);
+
+        let tiles = self
+            .tiles
+            .iter()
+            .enumerate()
+            .filter(|(_, tile)| **tile != Tile::Empty)
+            .map(|(index, _)| self.size.to_point(index));
+        let horizontal_borders = self
+            .horizontal_borders
+            .iter()
+            .enumerate()
+            .filter(|(_, border)| **border != Border::Empty)
+            .map(|(index, _)| {
+                let point = horizontal_size.to_point(index);
+                Point::new(point.x, point.y.min(max_y))
+            });
+        let vertical_borders = self
+            .vertical_borders
+            .iter()
+            .enumerate()
+            .filter(|(_, border)| **border != Border::Empty)
+            .map(|(index, _)| {
+                let point = vertical_size.to_point(index);
+                Point::new(point.x.min(max_x), point.y)
+            });
+
+        let mut points = tiles.chain(horizontal_borders).chain(vertical_borders);
+        let first = points.next()?;
+        let (min, max) = points.fold((first, first), |(min, max), point| {
+            (min.min(&point), max.max(&point))
+        });
+        let size = Size::new((max.x - min.x + 1) as u32, (max.y - min.y + 1) as u32);
+
+        Some(AABB::new(min, size))
+    }
+
+    /// Checks that all ids of tiles & borders exist in the [`Resources`].
+    /// Returns a description of each problem otherwise.
+    pub fn validate(&self, resources: &Resources) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+
+        for (index, tile) in self.tiles.iter().enumerate() {
+            let id = match tile {
+                Tile::Empty => continue,
+                Tile::Floor(id) => *id,
+                Tile::Solid(id) => *id,
+                Tile::Ramp { id, .. } => *id,
+            };
+
+            if id >= resources.textures.len() {
+                problems.push(format!("Tile {} has unknown texture {}", index, id));
+            }
+        }
+
+        let borders = self
+            .horizontal_borders
+            .iter()
+            .enumerate()
+            .map(|(index, border)| ("Horizontal", index, border))
+            .chain(
+                self.vertical_borders
+                    .iter()
+                    .enumerate()
+                    .map(|(index, border)| ("Vertical", index, border)),
+            );
+
+        for (direction, index, border) in borders {
+            let mut check = |kind: &str, id: usize, len: usize| {
+                if id >= len {
+                    problems.push(format!(
+                        "{} border {} has unknown {} {}",
+                        direction, index, kind, id
+                    ));
+                }
+            };
+
+            if let Some(wall_id) = border.get_wall_style() {
+                check("wall style", wall_id, resources.wall_styles.len());
+            }
+
+            match border {
+                Border::Door { door_id, .. } => {
+                    check("door style", *door_id, resources.door_styles.len())
+                }
+                Border::Window { window_id, .. } => {
+                    check("window style", *window_id, resources.window_styles.len())
+                }
+                _ => {}
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
     /// Borders
 
     pub fn get_horizontal_borders(&self) -> &Vec<Border> {
@@ -115,6 +500,34 @@ impl Tilemap2d {
         };
     }
 
+    /// Sets the borders around a rectangle of tiles to a wall & leaves the borders inside unchanged.
+    /// The parts of the rectangle outside the tilemap are ignored.
+    pub fn add_walls_around(&mut self, area: AABB, wall_id: usize) {
+        let area = match area.intersection(&AABB::with_size(self.size)) {
+            None => return,
+            Some(area) => area,
+        };
+        let start = area.start();
+        let end = area.end() - 1;
+        let wall = Border::Wall(wall_id);
+
+        info!("Add walls around {:?}", area);
+
+        for x in start.x..=end.x {
+            let top = self.size.to_index_risky(&Point::new(x, start.y));
+            let bottom = self.size.to_index_risky(&Point::new(x, end.y));
+            self.set_border(top, Top, wall);
+            self.set_border(bottom, Bottom, wall);
+        }
+
+        for y in start.y..=end.y {
+            let left = self.size.to_index_risky(&Point::new(start.x, y));
+            let right = self.size.to_index_risky(&Point::new(end.x, y));
+            self.set_border(left, Left, wall);
+            self.set_border(right, Right, wall);
+        }
+    }
+
     // nodes
 
     /// Returns the [`Border`] on a specific side of a node.
@@ -155,10 +568,146 @@ impl Tilemap2d {
     }
 }
 
+/// The ways a [`Tilemap2d`] can be rotated or mirrored.
+#[derive(Copy, Clone, Debug)]
+enum Transformation {
+    RotateCw,
+    RotateCcw,
+    MirrorHorizontal,
+    MirrorVertical,
+}
+
+impl Transformation {
+    fn transform_size(&self, size: Size) -> Size {
+        match self {
+            Transformation::RotateCw | Transformation::RotateCcw => {
+                Size::new(size.height(), size.width())
+            }
+            _ => size,
+        }
+    }
+
+    fn transform_side(&self, side: Side) -> Side {
+        match (self, side) {
+            (Transformation::RotateCw, Top) => Right,
+            (Transformation::RotateCw, Right) => Bottom,
+            (Transformation::RotateCw, Bottom) => Left,
+            (Transformation::RotateCw, Left) => Top,
+            (Transformation::RotateCcw, Top) => Left,
+            (Transformation::RotateCcw, Left) => Bottom,
+            (Transformation::RotateCcw, Bottom) => Right,
+            (Transformation::RotateCcw, Right) => Top,
+            (Transformation::MirrorHorizontal, Left) => Right,
+            (Transformation::MirrorHorizontal, Right) => Left,
+            (Transformation::MirrorVertical, Top) => Bottom,
+            (Transformation::MirrorVertical, Bottom) => Top,
+            (_, side) => side,
+        }
+    }
+
+    fn transform_tile(&self, size: Size, point: Point) -> Point {
+        let width = size.width() as i32;
+        let height = size.height() as i32;
+
+        match self {
+            Transformation::RotateCw => Point::new(height - 1 - point.y, point.x),
+            Transformation::RotateCcw => Point::new(point.y, width - 1 - point.x),
+            Transformation::MirrorHorizontal => Point::new(width - 1 - point.x, point.y),
+            Transformation::MirrorVertical => Point::new(point.x, height - 1 - point.y),
+        }
+    }
+
+    /// Transforms the position of a border. Returns true, if it is horizontal afterwards.
+    fn transform_border(&self, size: Size, is_horizontal: bool, point: Point) -> (bool, Point) {
+        let width = size.width() as i32;
+        let height = size.height() as i32;
+
+        match (self, is_horizontal) {
+            (Transformation::RotateCw, true) => (false, Point::new(height - point.y, point.x)),
+            (Transformation::RotateCw, false) => (true, Point::new(height - 1 - point.y, point.x)),
+            (Transformation::RotateCcw, true) => (false, Point::new(point.y, width - 1 - point.x)),
+            (Transformation::RotateCcw, false) => (true, Point::new(point.y, width - point.x)),
+            (Transformation::MirrorHorizontal, true) => {
+                (true, Point::new(width - 1 - point.x, point.y))
+            }
+            (Transformation::MirrorHorizontal, false) => {
+                (false, Point::new(width - point.x, point.y))
+            }
+            (Transformation::MirrorVertical, true) => (true, Point::new(point.x, height - point.y)),
+            (Transformation::MirrorVertical, false) => {
+                (false, Point::new(point.x, height - 1 - point.y))
+            }
+        }
+    }
+
+    fn transform_tile_type(&self, tile: Tile) -> Tile {
+        match tile {
+            Tile::Ramp { id, direction } => Tile::Ramp {
+                id,
+                direction: self.transform_side(direction),
+            },
+            _ => tile,
+        }
+    }
+
+    /// Doors of horizontal borders are in front towards the bottom & of vertical ones towards the right.
+    fn transform_border_type(&self, border: Border, is_horizontal: bool) -> Border {
+        match border {
+            Border::Door {
+                wall_id,
+                door_id,
+                is_front,
+                width,
+            } => {
+                let facing = match (is_horizontal, is_front) {
+                    (true, true) => Bottom,
+                    (true, false) => Top,
+                    (false, true) => Right,
+                    (false, false) => Left,
+                };
+                let is_front = matches!(self.transform_side(facing), Bottom | Right);
+
+                Border::new_wide_door(wall_id, door_id, is_front, width)
+            }
+            _ => border,
+        }
+    }
+}
+
+/// Copies the part of a grid starting at `start` into a grid with a new [`Size`].
+/// Values outside the original grid are set to `default`.
+fn copy_grid<T: Copy>(
+    values: &[T],
+    size: Size,
+    start: Point,
+    new_size: Size,
+    default: T,
+) -> Vec<T> {
+    let mut new_values = Vec::with_capacity(new_size.len());
+
+    for y in 0..new_size.height() as i32 {
+        for x in 0..new_size.width() as i32 {
+            let point = Point::new(start.x + x, start.y + y);
+            new_values.push(size.to_index(&point).map_or(default, |index| values[index]));
+        }
+    }
+
+    new_values
+}
+
+fn is_floor(tile: Tile) -> bool {
+    matches!(tile, Tile::Floor(_) | Tile::Ramp { .. })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rendering::style::door::DoorStyle;
+    use crate::rendering::style::wall::WallStyle;
+    use crate::rendering::style::window::WindowStyle;
+    use texture_generation::generation::TextureGenerator;
     use texture_generation::math::size::Size;
+    use texture_generation::utils::resource::ResourceManager;
     use Border::Empty;
 
     const WALL0: Border = Border::Wall(0);
@@ -201,6 +750,430 @@ mod tests {
         assert_eq!(tilemap.get_tiles(), &create_tiles());
     }
 
+    #[test]
+    fn test_fill_region() {
+        let size = Size::new(4, 3);
+        #[rustfmt::skip]
+        let tiles = vec![
+            Tile::Empty,    Tile::Empty,    Tile::Solid(1), Tile::Empty,
+            Tile::Solid(1), Tile::Empty,    Tile::Solid(1), Tile::Empty,
+            Tile::Empty,    Tile::Floor(2), Tile::Solid(1), Tile::Empty,
+        ];
+        let mut tilemap = Tilemap2d::new(size, tiles).unwrap();
+
+        assert_eq!(tilemap.fill_region(1, Tile::Floor(3)), 3);
+
+        #[rustfmt::skip]
+        let result = vec![
+            Tile::Floor(3), Tile::Floor(3), Tile::Solid(1), Tile::Empty,
+            Tile::Solid(1), Tile::Floor(3), Tile::Solid(1), Tile::Empty,
+            Tile::Empty,    Tile::Floor(2), Tile::Solid(1), Tile::Empty,
+        ];
+
+        assert_eq!(tilemap.get_tiles(), &result);
+    }
+
+    #[test]
+    fn test_add_walls_around() {
+        let mut tilemap = Tilemap2d::default(Size::square(4), Tile::Empty);
+
+        tilemap.add_walls_around(AABB::new(Point::new(1, 1), Size::square(2)), 0);
+
+        #[rustfmt::skip]
+        assert_eq!(
+            tilemap.get_horizontal_borders(),
+            &vec![
+                Empty, Empty, Empty, Empty,
+                Empty, WALL0, WALL0, Empty,
+                Empty, Empty, Empty, Empty,
+                Empty, WALL0, WALL0, Empty,
+                Empty, Empty, Empty, Empty,
+            ]
+        );
+
+        #[rustfmt::skip]
+        assert_eq!(
+            tilemap.get_vertical_borders(),
+            &vec![
+                Empty, Empty, Empty, Empty, Empty,
+                Empty, WALL0, Empty, WALL0, Empty,
+                Empty, WALL0, Empty, WALL0, Empty,
+                Empty, Empty, Empty, Empty, Empty,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_add_walls_around_keeps_interior_borders() {
+        let mut tilemap = Tilemap2d::default(Size::new(3, 1), Tile::Empty);
+        tilemap.set_border(0, Right, Border::new_door(1, 2, true));
+
+        tilemap.add_walls_around(AABB::new(Point::new(0, 0), Size::new(2, 1)), 3);
+
+        assert_eq!(tilemap.get_border(0, Right), Border::new_door(1, 2, true));
+        assert_eq!(tilemap.get_border(0, Left), WALL3);
+        assert_eq!(tilemap.get_border(1, Right), WALL3);
+        assert_eq!(tilemap.get_border(2, Right), Empty);
+        assert_eq!(tilemap.get_border(2, Top), Empty);
+    }
+
+    #[test]
+    fn test_add_walls_around_is_clipped() {
+        let mut tilemap = Tilemap2d::default(Size::new(2, 1), Tile::Empty);
+
+        tilemap.add_walls_around(AABB::new(Point::new(1, -1), Size::new(3, 3)), 1);
+
+        assert_eq!(tilemap.get_vertical_borders(), &vec![Empty, WALL1, WALL1]);
+        assert_eq!(
+            tilemap.get_horizontal_borders(),
+            &vec![Empty, WALL1, Empty, WALL1]
+        );
+    }
+
+    #[test]
+    fn test_content_bounds_of_empty_map() {
+        let tilemap = Tilemap2d::default(Size::new(4, 3), Tile::Empty);
+
+        assert_eq!(tilemap.content_bounds(), None);
+    }
+
+    #[test]
+    fn test_content_bounds_of_single_tile() {
+        let mut tilemap = Tilemap2d::default(Size::new(4, 3), Tile::Empty);
+        tilemap.set_tile(6, Tile::Floor(0));
+
+        assert_eq!(
+            tilemap.content_bounds(),
+            Some(AABB::new(Point::new(2, 1), Size::square(1)))
+        );
+    }
+
+    #[test]
+    fn test_content_bounds_of_sparse_map() {
+        let mut tilemap = Tilemap2d::default(Size::new(5, 4), Tile::Empty);
+        tilemap.set_tile(6, Tile::Solid(0));
+        tilemap.set_border(13, Right, WALL0);
+        tilemap.set_border(17, Bottom, WALL1);
+
+        assert_eq!(
+            tilemap.content_bounds(),
+            Some(AABB::new(Point::new(1, 1), Size::new(4, 3)))
+        );
+    }
+
+    #[test]
+    fn test_content_bounds_of_border_on_edge() {
+        let mut tilemap = Tilemap2d::default(Size::new(3, 3), Tile::Empty);
+        tilemap.set_border(8, Right, WALL0);
+
+        assert_eq!(
+            tilemap.content_bounds(),
+            Some(AABB::new(Point::new(2, 2), Size::square(1)))
+        );
+    }
+
+    #[test]
+    fn test_validate_valid_map() {
+        let mut tilemap = Tilemap2d::default(Size::new(2, 1), Tile::Floor(1));
+        tilemap.set_tile(1, Tile::Solid(0));
+        tilemap.set_border(0, Top, WALL1);
+        tilemap.set_border(0, Right, Border::new_door(0, 0, true));
+        tilemap.set_border(1, Right, Border::new_window(1, 0));
+
+        assert_eq!(tilemap.validate(&create_resources()), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_dangling_ids() {
+        let mut tilemap = Tilemap2d::default(Size::new(2, 1), Tile::Floor(0));
+        tilemap.set_tile(1, Tile::Solid(2));
+        tilemap.set_border(0, Top, WALL2);
+        tilemap.set_border(0, Right, Border::new_door(0, 1, true));
+        tilemap.set_border(1, Right, Border::new_window(3, 1));
+
+        assert_eq!(
+            tilemap.validate(&create_resources()),
+            Err(vec![
+                "Tile 1 has unknown texture 2".to_string(),
+                "Horizontal border 0 has unknown wall style 2".to_string(),
+                "Vertical border 1 has unknown door style 1".to_string(),
+                "Vertical border 2 has unknown wall style 3".to_string(),
+                "Vertical border 2 has unknown window style 1".to_string(),
+            ])
+        );
+    }
+
+    fn create_resources() -> Resources {
+        let mut resources = Resources::empty();
+        resources.textures = ResourceManager::new(
+            vec![TextureGenerator::default(), TextureGenerator::default()],
+            TextureGenerator::default(),
+        );
+        resources.wall_styles = ResourceManager::new(
+            vec![WallStyle::default(1), WallStyle::default(2)],
+            WallStyle::default(1),
+        );
+        resources.door_styles =
+            ResourceManager::new(vec![DoorStyle::default(1)], DoorStyle::default(1));
+        resources.window_styles =
+            ResourceManager::new(vec![WindowStyle::default(1)], WindowStyle::default(1));
+        resources
+    }
+
+    #[test]
+    fn test_find_rooms_separated_by_wall() {
+        let mut tilemap = Tilemap2d::default(Size::new(4, 2), Tile::Floor(0));
+        tilemap.set_border(1, Right, WALL0);
+        tilemap.set_border(5, Right, WALL1);
+
+        assert_eq!(
+            tilemap.find_rooms(),
+            vec![vec![0, 1, 4, 5], vec![2, 3, 6, 7]]
+        );
+    }
+
+    #[test]
+    fn test_find_rooms_with_door_and_window() {
+        let mut tilemap = Tilemap2d::default(Size::new(3, 2), Tile::Floor(0));
+        tilemap.set_tile(1, Tile::Solid(1));
+        tilemap.set_border(3, Right, Border::new_door(0, 0, true));
+        tilemap.set_border(4, Right, Border::new_window(0, 0));
+
+        assert_eq!(tilemap.find_rooms(), vec![vec![0, 3], vec![2, 5], vec![4]]);
+    }
+
+    #[test]
+    fn test_find_rooms_ignores_empty_and_solid_tiles() {
+        #[rustfmt::skip]
+        let tiles = vec![
+            Tile::Floor(0), Tile::Empty,    Tile::Floor(1),
+            Tile::Floor(0), Tile::Solid(2), Tile::Floor(1),
+        ];
+        let tilemap = Tilemap2d::new(Size::new(3, 2), tiles).unwrap();
+
+        assert_eq!(tilemap.find_rooms(), vec![vec![0, 3], vec![2, 5]]);
+    }
+
+    #[test]
+    fn test_find_rooms_of_open_map() {
+        let tilemap = Tilemap2d::default(Size::new(2, 2), Tile::Floor(0));
+
+        assert_eq!(tilemap.find_rooms(), vec![vec![0, 1, 2, 3]]);
+    }
+
+    #[test]
+    fn test_fill_region_with_same_tile() {
+        let mut tilemap = Tilemap2d::new(Size::new(2, 3), create_tiles()).unwrap();
+
+        assert_eq!(tilemap.fill_region(1, Tile::Empty), 0);
+        assert_eq!(tilemap.get_tiles(), &create_tiles());
+    }
+
+    #[test]
+    fn test_resize_grow() {
+        let mut tilemap = Tilemap2d::new(Size::new(2, 3), create_tiles()).unwrap();
+        tilemap.set_border(4, Bottom, WALL0);
+        tilemap.set_border(1, Right, WALL1);
+
+        let resized = tilemap.resize(Size::new(3, 4));
+
+        #[rustfmt::skip]
+        let tiles = vec![
+            Tile::Floor(1), Tile::Empty, Tile::Empty,
+            Tile::Solid(3), Tile::Empty, Tile::Empty,
+            Tile::Floor(4), Tile::Empty, Tile::Empty,
+            Tile::Empty,    Tile::Empty, Tile::Empty,
+        ];
+
+        assert_eq!(resized.get_size(), Size::new(3, 4));
+        assert_eq!(resized.get_tiles(), &tiles);
+        assert_eq!(resized.get_border(6, Bottom), WALL0);
+        assert_eq!(resized.get_border(1, Right), WALL1);
+        assert_eq!(resized.get_border(2, Right), Empty);
+    }
+
+    #[test]
+    fn test_resize_shrink() {
+        let mut tilemap = Tilemap2d::new(Size::new(2, 3), create_tiles()).unwrap();
+        tilemap.set_border(0, Bottom, WALL0);
+        tilemap.set_border(4, Bottom, WALL1);
+
+        let resized = tilemap.resize(Size::new(1, 2));
+
+        assert_eq!(resized.get_size(), Size::new(1, 2));
+        assert_eq!(resized.get_tiles(), &vec![Tile::Floor(1), Tile::Solid(3)]);
+        assert_eq!(resized.get_border(0, Bottom), WALL0);
+        assert!(!resized.get_horizontal_borders().contains(&WALL1));
+    }
+
+    #[test]
+    fn test_resize_mixed() {
+        let tilemap = Tilemap2d::new(Size::new(2, 3), create_tiles()).unwrap();
+
+        let resized = tilemap.resize(Size::new(3, 1));
+
+        assert_eq!(
+            resized.get_tiles(),
+            &vec![Tile::Floor(1), Tile::Empty, Tile::Empty]
+        );
+        assert_eq!(
+            resized.resize(Size::new(2, 3)).get_tiles(),
+            &vec![
+                Tile::Floor(1),
+                Tile::Empty,
+                Tile::Empty,
+                Tile::Empty,
+                Tile::Empty,
+                Tile::Empty
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract() {
+        let mut tilemap = create_asymmetric_tilemap();
+        tilemap.set_border(1, Top, WALL0);
+        tilemap.set_border(1, Left, WALL1);
+        tilemap.set_border(5, Bottom, WALL2);
+        tilemap.set_border(5, Right, WALL3);
+        tilemap.set_border(0, Left, WALL0);
+
+        let area = AABB::new(Point::new(1, 0), Size::new(2, 2));
+        let extracted = tilemap.extract(&area);
+
+        assert_eq!(extracted.get_size(), Size::new(2, 2));
+        assert_eq!(extracted.get_tiles().len(), 4);
+        assert_eq!(extracted.get_horizontal_borders().len(), 6);
+        assert_eq!(extracted.get_vertical_borders().len(), 6);
+        assert_eq!(
+            extracted.get_tiles(),
+            &vec![
+                Tile::Floor(1),
+                Tile::Floor(2),
+                Tile::Floor(4),
+                Tile::Floor(5)
+            ]
+        );
+        assert_eq!(extracted.get_border(0, Top), WALL0);
+        assert_eq!(extracted.get_border(0, Left), WALL1);
+        assert_eq!(extracted.get_border(3, Bottom), WALL2);
+        assert_eq!(extracted.get_border(3, Right), WALL3);
+        assert_eq!(count_walls(&extracted), 4);
+    }
+
+    #[test]
+    fn test_extract_partly_outside() {
+        let tilemap = create_asymmetric_tilemap();
+
+        let area = AABB::new(Point::new(2, 1), Size::new(2, 3));
+        let extracted = tilemap.extract(&area);
+
+        assert_eq!(extracted.get_size(), Size::new(2, 3));
+        assert_eq!(extracted.get_tile(0), Tile::Floor(5));
+        assert_eq!(
+            extracted
+                .get_tiles()
+                .iter()
+                .filter(|tile| **tile == Tile::Empty)
+                .count(),
+            5
+        );
+    }
+
+    #[test]
+    fn test_rotate_cw() {
+        let mut tilemap = create_asymmetric_tilemap();
+        tilemap.set_border(0, Top, WALL1);
+        tilemap.set_border(5, Right, WALL2);
+
+        let rotated = tilemap.rotate_cw();
+
+        assert_eq!(rotated.get_size(), Size::new(2, 3));
+        assert_eq!(
+            rotated.get_tiles(),
+            &vec![
+                Tile::Floor(3),
+                Tile::Floor(0),
+                Tile::Floor(4),
+                Tile::Floor(1),
+                Tile::Floor(5),
+                Tile::Floor(2),
+            ]
+        );
+        assert_eq!(rotated.get_border(1, Right), WALL1);
+        assert_eq!(rotated.get_border(4, Bottom), WALL2);
+        assert_eq!(count_walls(&rotated), 2);
+    }
+
+    #[test]
+    fn test_rotate_ccw() {
+        let mut tilemap = create_asymmetric_tilemap();
+        tilemap.set_border(0, Top, WALL1);
+        tilemap.set_border(5, Right, WALL2);
+
+        let rotated = tilemap.rotate_ccw();
+
+        assert_eq!(rotated.get_size(), Size::new(2, 3));
+        assert_eq!(
+            rotated.get_tiles(),
+            &vec![
+                Tile::Floor(2),
+                Tile::Floor(5),
+                Tile::Floor(1),
+                Tile::Floor(4),
+                Tile::Floor(0),
+                Tile::Floor(3),
+            ]
+        );
+        assert_eq!(rotated.get_border(4, Left), WALL1);
+        assert_eq!(rotated.get_border(1, Top), WALL2);
+        assert_eq!(count_walls(&rotated), 2);
+    }
+
+    #[test]
+    fn test_mirror() {
+        let mut tilemap = create_asymmetric_tilemap();
+        tilemap.set_border(0, Top, WALL1);
+        tilemap.set_border(5, Right, WALL2);
+
+        let horizontal = tilemap.mirror_horizontal();
+
+        assert_eq!(horizontal.get_tile(2), Tile::Floor(0));
+        assert_eq!(horizontal.get_border(2, Top), WALL1);
+        assert_eq!(horizontal.get_border(3, Left), WALL2);
+
+        let vertical = tilemap.mirror_vertical();
+
+        assert_eq!(vertical.get_tile(3), Tile::Floor(0));
+        assert_eq!(vertical.get_border(3, Bottom), WALL1);
+        assert_eq!(vertical.get_border(2, Right), WALL2);
+    }
+
+    #[test]
+    fn test_transformations_are_reversible() {
+        let mut tilemap = create_asymmetric_tilemap();
+        tilemap.set_tile(
+            4,
+            Tile::Ramp {
+                id: 3,
+                direction: Left,
+            },
+        );
+        tilemap.set_border(0, Top, WALL1);
+        tilemap.set_border(5, Right, WALL2);
+        tilemap.set_border(1, Bottom, Border::new_wide_door(0, 1, true, 2));
+        tilemap.set_border(1, Right, Border::new_door(0, 2, false));
+
+        assert_eq!(tilemap.rotate_cw().rotate_ccw(), tilemap);
+        assert_eq!(tilemap.rotate_ccw().rotate_cw(), tilemap);
+        assert_eq!(
+            tilemap.rotate_cw().rotate_cw().rotate_cw().rotate_cw(),
+            tilemap
+        );
+        assert_eq!(tilemap.mirror_horizontal().mirror_horizontal(), tilemap);
+        assert_eq!(tilemap.mirror_vertical().mirror_vertical(), tilemap);
+    }
+
     #[test]
     fn test_borders() {
         let size = Size::new(3, 3);
@@ -249,6 +1222,21 @@ mod tests {
         assert_eq!(tilemap.get_border_at_node(5, Left), WALL3);
     }
 
+    /// Creates a tilemap with 3 x 2 different tiles.
+    fn create_asymmetric_tilemap() -> Tilemap2d {
+        let tiles = (0..6).map(Tile::Floor).collect();
+        Tilemap2d::new(Size::new(3, 2), tiles).unwrap()
+    }
+
+    fn count_walls(tilemap: &Tilemap2d) -> usize {
+        tilemap
+            .get_horizontal_borders()
+            .iter()
+            .chain(tilemap.get_vertical_borders().iter())
+            .filter(|border| border.is_wall())
+            .count()
+    }
+
     fn create_tiles() -> Vec<Tile> {
         vec![
             Tile::Floor(1),
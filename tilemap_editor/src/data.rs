@@ -1,6 +1,9 @@
 use crate::resources::ResourceInfo;
+use crate::thumbnails::Thumbnails;
+use crate::viewport::Viewport;
 use iced_native::image;
 use texture_generation::math::color::convert_bgra;
+use texture_generation::math::side::Side;
 use texture_generation::math::size::Size;
 use tilemap::rendering::Renderer;
 use tilemap::tilemap::furniture::map2d::FurnitureMap2d;
@@ -10,20 +13,28 @@ use tilemap::tilemap::tilemap2d::Tilemap2d;
 use tilemap_io::tilemap::furniture::map2d::{save_furniture_map, FURNITURE_MAP_FILE_ENDING};
 use tilemap_io::tilemap::{save_tilemap, TILEMAP_FILE_ENDING};
 
+/// The number of tiles visible in the preview along each axis.
+const VIEWPORT_TILES: u32 = 16;
+
 pub struct EditorData {
     pub resource_info: ResourceInfo,
     pub renderer: Renderer,
     pub preview_renderer: Renderer,
+    pub thumbnails: Thumbnails,
     pub tilemap: Tilemap2d,
     pub furniture_map: FurnitureMap2d,
     pub selector: Selector,
+    pub viewport: Viewport,
 }
 
 impl EditorData {
-    pub fn new(resource_info: ResourceInfo) -> Self {
-        let (renderer, preview_renderer) = resource_info.create_renderers();
+    pub fn new(mut resource_info: ResourceInfo) -> Self {
+        let (renderer, preview_renderer, thumbnails) = resource_info.create_renderers();
         let (tilemap, furniture_map) = match resource_info.load_maps() {
-            Ok(maps) => maps,
+            Ok(maps) => {
+                validate_tilemap(&maps.0, &renderer);
+                maps
+            }
             Err(error) => {
                 eprintln!("Error: {:?}", error);
                 let tilemap = Tilemap2d::default(Size::square(5), Tile::Floor(999));
@@ -37,25 +48,69 @@ impl EditorData {
             resource_info,
             renderer,
             preview_renderer,
+            thumbnails,
             tilemap,
             furniture_map,
             selector,
+            viewport: Viewport::new(Size::square(VIEWPORT_TILES)),
         }
     }
 
+    /// Renders the visible part of the tilemap.
+    /// The furniture is only rendered, if the whole tilemap is visible.
     pub fn render_preview(&self) -> image::Handle {
-        let texture = self
-            .preview_renderer
-            .render(&self.tilemap, Some(&self.furniture_map));
+        let map_size = self.tilemap.get_size();
+        let texture = if self.viewport.is_showing_all(map_size) {
+            self.preview_renderer
+                .render(&self.tilemap, Some(&self.furniture_map))
+        } else {
+            self.preview_renderer
+                .render_region(&self.tilemap, &self.viewport.get_area(map_size))
+        };
         let rbg = convert_bgra(texture.get_color_data());
         let size = texture.get_size();
         image::Handle::from_pixels(size.width(), size.height(), rbg)
     }
 
-    pub fn reload_resources(&mut self) {
-        let (renderer, preview_renderer) = self.resource_info.create_renderers();
-        self.renderer = renderer;
-        self.preview_renderer = preview_renderer;
+    /// Reloads the changed resources. Returns true, if anything changed.
+    pub fn reload_resources(&mut self) -> bool {
+        if let Some((renderer, preview_renderer, thumbnails)) =
+            self.resource_info.reload_renderers()
+        {
+            self.renderer = renderer;
+            self.preview_renderer = preview_renderer;
+            self.thumbnails = thumbnails;
+            return true;
+        }
+
+        false
+    }
+
+    /// Moves the visible part of the tilemap by a tile. Returns true, if it moved.
+    pub fn pan(&mut self, side: Side) -> bool {
+        self.viewport.pan(side, self.tilemap.get_size())
+    }
+
+    /// Converts the coordinates of the preview to the coordinates of the whole tilemap.
+    pub fn convert_from_preview(&self, x: u32, y: u32) -> (u32, u32) {
+        let start = self.viewport.get_area(self.tilemap.get_size()).start();
+        let tile_size = self.preview_renderer.get_tile_size();
+
+        (
+            x + start.x as u32 * tile_size,
+            y + start.y as u32 * tile_size,
+        )
+    }
+
+    /// Zooms the preview in or out by changing its tile size. Returns true, if it changed.
+    pub fn zoom(&mut self, zoom_in: bool) -> bool {
+        if !self.resource_info.zoom(zoom_in) {
+            return false;
+        }
+
+        self.preview_renderer = self.resource_info.create_preview_renderer();
+        self.selector = Selector::new(self.preview_renderer.get_tile_size());
+        true
     }
 
     pub fn load_maps(&mut self) -> bool {
@@ -63,6 +118,7 @@ impl EditorData {
 
         match self.resource_info.load_maps() {
             Ok((tilemap, furniture_map)) => {
+                validate_tilemap(&tilemap, &self.renderer);
                 self.tilemap = tilemap;
                 self.furniture_map = furniture_map;
                 info!("Finished loading");
@@ -88,3 +144,12 @@ impl EditorData {
         info!("Finished saving");
     }
 }
+
+/// Logs the ids of the tilemap, which are missing in the resources of the renderer.
+fn validate_tilemap(tilemap: &Tilemap2d, renderer: &Renderer) {
+    if let Err(problems) = tilemap.validate(renderer.get_resources()) {
+        for problem in problems {
+            warn!("Invalid tilemap: {}", problem);
+        }
+    }
+}
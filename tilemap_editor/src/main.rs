@@ -16,11 +16,14 @@ mod data;
 mod message;
 mod preview;
 mod resources;
+mod thumbnails;
 mod tool;
 mod toolbar;
+mod viewport;
 
 pub fn main() -> iced::Result {
-    init_logging();
+    // The arguments are parsed again by the editor, because a Sandbox can't get them passed
+    init_logging(ResourceInfo::from_args().get_log_level());
     TilemapEditor::run(Settings::default())
 }
 
@@ -38,9 +41,14 @@ impl TilemapEditor {
             .data
             .renderer
             .render(&self.data.tilemap, Some(&self.data.furniture_map));
-        save_color_image(&data, "tilemap-color.png");
-        save_depth_image(&data, "tilemap-depth.png");
-        info!("Finished exporting");
+
+        let result = save_color_image(&data, "tilemap-color.png")
+            .and_then(|_| save_depth_image(&data, "tilemap-depth.png"));
+
+        match result {
+            Ok(_) => info!("Finished exporting"),
+            Err(error) => error!("Failed to export the tilemap: {:?}", error),
+        }
     }
 }
 
@@ -68,8 +76,7 @@ impl Sandbox for TilemapEditor {
 
         let trigger_preview = match message {
             EditorMessage::ReloadResources | EditorMessage::PressedKey(KeyCode::R) => {
-                self.data.reload_resources();
-                true
+                self.data.reload_resources()
             }
             EditorMessage::LoadTilemap | EditorMessage::PressedKey(KeyCode::L) => {
                 self.data.load_maps()
@@ -82,6 +89,18 @@ impl Sandbox for TilemapEditor {
                 self.export_tilemap();
                 false
             }
+            EditorMessage::Pan(side) => self.data.pan(side),
+            EditorMessage::ClickedButton { x, y, button } => {
+                let (x, y) = self.data.convert_from_preview(x, y);
+                let message = EditorMessage::ClickedButton { x, y, button };
+                self.tools.update(&mut self.data, message)
+            }
+            EditorMessage::ZoomIn
+            | EditorMessage::PressedKey(KeyCode::Plus)
+            | EditorMessage::PressedKey(KeyCode::NumpadAdd) => self.data.zoom(true),
+            EditorMessage::ZoomOut
+            | EditorMessage::PressedKey(KeyCode::Minus)
+            | EditorMessage::PressedKey(KeyCode::NumpadSubtract) => self.data.zoom(false),
             _ => self.tools.update(&mut self.data, message),
         };
 
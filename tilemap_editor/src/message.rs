@@ -1,6 +1,7 @@
 use iced_native::keyboard::KeyCode;
 use iced_native::mouse::Button;
 use texture_generation::math::side::Side;
+use tilemap::tilemap::furniture::snap::SnapMode;
 
 #[derive(Debug, Clone)]
 pub enum EditorMessage {
@@ -8,6 +9,7 @@ pub enum EditorMessage {
     ChangeFurnitureStyle(String),
     ChangeHeight(u32),
     ChangeSide(Side),
+    ChangeSnapMode(SnapMode),
     ChangeTexture(String),
     ChangeTool(usize),
     ChangeWallStyle(String),
@@ -17,7 +19,10 @@ pub enum EditorMessage {
     ExportTilemap,
     LoadTilemap,
     NewMap,
+    Pan(Side),
     PressedKey(KeyCode),
     ReloadResources,
     SaveTilemap,
+    ZoomIn,
+    ZoomOut,
 }
@@ -1,10 +1,12 @@
 use crate::message::EditorMessage;
+use iced::keyboard::KeyCode;
 use iced_native::layout::{Limits, Node};
 use iced_native::{
     event, image, mouse, Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size,
     Vector, Widget,
 };
 use std::hash::Hash;
+use texture_generation::math::side::Side;
 
 pub struct Preview {
     handle: image::Handle,
@@ -103,12 +105,19 @@ where
         messages: &mut Vec<EditorMessage>,
     ) -> event::Status {
         match event {
-            Event::Keyboard(iced::keyboard::Event::KeyReleased { key_code, .. }) => {
+            Event::Keyboard(iced::keyboard::Event::KeyReleased {
+                key_code,
+                modifiers,
+            }) => {
                 let image = self.get_image_rectangle(renderer, layout.bounds());
 
                 if image.contains(cursor_position) {
                     info!("Released key {:?}", key_code);
-                    messages.push(EditorMessage::PressedKey(key_code))
+
+                    match (modifiers.shift, get_pan_side(key_code)) {
+                        (true, Some(side)) => messages.push(EditorMessage::Pan(side)),
+                        _ => messages.push(EditorMessage::PressedKey(key_code)),
+                    }
                 }
             }
             Event::Mouse(mouse::Event::ButtonPressed(button)) => {
@@ -132,6 +141,17 @@ where
     }
 }
 
+/// The arrow keys pan the preview, while shift is pressed.
+fn get_pan_side(key_code: KeyCode) -> Option<Side> {
+    match key_code {
+        KeyCode::Up => Some(Side::Top),
+        KeyCode::Left => Some(Side::Left),
+        KeyCode::Down => Some(Side::Bottom),
+        KeyCode::Right => Some(Side::Right),
+        _ => None,
+    }
+}
+
 impl<'a, Renderer> From<Preview> for Element<'a, EditorMessage, Renderer>
 where
     Renderer: crate::preview::renderer::Renderer + image::Renderer,
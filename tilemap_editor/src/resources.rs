@@ -1,18 +1,27 @@
+use crate::thumbnails::{Thumbnails, THUMBNAIL_SIZE};
 use anyhow::Result;
+use log::LevelFilter;
 use std::path::{Path, PathBuf};
 use structopt::StructOpt;
 use texture_generation::definition::read;
 use texture_generation::generation::process::ambient_occlusion::AmbientOcclusion;
-use texture_generation::generation::process::lighting::Lighting;
+use texture_generation::generation::process::lighting::{Light, Lighting, SpecularParams};
 use texture_generation::generation::process::PostProcess;
 use texture_generation::math::vector3::Vector3;
+use texture_generation::utils::logging::get_log_level;
 use tilemap::rendering::Renderer;
 use tilemap::tilemap::furniture::map2d::FurnitureMap2d;
 use tilemap::tilemap::tilemap2d::Tilemap2d;
-use tilemap_io::rendering::resource::lookup::ResourceLookup;
+use tilemap_io::rendering::resource::lookup::{ResourceCache, ResourceLookup};
+use tilemap_io::rendering::resource::ResourceDefinitions;
 use tilemap_io::tilemap::furniture::map2d::{load_furniture_map, FURNITURE_MAP_FILE_ENDING};
 use tilemap_io::tilemap::{load_tilemap, TILEMAP_FILE_ENDING};
 
+/// The smallest tile size of the preview, when zooming out.
+const MIN_PREVIEW_TILE_SIZE: u32 = 16;
+/// The biggest tile size of the preview, when zooming in.
+const MAX_PREVIEW_TILE_SIZE: u32 = 512;
+
 #[derive(StructOpt)]
 #[structopt(name = "texture_generator")]
 pub struct ResourceInfo {
@@ -39,15 +48,72 @@ pub struct ResourceInfo {
     /// The starting height for wall tiles.
     #[structopt(default_value = "200")]
     wall_height: u8,
+
+    /// Logs more details. Repeat it for even more details.
+    #[structopt(short, long, parse(from_occurrences))]
+    verbose: u8,
+
+    /// Only logs errors.
+    #[structopt(short, long)]
+    quiet: bool,
+
+    #[structopt(skip)]
+    cache: ResourceCache,
 }
 
 impl ResourceInfo {
+    pub fn get_log_level(&self) -> LevelFilter {
+        get_log_level(self.verbose, self.quiet)
+    }
+
     pub fn get_map_path(&self) -> &Path {
         &self.map_path
     }
 
-    /// Loads the needed [`Resource`]s and creates a normal & a preview [`Renderer`].
-    pub fn create_renderers(&self) -> (Renderer, Renderer) {
+    /// Doubles or halves the tile size of the preview. Returns true, if it changed.
+    pub fn zoom(&mut self, zoom_in: bool) -> bool {
+        let tile_size = calculate_zoomed_tile_size(self.preview_tile_size, zoom_in);
+
+        if tile_size == self.preview_tile_size {
+            info!("Preview tile size {} is at its limit", tile_size);
+            return false;
+        }
+
+        info!(
+            "Change preview tile size from {} to {}",
+            self.preview_tile_size, tile_size
+        );
+        self.preview_tile_size = tile_size;
+        true
+    }
+
+    /// Loads the needed [`Resource`]s and creates a normal & a preview [`Renderer`] and the [`Thumbnails`].
+    pub fn create_renderers(&mut self) -> (Renderer, Renderer, Thumbnails) {
+        let (definitions, _) = self.load_definitions();
+        self.create_renderers_from(&definitions)
+    }
+
+    /// Creates new [`Renderer`]s like [`ResourceInfo::create_renderers()`], but only if a definition changed.
+    pub fn reload_renderers(&mut self) -> Option<(Renderer, Renderer, Thumbnails)> {
+        let (definitions, changed) = self.load_definitions();
+
+        if changed.is_empty() {
+            info!("No definition changed");
+            return None;
+        }
+
+        info!("Changed definitions: {:?}", changed);
+
+        Some(self.create_renderers_from(&definitions))
+    }
+
+    /// Loads the needed [`Resource`]s and only creates the preview [`Renderer`].
+    pub fn create_preview_renderer(&mut self) -> Renderer {
+        let (definitions, _) = self.load_definitions();
+        self.create_preview_renderer_from(&definitions)
+    }
+
+    fn load_definitions(&mut self) -> (ResourceDefinitions, Vec<String>) {
         info!("Load lookup from {:?}", self.lookup_path);
 
         let lookup = match read(&self.lookup_path) {
@@ -58,8 +124,13 @@ impl ResourceInfo {
             }
         };
 
-        let definitions = lookup.convert(&self.resource_path);
+        lookup.convert_changed(&self.resource_path, &mut self.cache)
+    }
 
+    fn create_renderers_from(
+        &self,
+        definitions: &ResourceDefinitions,
+    ) -> (Renderer, Renderer, Thumbnails) {
         info!(
             "Init renderer: tile_size={} wall_height={}",
             self.render_tile_size, self.wall_height
@@ -69,26 +140,45 @@ impl ResourceInfo {
         let resources = definitions.convert(
             vec![
                 PostProcess::AmbientOcclusion(ambient_occlusion),
-                PostProcess::Lighting(Lighting::new(Vector3::new(1.0, 0.0, 2.0), 10, 32)),
+                PostProcess::Lighting(Lighting::new(
+                    vec![Light::white(Vector3::new(1.0, 0.0, 2.0))],
+                    10,
+                    0.1,
+                    Some(SpecularParams::new(32.0, 1.0)),
+                )),
             ],
             self.render_tile_size,
         );
         let renderer =
             tilemap::rendering::Renderer::new(self.render_tile_size, self.wall_height, resources);
 
+        (
+            renderer,
+            self.create_preview_renderer_from(definitions),
+            self.create_thumbnails_from(definitions),
+        )
+    }
+
+    fn create_preview_renderer_from(&self, definitions: &ResourceDefinitions) -> Renderer {
         info!(
             "Init preview renderer: tile_size={}",
             self.preview_tile_size
         );
 
         let preview_resources = definitions.convert(Vec::default(), self.preview_tile_size);
-        let preview_renderer = tilemap::rendering::Renderer::new(
+        tilemap::rendering::Renderer::new(
             self.preview_tile_size,
             self.wall_height,
             preview_resources,
-        );
+        )
+    }
+
+    fn create_thumbnails_from(&self, definitions: &ResourceDefinitions) -> Thumbnails {
+        let tile_size = THUMBNAIL_SIZE / 2;
+        let resources = definitions.convert(Vec::default(), tile_size);
+        let renderer = tilemap::rendering::Renderer::new(tile_size, self.wall_height, resources);
 
-        (renderer, preview_renderer)
+        Thumbnails::new(&renderer)
     }
 
     pub fn load_maps(&self) -> Result<(Tilemap2d, FurnitureMap2d)> {
@@ -106,3 +196,45 @@ impl ResourceInfo {
         Ok((tilemap, furniture_map))
     }
 }
+
+/// Doubles or halves the tile size, but keeps it between the min & max tile size of the preview.
+fn calculate_zoomed_tile_size(tile_size: u32, zoom_in: bool) -> u32 {
+    let tile_size = if zoom_in {
+        tile_size.saturating_mul(2)
+    } else {
+        tile_size / 2
+    };
+
+    tile_size
+        .max(MIN_PREVIEW_TILE_SIZE)
+        .min(MAX_PREVIEW_TILE_SIZE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zoom_in() {
+        assert_eq!(calculate_zoomed_tile_size(128, true), 256);
+        assert_eq!(calculate_zoomed_tile_size(256, true), 512);
+    }
+
+    #[test]
+    fn test_zoom_in_clamps_at_max() {
+        assert_eq!(calculate_zoomed_tile_size(300, true), MAX_PREVIEW_TILE_SIZE);
+        assert_eq!(calculate_zoomed_tile_size(512, true), MAX_PREVIEW_TILE_SIZE);
+    }
+
+    #[test]
+    fn test_zoom_out() {
+        assert_eq!(calculate_zoomed_tile_size(128, false), 64);
+        assert_eq!(calculate_zoomed_tile_size(32, false), 16);
+    }
+
+    #[test]
+    fn test_zoom_out_clamps_at_min() {
+        assert_eq!(calculate_zoomed_tile_size(20, false), MIN_PREVIEW_TILE_SIZE);
+        assert_eq!(calculate_zoomed_tile_size(16, false), MIN_PREVIEW_TILE_SIZE);
+    }
+}
@@ -0,0 +1,46 @@
+use iced_native::image;
+use texture_generation::math::color::convert_bgra;
+use tilemap::rendering::thumbnail::Thumbnail;
+use tilemap::rendering::Renderer;
+
+/// The width & height of a thumbnail in pixels.
+pub const THUMBNAIL_SIZE: u32 = 64;
+
+/// Caches a small preview of each texture, wall, door & window style for the sidebars.
+#[derive(Default)]
+pub struct Thumbnails {
+    pub textures: Vec<image::Handle>,
+    pub wall_styles: Vec<image::Handle>,
+    pub door_styles: Vec<image::Handle>,
+    pub window_styles: Vec<image::Handle>,
+}
+
+impl Thumbnails {
+    /// Renders the thumbnails with a [`Renderer`], whose tile size is half of [`THUMBNAIL_SIZE`].
+    pub fn new(renderer: &Renderer) -> Self {
+        info!("Render thumbnails");
+
+        let resources = renderer.get_resources();
+
+        Thumbnails {
+            textures: render(renderer, resources.textures.len(), Thumbnail::Texture),
+            wall_styles: render(renderer, resources.wall_styles.len(), Thumbnail::Wall),
+            door_styles: render(renderer, resources.door_styles.len(), Thumbnail::Door),
+            window_styles: render(renderer, resources.window_styles.len(), Thumbnail::Window),
+        }
+    }
+}
+
+fn render(renderer: &Renderer, len: usize, create: fn(usize) -> Thumbnail) -> Vec<image::Handle> {
+    (0..len)
+        .map(|id| {
+            let texture = renderer.render_thumbnail(create(id));
+            let size = texture.get_size();
+            image::Handle::from_pixels(
+                size.width(),
+                size.height(),
+                convert_bgra(texture.get_color_data()),
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,90 @@
+use crate::data::EditorData;
+use crate::message::EditorMessage;
+use crate::tool::eyedropper::pick_texture;
+use crate::tool::{create_pick_list, cycle_resource, help, title, Tool};
+use iced::mouse::Button;
+use iced::{pick_list, Column, Text};
+use texture_generation::math::point::Point;
+use tilemap::tilemap::selector::Selector;
+use tilemap::tilemap::tile::Tile;
+use tilemap::tilemap::tilemap2d::Tilemap2d;
+
+/// Fills all connected tiles of the same type like a paint bucket.
+#[derive(Clone, Debug, Default)]
+pub struct BucketTool {
+    texture_id: usize,
+    texture_state: pick_list::State<String>,
+}
+
+impl Tool for BucketTool {
+    fn get_name(&self) -> &str {
+        "Bucket"
+    }
+
+    fn update(&mut self, data: &mut EditorData, message: EditorMessage) -> bool {
+        match message {
+            EditorMessage::ChangeTexture(name) => {
+                if let Some(id) = data.renderer.get_resources().textures.get_id(&name) {
+                    info!("BucketTool: Change texture to '{}' with id {}", &name, id);
+                    self.texture_id = id;
+                }
+            }
+            EditorMessage::PressedKey(key_code) => {
+                if let Some(name) = cycle_resource(
+                    &data.renderer.get_resources().textures,
+                    self.texture_id,
+                    key_code,
+                ) {
+                    return self.update(data, EditorMessage::ChangeTexture(name));
+                }
+            }
+            EditorMessage::ClickedButton { x, y, button } => {
+                let point = Point::new(x as i32, y as i32);
+
+                if let Some(index) = data.selector.get_tile_index(&data.tilemap, point) {
+                    let tile = match button {
+                        Button::Left => Tile::Floor(self.texture_id),
+                        Button::Right => Tile::Empty,
+                        Button::Middle => Tile::Solid(self.texture_id),
+                        _ => return false,
+                    };
+
+                    return data.tilemap.fill_region(index, tile) > 0;
+                }
+            }
+            _ => {}
+        }
+
+        false
+    }
+
+    fn pick(&mut self, tilemap: &Tilemap2d, selector: &Selector, point: Point) -> bool {
+        if let Some(id) = pick_texture(tilemap, selector, point) {
+            info!("BucketTool: Pick texture with id {}", id);
+            self.texture_id = id;
+            return true;
+        }
+
+        false
+    }
+
+    fn view_sidebar(&mut self, data: &EditorData) -> Column<EditorMessage> {
+        let pick_list = create_pick_list(
+            &data.renderer.get_resources().textures,
+            &mut self.texture_state,
+            self.texture_id,
+            EditorMessage::ChangeTexture,
+        );
+
+        Column::new()
+            .push(Text::new("Tile Texture"))
+            .push(pick_list)
+            .push(title("Help"))
+            .push(help("Click left to fill the region with floor tiles"))
+            .push(help(
+                "Click middle button to fill the region with full tiles, which block movement",
+            ))
+            .push(help("Click right to remove the tiles of the region"))
+            .push(help("'[' & ']' to cycle the texture"))
+    }
+}
@@ -1,10 +1,13 @@
 use crate::data::EditorData;
 use crate::message::EditorMessage;
-use crate::tool::{create_pick_list, help, title, Tool};
+use crate::tool::eyedropper::pick_border;
+use crate::tool::{create_pick_list, create_thumbnail_buttons, cycle_resource, help, title, Tool};
 use iced::mouse::Button;
-use iced::{pick_list, Column, Text};
+use iced::{button, pick_list, Column, Text};
 use texture_generation::math::point::Point;
 use tilemap::tilemap::border::Border;
+use tilemap::tilemap::selector::Selector;
+use tilemap::tilemap::tilemap2d::Tilemap2d;
 
 #[derive(Clone, Debug, Default)]
 pub struct DoorTool {
@@ -12,6 +15,7 @@ pub struct DoorTool {
     door_id: usize,
     wall_state: pick_list::State<String>,
     door_state: pick_list::State<String>,
+    thumbnail_states: Vec<button::State>,
 }
 
 impl Tool for DoorTool {
@@ -33,30 +37,37 @@ impl Tool for DoorTool {
                     self.door_id = id;
                 }
             }
+            EditorMessage::PressedKey(key_code) => {
+                if let Some(name) = cycle_resource(
+                    &data.renderer.get_resources().door_styles,
+                    self.door_id,
+                    key_code,
+                ) {
+                    return self.update(data, EditorMessage::ChangeDoorStyle(name));
+                }
+            }
             EditorMessage::ClickedButton { x, y, button } => {
                 let point = Point::new(x as i32, y as i32);
 
-                if let Some(index) = data.selector.get_tile_index(&data.tilemap, point) {
-                    if let Some(side) = data.selector.get_side(&data.tilemap, point, index) {
-                        let old_border = data.tilemap.get_border(index, side);
-
-                        let border = match button {
-                            Button::Left => match old_border {
-                                Border::Door { .. } => old_border.switch_is_front(),
-                                _ => Border::new_door(self.wall_id, self.door_id, true),
-                            },
-                            _ => old_border.reduce(),
-                        };
+                if let Some((index, side)) = data.selector.get_border(&data.tilemap, point) {
+                    let old_border = data.tilemap.get_border(index, side);
 
-                        if old_border != border {
-                            info!("Set {:?} border of tile {} to {:?}", side, index, border);
+                    let border = match button {
+                        Button::Left => match old_border {
+                            Border::Door { .. } => old_border.switch_is_front(),
+                            _ => Border::new_door(self.wall_id, self.door_id, true),
+                        },
+                        _ => old_border.reduce(),
+                    };
 
-                            data.tilemap.set_border(index, side, border);
-                            return true;
-                        }
+                    if old_border != border {
+                        info!("Set {:?} border of tile {} to {:?}", side, index, border);
 
-                        return false;
+                        data.tilemap.set_border(index, side, border);
+                        return true;
                     }
+
+                    return false;
                 }
             }
             _ => {}
@@ -65,6 +76,23 @@ impl Tool for DoorTool {
         false
     }
 
+    fn pick(&mut self, tilemap: &Tilemap2d, selector: &Selector, point: Point) -> bool {
+        match pick_border(tilemap, selector, point) {
+            Some(Border::Door {
+                wall_id, door_id, ..
+            }) => {
+                info!(
+                    "DoorTool: Pick wall style {} & door style {}",
+                    wall_id, door_id
+                );
+                self.wall_id = wall_id;
+                self.door_id = door_id;
+                true
+            }
+            _ => false,
+        }
+    }
+
     fn view_sidebar(&mut self, data: &EditorData) -> Column<EditorMessage> {
         let wall_pick_list = create_pick_list(
             &data.renderer.get_resources().wall_styles,
@@ -78,15 +106,23 @@ impl Tool for DoorTool {
             self.door_id,
             EditorMessage::ChangeDoorStyle,
         );
+        let thumbnails = create_thumbnail_buttons(
+            &data.renderer.get_resources().door_styles,
+            &data.thumbnails.door_styles,
+            &mut self.thumbnail_states,
+            EditorMessage::ChangeDoorStyle,
+        );
 
         Column::new()
             .push(Text::new("Wall Style"))
             .push(wall_pick_list)
             .push(Text::new("Door Style"))
             .push(door_pick_list)
+            .push(thumbnails)
             .push(title("Help"))
             .push(help("Click right to add"))
             .push(help("Click right on a door to change its side"))
             .push(help("Click left to remove"))
+            .push(help("'[' & ']' to cycle the door style"))
     }
 }
@@ -0,0 +1,80 @@
+use crate::data::EditorData;
+use crate::message::EditorMessage;
+use crate::tool::{help, title, Tool};
+use iced::Column;
+use texture_generation::math::point::Point;
+use tilemap::tilemap::border::Border;
+use tilemap::tilemap::selector::Selector;
+use tilemap::tilemap::tile::Tile;
+use tilemap::tilemap::tilemap2d::Tilemap2d;
+
+/// Copies the style under the cursor into the previous tool, which is done by [`Tools`](crate::tool::tools::Tools).
+#[derive(Clone, Debug, Default)]
+pub struct EyedropperTool {}
+
+impl Tool for EyedropperTool {
+    fn get_name(&self) -> &str {
+        "Eyedropper"
+    }
+
+    fn update(&mut self, _data: &mut EditorData, _message: EditorMessage) -> bool {
+        false
+    }
+
+    fn view_sidebar(&mut self, _data: &EditorData) -> Column<EditorMessage> {
+        Column::new().push(title("Help")).push(help(
+            "Click on a tile or border to select its style in the previous tool",
+        ))
+    }
+}
+
+/// Returns the texture of the [`Tile`] at the [`Point`], if it has one.
+pub fn pick_texture(tilemap: &Tilemap2d, selector: &Selector, point: Point) -> Option<usize> {
+    let index = selector.get_tile_index(tilemap, point)?;
+
+    match tilemap.get_tile(index) {
+        Tile::Empty => None,
+        Tile::Floor(id) => Some(id),
+        Tile::Solid(id) => Some(id),
+        Tile::Ramp { id, .. } => Some(id),
+    }
+}
+
+/// Returns the [`Border`] at the [`Point`], if it isn't empty.
+pub fn pick_border(tilemap: &Tilemap2d, selector: &Selector, point: Point) -> Option<Border> {
+    let (index, side) = selector.get_border(tilemap, point)?;
+
+    match tilemap.get_border(index, side) {
+        Border::Empty => None,
+        border => Some(border),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use texture_generation::math::side::Side::*;
+    use texture_generation::math::size::Size;
+
+    #[test]
+    fn test_pick_empty_tile() {
+        let selector = Selector::new(100);
+        let tilemap = Tilemap2d::default(Size::new(2, 1), Tile::Empty);
+
+        assert_eq!(pick_texture(&tilemap, &selector, Point::new(50, 50)), None);
+    }
+
+    #[test]
+    fn test_pick_border() {
+        let selector = Selector::new(100);
+        let mut tilemap = Tilemap2d::default(Size::new(2, 1), Tile::Empty);
+        let window = Border::new_window(2, 5);
+        tilemap.set_border(0, Right, window);
+
+        assert_eq!(
+            pick_border(&tilemap, &selector, Point::new(102, 50)),
+            Some(window)
+        );
+        assert_eq!(pick_border(&tilemap, &selector, Point::new(50, 2)), None);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::data::EditorData;
 use crate::message::EditorMessage;
-use crate::tool::{create_pick_list, help, title, Tool};
+use crate::tool::{create_pick_list, cycle_resource, help, title, Tool};
 use iced::keyboard::KeyCode;
 use iced::mouse::Button;
 use iced::{pick_list, slider, Column, PickList, Slider, Text};
@@ -9,6 +9,7 @@ use texture_generation::math::aabb::AABB;
 use texture_generation::math::point::Point;
 use texture_generation::math::side::Side;
 use texture_generation::math::size::Size;
+use tilemap::tilemap::furniture::snap::SnapMode;
 use tilemap::tilemap::furniture::Furniture;
 
 #[derive(Clone, Debug)]
@@ -23,6 +24,8 @@ pub struct FurnitureTool {
     max_size: u32,
     front: Side,
     front_state: pick_list::State<Side>,
+    snap: SnapMode,
+    snap_state: pick_list::State<SnapMode>,
 }
 
 impl FurnitureTool {
@@ -38,6 +41,8 @@ impl FurnitureTool {
             max_size,
             front: Side::Bottom,
             front_state: Default::default(),
+            snap: SnapMode::None,
+            snap_state: Default::default(),
         }
     }
 
@@ -119,6 +124,19 @@ impl FurnitureTool {
         false
     }
 
+    fn update_snap(&mut self, snap: SnapMode) -> bool {
+        info!("FurnitureTool: Change snap mode to {}", snap);
+        self.snap = snap;
+        false
+    }
+
+    /// Returns the style of the selected furniture or the one for new furniture.
+    fn get_style_id(&self, data: &EditorData) -> usize {
+        self.selected_id
+            .and_then(|id| data.furniture_map.get_furniture(id))
+            .map_or(self.style_id, |furniture| furniture.style_id)
+    }
+
     fn update_style(&mut self, data: &mut EditorData, style: String) -> bool {
         if let Some(style_id) = data
             .renderer
@@ -161,14 +179,23 @@ impl Tool for FurnitureTool {
             EditorMessage::ChangeHeight(height) => self.update_height(data, height),
             EditorMessage::ChangeSide(front) => self.update_front(data, front),
             EditorMessage::ChangeFurnitureStyle(name) => self.update_style(data, name),
+            EditorMessage::ChangeSnapMode(snap) => self.update_snap(snap),
             EditorMessage::ClickedButton { x, y, button } => {
                 let point = Point::new(x as i32, y as i32);
 
-                if let Some(index) = data
+                let cell_size = data
+                    .furniture_map
+                    .convert_from_tile_size(data.selector.get_tile_size());
+
+                if data
                     .selector
                     .get_furniture_tile_index(&data.furniture_map, point)
+                    .is_some()
                 {
-                    if let Some(id) = data.furniture_map.get_id_at(index) {
+                    if let Some(id) = data
+                        .furniture_map
+                        .get_furniture_at(point, Size::square(cell_size))
+                    {
                         return match button {
                             Button::Left => {
                                 self.selected_id = Some(id);
@@ -181,20 +208,23 @@ impl Tool for FurnitureTool {
                             }
                         };
                     } else {
+                        let start = data
+                            .furniture_map
+                            .get_snapped_cell(point, cell_size, self.snap);
                         let furniture = Furniture::new(
                             self.style_id,
-                            data.furniture_map.get_size().to_point(index),
+                            start,
                             Size::new(self.width, self.height),
                             self.front,
                         )
                         .unwrap();
 
-                        if let Some(id) = data.furniture_map.add(furniture) {
+                        if let Some(id) = data.furniture_map.add_without_overlap(furniture) {
                             info!("FurnitureTool: Add furniture with id {}", id);
                             self.selected_id = Some(id);
                             return true;
                         } else {
-                            warn!("FurnitureTool: Failed to add furniture, because its partly outside the map!");
+                            warn!("FurnitureTool: Failed to add furniture, because its partly outside the map or overlaps other furniture!");
                         }
                     }
                 }
@@ -204,6 +234,14 @@ impl Tool for FurnitureTool {
             EditorMessage::PressedKey(KeyCode::Left) => self.move_furniture(data, -1, 0),
             EditorMessage::PressedKey(KeyCode::Down) => self.move_furniture(data, 0, 1),
             EditorMessage::PressedKey(KeyCode::Right) => self.move_furniture(data, 1, 0),
+            EditorMessage::PressedKey(key_code) => match cycle_resource(
+                &data.renderer.get_resources().furniture_styles,
+                self.get_style_id(data),
+                key_code,
+            ) {
+                Some(name) => self.update_style(data, name),
+                None => false,
+            },
             _ => false,
         }
     }
@@ -253,6 +291,14 @@ impl Tool for FurnitureTool {
             EditorMessage::ChangeSide,
         );
 
+        let snap_options: Vec<SnapMode> = SnapMode::iterator().copied().collect();
+        let snap_pick_list = PickList::new(
+            &mut self.snap_state,
+            snap_options,
+            Some(self.snap),
+            EditorMessage::ChangeSnapMode,
+        );
+
         let mut column = Column::new().push(title("Control"));
 
         if let Some(id) = self.selected_id {
@@ -268,9 +314,12 @@ impl Tool for FurnitureTool {
             .push(height_slider)
             .push(Text::new("Front"))
             .push(side_pick_list)
+            .push(Text::new("Snap to"))
+            .push(snap_pick_list)
             .push(title("Help"))
             .push(help("Click right to add or select"))
             .push(help("Click left to delete"))
             .push(help("Arrow keys to move selected furniture"))
+            .push(help("'[' & ']' to cycle the style"))
     }
 }
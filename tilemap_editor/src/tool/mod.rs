@@ -1,9 +1,19 @@
 use crate::data::EditorData;
 use crate::message::EditorMessage;
-use iced::{pick_list, Column, HorizontalAlignment, Length, PickList, Row, Text};
+use crate::thumbnails::THUMBNAIL_SIZE;
+use iced::keyboard::KeyCode;
+use iced::{
+    button, image, pick_list, Button, Column, HorizontalAlignment, Image, Length, PickList, Row,
+    Text,
+};
+use texture_generation::math::point::Point;
 use texture_generation::utils::resource::{Resource, ResourceManager};
+use tilemap::tilemap::selector::Selector;
+use tilemap::tilemap::tilemap2d::Tilemap2d;
 
+pub mod bucket;
 pub mod door;
+pub mod eyedropper;
 pub mod furniture;
 pub mod map;
 pub mod tile;
@@ -16,6 +26,11 @@ pub trait Tool {
 
     fn update(&mut self, data: &mut EditorData, message: EditorMessage) -> bool;
 
+    /// Selects the style of the tile or border at the [`Point`]. Returns true, if it picked anything.
+    fn pick(&mut self, _tilemap: &Tilemap2d, _selector: &Selector, _point: Point) -> bool {
+        false
+    }
+
     fn view_sidebar(&mut self, data: &EditorData) -> Column<EditorMessage>;
 }
 
@@ -34,6 +49,70 @@ fn create_pick_list<'a, T: Resource>(
     PickList::new(state, names, Some(selected_name.to_string()), on_selected)
 }
 
+/// The number of thumbnails in each row of a sidebar.
+const THUMBNAILS_PER_ROW: usize = 4;
+
+/// Creates a button with the thumbnail of each resource, which selects it.
+fn create_thumbnail_buttons<'a, T: Resource>(
+    resource_manager: &ResourceManager<T>,
+    thumbnails: &[image::Handle],
+    states: &'a mut Vec<button::State>,
+    on_selected: fn(String) -> EditorMessage,
+) -> Column<'a, EditorMessage> {
+    states.resize_with(thumbnails.len(), button::State::default);
+    let mut column = Column::new();
+    let mut row = Row::new();
+
+    for (id, (thumbnail, state)) in thumbnails.iter().zip(states.iter_mut()).enumerate() {
+        let name = resource_manager.get(id).get_name().to_string();
+        let image = Image::new(thumbnail.clone())
+            .width(Length::Units(THUMBNAIL_SIZE as u16))
+            .height(Length::Units(THUMBNAIL_SIZE as u16));
+        row = row.push(Button::new(state, image).on_press(on_selected(name)));
+
+        if (id + 1) % THUMBNAILS_PER_ROW == 0 {
+            column = column.push(row);
+            row = Row::new();
+        }
+    }
+
+    if thumbnails.len() % THUMBNAILS_PER_ROW != 0 {
+        column = column.push(row);
+    }
+
+    column
+}
+
+/// Returns the name of the previous resource for '[' or the next one for ']', while wrapping at both ends.
+fn cycle_resource<T: Resource>(
+    resource_manager: &ResourceManager<T>,
+    id: usize,
+    key_code: KeyCode,
+) -> Option<String> {
+    let forward = match key_code {
+        KeyCode::LBracket => false,
+        KeyCode::RBracket => true,
+        _ => return None,
+    };
+
+    cycle_id(id, resource_manager.len(), forward)
+        .map(|id| resource_manager.get(id).get_name().to_string())
+}
+
+fn cycle_id(id: usize, len: usize, forward: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    let id = id.min(len - 1);
+
+    Some(if forward {
+        (id + 1) % len
+    } else {
+        (id + len - 1) % len
+    })
+}
+
 pub fn title(label: &str) -> Text {
     Text::new(label)
         .width(Length::Fill)
@@ -43,3 +122,31 @@ pub fn title(label: &str) -> Text {
 pub fn help(label: &str) -> Row<EditorMessage> {
     Row::new().push(Text::new("+")).push(Text::new(label))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_forward() {
+        assert_eq!(cycle_id(0, 3, true), Some(1));
+        assert_eq!(cycle_id(1, 3, true), Some(2));
+    }
+
+    #[test]
+    fn test_cycle_forward_past_last_wraps_to_first() {
+        assert_eq!(cycle_id(2, 3, true), Some(0));
+    }
+
+    #[test]
+    fn test_cycle_backward_past_first_wraps_to_last() {
+        assert_eq!(cycle_id(0, 3, false), Some(2));
+        assert_eq!(cycle_id(2, 3, false), Some(1));
+    }
+
+    #[test]
+    fn test_cycle_without_resources() {
+        assert_eq!(cycle_id(0, 0, true), None);
+        assert_eq!(cycle_id(0, 0, false), None);
+    }
+}
@@ -1,15 +1,19 @@
 use crate::data::EditorData;
 use crate::message::EditorMessage;
-use crate::tool::{create_pick_list, help, title, Tool};
+use crate::tool::eyedropper::pick_texture;
+use crate::tool::{create_pick_list, create_thumbnail_buttons, cycle_resource, help, title, Tool};
 use iced::mouse::Button;
-use iced::{pick_list, Column, Text};
+use iced::{button, pick_list, Column, Text};
 use texture_generation::math::point::Point;
+use tilemap::tilemap::selector::Selector;
 use tilemap::tilemap::tile::Tile;
+use tilemap::tilemap::tilemap2d::Tilemap2d;
 
 #[derive(Clone, Debug, Default)]
 pub struct TileTool {
     texture_id: usize,
     texture_state: pick_list::State<String>,
+    thumbnail_states: Vec<button::State>,
 }
 
 impl Tool for TileTool {
@@ -25,6 +29,15 @@ impl Tool for TileTool {
                     self.texture_id = id;
                 }
             }
+            EditorMessage::PressedKey(key_code) => {
+                if let Some(name) = cycle_resource(
+                    &data.renderer.get_resources().textures,
+                    self.texture_id,
+                    key_code,
+                ) {
+                    return self.update(data, EditorMessage::ChangeTexture(name));
+                }
+            }
             EditorMessage::ClickedButton { x, y, button } => {
                 let point = Point::new(x as i32, y as i32);
 
@@ -48,6 +61,16 @@ impl Tool for TileTool {
         false
     }
 
+    fn pick(&mut self, tilemap: &Tilemap2d, selector: &Selector, point: Point) -> bool {
+        if let Some(id) = pick_texture(tilemap, selector, point) {
+            info!("TileTool: Pick texture with id {}", id);
+            self.texture_id = id;
+            return true;
+        }
+
+        false
+    }
+
     fn view_sidebar(&mut self, data: &EditorData) -> Column<EditorMessage> {
         let pick_list = create_pick_list(
             &data.renderer.get_resources().textures,
@@ -55,15 +78,51 @@ impl Tool for TileTool {
             self.texture_id,
             EditorMessage::ChangeTexture,
         );
+        let thumbnails = create_thumbnail_buttons(
+            &data.renderer.get_resources().textures,
+            &data.thumbnails.textures,
+            &mut self.thumbnail_states,
+            EditorMessage::ChangeTexture,
+        );
 
         Column::new()
             .push(Text::new("Tile Texture"))
             .push(pick_list)
+            .push(thumbnails)
             .push(title("Help"))
             .push(help("Click right to paint a floor tile"))
             .push(help(
                 "Click middle button to paint a full tile, which blocks movement",
             ))
             .push(help("Click left to remove a tile"))
+            .push(help("'[' & ']' to cycle the texture"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use texture_generation::math::size::Size;
+
+    #[test]
+    fn test_pick_floor() {
+        let selector = Selector::new(100);
+        let mut tilemap = Tilemap2d::default(Size::new(2, 1), Tile::Empty);
+        tilemap.set_tile(1, Tile::Floor(3));
+        let mut tool = TileTool::default();
+
+        assert!(tool.pick(&tilemap, &selector, Point::new(150, 50)));
+        assert_eq!(tool.texture_id, 3);
+    }
+
+    #[test]
+    fn test_pick_empty_tile_keeps_texture() {
+        let selector = Selector::new(100);
+        let tilemap = Tilemap2d::default(Size::new(2, 1), Tile::Empty);
+        let mut tool = TileTool::default();
+        tool.texture_id = 7;
+
+        assert!(!tool.pick(&tilemap, &selector, Point::new(50, 50)));
+        assert_eq!(tool.texture_id, 7);
     }
 }
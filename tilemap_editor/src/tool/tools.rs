@@ -1,6 +1,8 @@
 use crate::data::EditorData;
 use crate::message::EditorMessage;
+use crate::tool::bucket::BucketTool;
 use crate::tool::door::DoorTool;
+use crate::tool::eyedropper::EyedropperTool;
 use crate::tool::furniture::FurnitureTool;
 use crate::tool::map::file::FileTool;
 use crate::tool::map::new::NewMapTool;
@@ -9,25 +11,39 @@ use crate::tool::wall::WallTool;
 use crate::tool::window::WindowTool;
 use crate::tool::Tool;
 use iced::Element;
+use texture_generation::math::point::Point;
 
 pub struct Tools {
     tools: Vec<Box<dyn Tool>>,
     current_tool: usize,
+    /// The tool, which gets the style picked by the [`EyedropperTool`].
+    previous_tool: usize,
+    eyedropper: usize,
 }
 
 impl Tools {
     pub fn new() -> Self {
+        let tools: Vec<Box<dyn Tool>> = vec![
+            Box::new(TileTool::default()),
+            Box::new(BucketTool::default()),
+            Box::new(WallTool::default()),
+            Box::new(DoorTool::default()),
+            Box::new(WindowTool::default()),
+            Box::new(FurnitureTool::new(1, 1, 10)),
+            Box::new(EyedropperTool::default()),
+            Box::new(NewMapTool::new(10, 10, 20)),
+            Box::new(FileTool::default()),
+        ];
+        let eyedropper = tools
+            .iter()
+            .position(|tool| tool.get_name() == EyedropperTool::default().get_name())
+            .unwrap();
+
         Tools {
-            tools: vec![
-                Box::new(TileTool::default()),
-                Box::new(WallTool::default()),
-                Box::new(DoorTool::default()),
-                Box::new(WindowTool::default()),
-                Box::new(FurnitureTool::new(1, 1, 10)),
-                Box::new(NewMapTool::new(10, 10, 20)),
-                Box::new(FileTool::default()),
-            ],
+            tools,
             current_tool: 0,
+            previous_tool: 0,
+            eyedropper,
         }
     }
 
@@ -41,7 +57,19 @@ impl Tools {
 
     pub fn update(&mut self, data: &mut EditorData, message: EditorMessage) -> bool {
         match message {
-            EditorMessage::ChangeTool(id) => self.current_tool = id,
+            EditorMessage::ChangeTool(id) => {
+                if id != self.current_tool {
+                    self.previous_tool = self.current_tool;
+                }
+                self.current_tool = id
+            }
+            EditorMessage::ClickedButton { x, y, .. } if self.current_tool == self.eyedropper => {
+                let point = Point::new(x as i32, y as i32);
+
+                if self.tools[self.previous_tool].pick(&data.tilemap, &data.selector, point) {
+                    self.current_tool = self.previous_tool;
+                }
+            }
             _ => return self.tools[self.current_tool].update(data, message),
         }
 
@@ -1,15 +1,49 @@
 use crate::data::EditorData;
 use crate::message::EditorMessage;
-use crate::tool::{create_pick_list, help, title, Tool};
+use crate::tool::eyedropper::pick_border;
+use crate::tool::{create_pick_list, create_thumbnail_buttons, cycle_resource, help, title, Tool};
 use iced::mouse::Button;
-use iced::{pick_list, Column, Text};
+use iced::{button, pick_list, Column, Text};
+use texture_generation::math::aabb::AABB;
 use texture_generation::math::point::Point;
+use texture_generation::math::size::Size;
 use tilemap::tilemap::border::Border;
+use tilemap::tilemap::selector::Selector;
+use tilemap::tilemap::tilemap2d::Tilemap2d;
 
 #[derive(Clone, Debug, Default)]
 pub struct WallTool {
     wall_id: usize,
+    /// The first corner of a wall rectangle.
+    rectangle_start: Option<Point>,
     pick_list_state: pick_list::State<String>,
+    thumbnail_states: Vec<button::State>,
+}
+
+impl WallTool {
+    /// The first click selects a corner tile & the second one adds the walls around the rectangle.
+    fn click_rectangle(&mut self, data: &mut EditorData, index: usize) -> bool {
+        let point = match data.tilemap.tile_xy(index) {
+            Some((x, y)) => Point::new(x as i32, y as i32),
+            None => return false,
+        };
+
+        match self.rectangle_start.take() {
+            None => {
+                info!("WallTool: Start wall rectangle at {:?}", point);
+                self.rectangle_start = Some(point);
+                false
+            }
+            Some(start) => {
+                let min = start.min(&point);
+                let max = start.max(&point);
+                let size = Size::new((max.x - min.x + 1) as u32, (max.y - min.y + 1) as u32);
+                data.tilemap
+                    .add_walls_around(AABB::new(min, size), self.wall_id);
+                true
+            }
+        }
+    }
 }
 
 impl Tool for WallTool {
@@ -25,10 +59,23 @@ impl Tool for WallTool {
                     self.wall_id = id;
                 }
             }
+            EditorMessage::PressedKey(key_code) => {
+                if let Some(name) = cycle_resource(
+                    &data.renderer.get_resources().wall_styles,
+                    self.wall_id,
+                    key_code,
+                ) {
+                    return self.update(data, EditorMessage::ChangeWallStyle(name));
+                }
+            }
             EditorMessage::ClickedButton { x, y, button } => {
                 let point = Point::new(x as i32, y as i32);
 
                 if let Some(index) = data.selector.get_tile_index(&data.tilemap, point) {
+                    if matches!(button, Button::Middle) {
+                        return self.click_rectangle(data, index);
+                    }
+
                     if let Some(side) = data.selector.get_side(&data.tilemap, point, index) {
                         let border = match button {
                             Button::Left => Border::Wall(self.wall_id),
@@ -48,6 +95,16 @@ impl Tool for WallTool {
         false
     }
 
+    fn pick(&mut self, tilemap: &Tilemap2d, selector: &Selector, point: Point) -> bool {
+        if let Some(id) = pick_border(tilemap, selector, point).and_then(|b| b.get_wall_style()) {
+            info!("WallTool: Pick wall style with id {}", id);
+            self.wall_id = id;
+            return true;
+        }
+
+        false
+    }
+
     fn view_sidebar(&mut self, data: &EditorData) -> Column<EditorMessage> {
         let resource_manager = &data.renderer.get_resources().wall_styles;
         let pick_list = create_pick_list(
@@ -56,11 +113,20 @@ impl Tool for WallTool {
             self.wall_id,
             EditorMessage::ChangeWallStyle,
         );
+        let thumbnails = create_thumbnail_buttons(
+            resource_manager,
+            &data.thumbnails.wall_styles,
+            &mut self.thumbnail_states,
+            EditorMessage::ChangeWallStyle,
+        );
         Column::new()
             .push(Text::new("Wall Style"))
             .push(pick_list)
+            .push(thumbnails)
             .push(title("Help"))
             .push(help("Click right to add"))
             .push(help("Click left to remove"))
+            .push(help("Click middle on 2 corners to add a wall rectangle"))
+            .push(help("'[' & ']' to cycle the wall style"))
     }
 }
@@ -1,10 +1,13 @@
 use crate::data::EditorData;
 use crate::message::EditorMessage;
-use crate::tool::{create_pick_list, help, title, Tool};
+use crate::tool::eyedropper::pick_border;
+use crate::tool::{create_pick_list, create_thumbnail_buttons, cycle_resource, help, title, Tool};
 use iced::mouse::Button;
-use iced::{pick_list, Column, Text};
+use iced::{button, pick_list, Column, Text};
 use texture_generation::math::point::Point;
 use tilemap::tilemap::border::Border;
+use tilemap::tilemap::selector::Selector;
+use tilemap::tilemap::tilemap2d::Tilemap2d;
 
 #[derive(Clone, Debug, Default)]
 pub struct WindowTool {
@@ -12,6 +15,7 @@ pub struct WindowTool {
     window_id: usize,
     wall_state: pick_list::State<String>,
     window_state: pick_list::State<String>,
+    thumbnail_states: Vec<button::State>,
 }
 
 impl Tool for WindowTool {
@@ -39,27 +43,34 @@ impl Tool for WindowTool {
                     self.window_id = id;
                 }
             }
+            EditorMessage::PressedKey(key_code) => {
+                if let Some(name) = cycle_resource(
+                    &data.renderer.get_resources().window_styles,
+                    self.window_id,
+                    key_code,
+                ) {
+                    return self.update(data, EditorMessage::ChangeWindowStyle(name));
+                }
+            }
             EditorMessage::ClickedButton { x, y, button } => {
                 let point = Point::new(x as i32, y as i32);
 
-                if let Some(index) = data.selector.get_tile_index(&data.tilemap, point) {
-                    if let Some(side) = data.selector.get_side(&data.tilemap, point, index) {
-                        let old_border = data.tilemap.get_border(index, side);
-
-                        let border = match button {
-                            Button::Left => Border::new_window(self.wall_id, self.window_id),
-                            _ => old_border.reduce(),
-                        };
+                if let Some((index, side)) = data.selector.get_border(&data.tilemap, point) {
+                    let old_border = data.tilemap.get_border(index, side);
 
-                        if old_border != border {
-                            info!("Set {:?} border of tile {} to {:?}", side, index, border);
+                    let border = match button {
+                        Button::Left => Border::new_window(self.wall_id, self.window_id),
+                        _ => old_border.reduce(),
+                    };
 
-                            data.tilemap.set_border(index, side, border);
-                            return true;
-                        }
+                    if old_border != border {
+                        info!("Set {:?} border of tile {} to {:?}", side, index, border);
 
-                        return false;
+                        data.tilemap.set_border(index, side, border);
+                        return true;
                     }
+
+                    return false;
                 }
             }
             _ => {}
@@ -68,6 +79,21 @@ impl Tool for WindowTool {
         false
     }
 
+    fn pick(&mut self, tilemap: &Tilemap2d, selector: &Selector, point: Point) -> bool {
+        match pick_border(tilemap, selector, point) {
+            Some(Border::Window { wall_id, window_id }) => {
+                info!(
+                    "WindowTool: Pick wall style {} & window style {}",
+                    wall_id, window_id
+                );
+                self.wall_id = wall_id;
+                self.window_id = window_id;
+                true
+            }
+            _ => false,
+        }
+    }
+
     fn view_sidebar(&mut self, data: &EditorData) -> Column<EditorMessage> {
         let wall_pick_list = create_pick_list(
             &data.renderer.get_resources().wall_styles,
@@ -81,13 +107,21 @@ impl Tool for WindowTool {
             self.window_id,
             EditorMessage::ChangeWindowStyle,
         );
+        let thumbnails = create_thumbnail_buttons(
+            &data.renderer.get_resources().window_styles,
+            &data.thumbnails.window_styles,
+            &mut self.thumbnail_states,
+            EditorMessage::ChangeWindowStyle,
+        );
         Column::new()
             .push(Text::new("Wall Style"))
             .push(wall_pick_list)
             .push(Text::new("Window Style"))
             .push(window_pick_list)
+            .push(thumbnails)
             .push(title("Help"))
             .push(help("Click right to add"))
             .push(help("Click left to remove"))
+            .push(help("'[' & ']' to cycle the window style"))
     }
 }
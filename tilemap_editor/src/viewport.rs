@@ -0,0 +1,136 @@
+use texture_generation::math::aabb::AABB;
+use texture_generation::math::point::Point;
+use texture_generation::math::side::Side;
+use texture_generation::math::size::Size;
+
+/// The part of the tilemap, which is visible in the preview.
+#[derive(Clone, Debug)]
+pub struct Viewport {
+    /// The top left tile of the visible area, before it is shifted inside the map.
+    start: Point,
+    /// The number of visible tiles along each axis.
+    size: Size,
+}
+
+impl Viewport {
+    pub fn new(size: Size) -> Self {
+        Viewport {
+            start: Point::default(),
+            size,
+        }
+    }
+
+    /// Returns true, if the whole map is visible.
+    pub fn is_showing_all(&self, map_size: Size) -> bool {
+        self.size.width() >= map_size.width() && self.size.height() >= map_size.height()
+    }
+
+    /// Returns the visible area in tile coordinates, which is shifted to be completely inside the map.
+    pub fn get_area(&self, map_size: Size) -> AABB {
+        let size = Size::new(
+            self.size.width().min(map_size.width()),
+            self.size.height().min(map_size.height()),
+        );
+        let max_x = (map_size.width() - size.width()) as i32;
+        let max_y = (map_size.height() - size.height()) as i32;
+        let start = Point::new(
+            self.start.x.max(0).min(max_x),
+            self.start.y.max(0).min(max_y),
+        );
+
+        AABB::new(start, size)
+    }
+
+    /// Moves the visible area by a tile towards the [`Side`]. Returns true, if it moved.
+    pub fn pan(&mut self, side: Side, map_size: Size) -> bool {
+        let old_start = self.get_area(map_size).start();
+
+        self.start = old_start
+            + match side {
+                Side::Top => Point::new(0, -1),
+                Side::Left => Point::new(-1, 0),
+                Side::Bottom => Point::new(0, 1),
+                Side::Right => Point::new(1, 0),
+            };
+        self.start = self.get_area(map_size).start();
+
+        self.start != old_start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Side::*;
+
+    #[test]
+    fn test_pan() {
+        let map_size = Size::new(10, 8);
+        let mut viewport = Viewport::new(Size::new(4, 3));
+
+        assert!(viewport.pan(Right, map_size));
+        assert!(viewport.pan(Bottom, map_size));
+        assert!(viewport.pan(Bottom, map_size));
+
+        assert_eq!(
+            viewport.get_area(map_size),
+            AABB::new(Point::new(1, 2), Size::new(4, 3))
+        );
+    }
+
+    #[test]
+    fn test_pan_stops_at_top_left() {
+        let map_size = Size::new(10, 8);
+        let mut viewport = Viewport::new(Size::new(4, 3));
+
+        assert!(!viewport.pan(Top, map_size));
+        assert!(!viewport.pan(Left, map_size));
+
+        assert_eq!(
+            viewport.get_area(map_size),
+            AABB::new(Point::new(0, 0), Size::new(4, 3))
+        );
+    }
+
+    #[test]
+    fn test_pan_stops_at_bottom_right() {
+        let map_size = Size::new(10, 8);
+        let mut viewport = Viewport::new(Size::new(4, 3));
+
+        for _ in 0..20 {
+            viewport.pan(Right, map_size);
+            viewport.pan(Bottom, map_size);
+        }
+
+        assert!(!viewport.pan(Right, map_size));
+        assert!(!viewport.pan(Bottom, map_size));
+        assert_eq!(
+            viewport.get_area(map_size),
+            AABB::new(Point::new(6, 5), Size::new(4, 3))
+        );
+    }
+
+    #[test]
+    fn test_shrinking_map_keeps_area_inside() {
+        let mut viewport = Viewport::new(Size::new(4, 3));
+
+        for _ in 0..6 {
+            viewport.pan(Right, Size::new(10, 8));
+        }
+
+        assert_eq!(
+            viewport.get_area(Size::new(5, 2)),
+            AABB::new(Point::new(1, 0), Size::new(4, 2))
+        );
+    }
+
+    #[test]
+    fn test_small_map_is_showing_all() {
+        let map_size = Size::new(3, 2);
+        let mut viewport = Viewport::new(Size::new(4, 3));
+
+        assert!(viewport.is_showing_all(map_size));
+        assert!(!viewport.pan(Right, map_size));
+        assert_eq!(viewport.get_area(map_size), AABB::with_size(map_size));
+    }
+}
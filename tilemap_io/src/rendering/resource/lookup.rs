@@ -1,7 +1,14 @@
 use crate::rendering::resource::ResourceDefinitions;
+use crate::rendering::style::door::DoorDefinition;
+use crate::rendering::style::furniture::FurnitureStyleDefinition;
+use crate::rendering::style::node::NodeDefinition;
+use crate::rendering::style::wall::WallDefinition;
+use crate::rendering::style::window::WindowDefinition;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
-use texture_generation::definition::read_resources;
+use texture_generation::definition::generation::TextureDefinition;
+use texture_generation::definition::ResourceReader;
 
 #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ResourceLookup {
@@ -13,43 +20,149 @@ pub struct ResourceLookup {
     windows: Vec<String>,
 }
 
+/// Remembers the definitions read by a [`ResourceLookup`], so that only changed files are read again.
+#[derive(Default)]
+pub struct ResourceCache {
+    doors: ResourceReader<DoorDefinition>,
+    furniture: ResourceReader<FurnitureStyleDefinition>,
+    nodes: ResourceReader<NodeDefinition>,
+    textures: ResourceReader<TextureDefinition>,
+    walls: ResourceReader<WallDefinition>,
+    windows: ResourceReader<WindowDefinition>,
+}
+
 impl ResourceLookup {
     pub fn convert(&self, path: &Path) -> ResourceDefinitions {
+        self.convert_changed(path, &mut ResourceCache::default()).0
+    }
+
+    /// Converts like [`ResourceLookup::convert()`], but only reads changed files.
+    /// Also returns the changed definitions as `folder/name`.
+    pub fn convert_changed(
+        &self,
+        path: &Path,
+        cache: &mut ResourceCache,
+    ) -> (ResourceDefinitions, Vec<String>) {
         info!("Load definitions from {:?}", path);
 
-        let textures = read_resources(&path.join("textures"), &self.textures);
+        let mut changed = Vec::new();
+
+        let textures = resolve_references(
+            &path.join("textures"),
+            read(
+                path,
+                "textures",
+                &self.textures,
+                &mut cache.textures,
+                &mut changed,
+            ),
+        );
 
         info!("Loaded {} texture definitions", textures.len());
 
         let style_path = path.join("styles");
 
-        let doors = read_resources(&style_path.join("doors"), &self.doors);
+        let doors = read(
+            &style_path,
+            "doors",
+            &self.doors,
+            &mut cache.doors,
+            &mut changed,
+        );
 
         info!("Loaded {} door definitions", doors.len());
 
-        let furniture = read_resources(&style_path.join("furniture"), &self.furniture);
+        let furniture = read(
+            &style_path,
+            "furniture",
+            &self.furniture,
+            &mut cache.furniture,
+            &mut changed,
+        );
 
         info!("Loaded {} furniture definitions", furniture.len());
 
-        let nodes = read_resources(&style_path.join("nodes"), &self.nodes);
+        let nodes = read(
+            &style_path,
+            "nodes",
+            &self.nodes,
+            &mut cache.nodes,
+            &mut changed,
+        );
 
         info!("Loaded {} node definitions", nodes.len());
 
-        let walls = read_resources(&style_path.join("walls"), &self.walls);
+        let walls = read(
+            &style_path,
+            "walls",
+            &self.walls,
+            &mut cache.walls,
+            &mut changed,
+        );
 
         info!("Loaded {} wall definitions", walls.len());
 
-        let windows = read_resources(&style_path.join("windows"), &self.windows);
+        let windows = read(
+            &style_path,
+            "windows",
+            &self.windows,
+            &mut cache.windows,
+            &mut changed,
+        );
 
         info!("Loaded {} window definitions", windows.len());
 
-        ResourceDefinitions {
+        let definitions = ResourceDefinitions {
             doors,
             furniture,
             nodes,
             textures,
             walls,
             windows,
-        }
+        };
+
+        (definitions, changed)
     }
 }
+
+fn read<T: DeserializeOwned + Clone>(
+    path: &Path,
+    folder: &str,
+    names: &[String],
+    reader: &mut ResourceReader<T>,
+    changed: &mut Vec<String>,
+) -> Vec<Option<(String, T)>> {
+    let (resources, changed_names) = reader.read(&path.join(folder), names);
+
+    changed.extend(
+        changed_names
+            .into_iter()
+            .map(|name| format!("{}/{}", folder, name)),
+    );
+
+    resources
+}
+
+/// Resolves the references between texture definitions. Definitions with invalid references are skipped.
+fn resolve_references(
+    dir: &Path,
+    textures: Vec<Option<(String, TextureDefinition)>>,
+) -> Vec<Option<(String, TextureDefinition)>> {
+    textures
+        .into_iter()
+        .map(|entry| {
+            let (name, definition) = entry?;
+
+            match definition.resolve_references(dir) {
+                Ok(definition) => Some((name, definition)),
+                Err(error) => {
+                    warn!(
+                        "Couldn't resolve the references of '{}', because of {:?}",
+                        name, error
+                    );
+                    None
+                }
+            }
+        })
+        .collect()
+}
@@ -2,6 +2,7 @@ use crate::rendering::style::edge::EdgeDefinition;
 use crate::rendering::style::handle::HandleDefinition;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use texture_generation::math::side::Side;
 use texture_generation::utils::resource::ResourceDefinition;
 use tilemap::rendering::style::door::DoorStyle;
 
@@ -11,6 +12,7 @@ pub struct DoorDefinition {
     edge_style: EdgeDefinition,
     handle_style: Option<HandleDefinition>,
     is_centered: bool,
+    swing_side: Option<Side>,
 }
 
 impl ResourceDefinition for DoorDefinition {
@@ -34,11 +36,13 @@ impl ResourceDefinition for DoorDefinition {
                 "Failed to convert 'handle_style' of the door '{}'",
                 name
             ))?;
-        Ok(DoorStyle::new(
+        Ok(DoorStyle::with_swing(
             name,
             edge_style,
             handle_style,
             self.is_centered,
+            self.swing_side.is_some(),
+            self.swing_side.unwrap_or(Side::Bottom),
         ))
     }
 }
@@ -55,9 +59,25 @@ mod tests {
             edge_style: EdgeDefinition::Mock(10),
             handle_style: None,
             is_centered: true,
+            swing_side: None,
         };
         let style = DoorStyle::new("door0", EdgeStyle::Mock(30), None, true);
 
         assert_eq!(style, definition.convert("door0", 600).unwrap())
     }
+
+    #[test]
+    fn test_convert_with_swing() {
+        let definition = DoorDefinition {
+            tile_size: 200,
+            edge_style: EdgeDefinition::Mock(10),
+            handle_style: None,
+            is_centered: false,
+            swing_side: Some(Side::Top),
+        };
+        let style =
+            DoorStyle::with_swing("door0", EdgeStyle::Mock(30), None, false, true, Side::Top);
+
+        assert_eq!(style, definition.convert("door0", 600).unwrap())
+    }
 }
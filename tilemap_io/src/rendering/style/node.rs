@@ -2,30 +2,96 @@ use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use texture_generation::definition::convert;
 use texture_generation::definition::generation::component::rendering::RenderingDefinition;
+use texture_generation::definition::math::shape_factor::ShapeFactorDefinition;
+use texture_generation::math::color::Color;
 use texture_generation::utils::resource::ResourceDefinition;
-use tilemap::rendering::style::node::NodeStyle;
+use tilemap::rendering::style::node::{CornerJoint, NodeStyle};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CornerJointDefinition {
+    Butt,
+    Miter,
+    Round,
+}
+
+impl CornerJointDefinition {
+    fn convert(&self) -> CornerJoint {
+        match self {
+            CornerJointDefinition::Butt => CornerJoint::Butt,
+            CornerJointDefinition::Miter => CornerJoint::Miter,
+            CornerJointDefinition::Round => CornerJoint::Round,
+        }
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct NodeDefinition {
-    tile_size: u32,
-    size: u32,
-    component: RenderingDefinition,
+#[serde(untagged)]
+pub enum NodeDefinition {
+    /// A post or column, which is rendered on top of the walls.
+    Post {
+        tile_size: u32,
+        size: u32,
+        shape: ShapeFactorDefinition,
+        color: String,
+        depth: u8,
+    },
+    /// Fills the area between the walls.
+    Fill {
+        tile_size: u32,
+        size: u32,
+        joint: Option<CornerJointDefinition>,
+        component: RenderingDefinition,
+    },
 }
 
 impl ResourceDefinition for NodeDefinition {
     type R = NodeStyle;
 
     fn convert(&self, name: &str, size: u32) -> Result<NodeStyle> {
-        let factor = size as f32 / self.tile_size as f32;
-        let size = convert(self.size, factor);
-        let component = self
-            .component
-            .convert("component", factor)
-            .context(format!(
-                "Failed to convert 'component' of the node '{}'",
-                name
-            ))?;
-        Ok(NodeStyle::new(name, size, component))
+        match self {
+            NodeDefinition::Post {
+                tile_size,
+                size: post_size,
+                shape,
+                color,
+                depth,
+            } => {
+                let factor = size as f32 / *tile_size as f32;
+                let shape_factory = shape
+                    .convert()
+                    .context(format!("Failed to convert 'shape' of the post '{}'", name))?;
+                let color = Color::convert(color)
+                    .context(format!("Failed to convert 'color' of the post '{}'", name))?;
+                Ok(NodeStyle::new_post(
+                    name,
+                    convert(*post_size, factor),
+                    shape_factory,
+                    color,
+                    *depth,
+                ))
+            }
+            NodeDefinition::Fill {
+                tile_size,
+                size: node_size,
+                joint,
+                component,
+            } => {
+                let factor = size as f32 / *tile_size as f32;
+                let component = component.convert("component", factor).context(format!(
+                    "Failed to convert 'component' of the node '{}'",
+                    name
+                ))?;
+                let joint = joint
+                    .map(|joint| joint.convert())
+                    .unwrap_or(CornerJoint::Miter);
+                Ok(NodeStyle::with_joint(
+                    name,
+                    convert(*node_size, factor),
+                    joint,
+                    component,
+                ))
+            }
+        }
     }
 }
 
@@ -34,6 +100,7 @@ mod tests {
     use super::*;
     use texture_generation::generation::component::rendering::RenderingComponent;
     use texture_generation::math::color::RED;
+    use texture_generation::math::shape_factory::ShapeFactory;
 
     #[test]
     fn test_convert() {
@@ -41,9 +108,10 @@ mod tests {
             color: "#FF0000".to_string(),
             depth: 123,
         };
-        let definition = NodeDefinition {
+        let definition = NodeDefinition::Fill {
             tile_size: 200,
             size: 35,
+            joint: None,
             component: rendering_definition,
         };
         let component = RenderingComponent::new_fill_area(RED, 123);
@@ -51,4 +119,36 @@ mod tests {
 
         assert_eq!(style, definition.convert("window0", 600).unwrap())
     }
+
+    #[test]
+    fn test_convert_with_joint() {
+        let rendering_definition = RenderingDefinition::FillArea {
+            color: "#FF0000".to_string(),
+            depth: 123,
+        };
+        let definition = NodeDefinition::Fill {
+            tile_size: 200,
+            size: 35,
+            joint: Some(CornerJointDefinition::Round),
+            component: rendering_definition,
+        };
+        let component = RenderingComponent::new_fill_area(RED, 123);
+        let style = NodeStyle::with_joint("window0", 105, CornerJoint::Round, component);
+
+        assert_eq!(style, definition.convert("window0", 600).unwrap())
+    }
+
+    #[test]
+    fn test_convert_post() {
+        let definition = NodeDefinition::Post {
+            tile_size: 200,
+            size: 35,
+            shape: ShapeFactorDefinition::Circle,
+            color: "#FF0000".to_string(),
+            depth: 123,
+        };
+        let style = NodeStyle::new_post("post0", 105, ShapeFactory::Circle, RED, 123);
+
+        assert_eq!(style, definition.convert("post0", 600).unwrap())
+    }
 }
@@ -1,6 +1,7 @@
 use crate::rendering::style::edge::EdgeDefinition;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use texture_generation::math::color::Color;
 use texture_generation::utils::resource::ResourceDefinition;
 use tilemap::rendering::style::wall::WallStyle;
 
@@ -10,6 +11,7 @@ pub struct WallDefinition {
     edge_style: EdgeDefinition,
     node_style: Option<usize>,
     corner_style: Option<usize>,
+    cap: Option<String>,
 }
 
 impl ResourceDefinition for WallDefinition {
@@ -24,11 +26,19 @@ impl ResourceDefinition for WallDefinition {
                 "Failed to convert 'edge_style' of the wall '{}'",
                 name
             ))?;
-        Ok(WallStyle::new(
+        let cap = match &self.cap {
+            None => None,
+            Some(color) => Some(
+                Color::convert(color)
+                    .context(format!("Failed to convert 'cap' of the wall '{}'", name))?,
+            ),
+        };
+        Ok(WallStyle::with_cap(
             name,
             edge_style,
             self.node_style,
             self.corner_style,
+            cap,
         ))
     }
 }
@@ -36,6 +46,7 @@ impl ResourceDefinition for WallDefinition {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use texture_generation::math::color::BLUE;
     use tilemap::rendering::style::edge::EdgeStyle;
 
     #[test]
@@ -45,9 +56,24 @@ mod tests {
             edge_style: EdgeDefinition::Mock(10),
             node_style: Some(4),
             corner_style: Some(3),
+            cap: None,
         };
         let style = WallStyle::new("wall0", EdgeStyle::Mock(30), Some(4), Some(3));
 
         assert_eq!(style, definition.convert("wall0", 600).unwrap())
     }
+
+    #[test]
+    fn test_convert_with_cap() {
+        let definition = WallDefinition {
+            tile_size: 200,
+            edge_style: EdgeDefinition::Mock(10),
+            node_style: None,
+            corner_style: Some(3),
+            cap: Some("#0000FF".to_string()),
+        };
+        let style = WallStyle::with_cap("wall0", EdgeStyle::Mock(30), None, Some(3), Some(BLUE));
+
+        assert_eq!(style, definition.convert("wall0", 600).unwrap())
+    }
 }
@@ -1,14 +1,37 @@
 use crate::rendering::style::edge::EdgeDefinition;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use texture_generation::definition::convert;
+use texture_generation::math::color::Color;
 use texture_generation::utils::resource::ResourceDefinition;
-use tilemap::rendering::style::window::WindowStyle;
+use tilemap::rendering::style::window::{GlassStyle, WindowStyle};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GlassDefinition {
+    glass_color: String,
+    sill_depth: u8,
+    frame_thickness: u32,
+}
+
+impl GlassDefinition {
+    pub fn convert(&self, parent: &str, factor: f32) -> Result<GlassStyle> {
+        let color = Color::convert(&self.glass_color)
+            .context(format!("Failed to convert '{}.glass_color'", parent))?;
+
+        GlassStyle::new(
+            color,
+            self.sill_depth,
+            convert(self.frame_thickness, factor),
+        )
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct WindowDefinition {
     tile_size: u32,
     pane_style: EdgeDefinition,
     stool_style: EdgeDefinition,
+    glass_style: Option<GlassDefinition>,
 }
 
 impl ResourceDefinition for WindowDefinition {
@@ -30,13 +53,25 @@ impl ResourceDefinition for WindowDefinition {
                 "Failed to convert 'stool_style' of the window '{}'",
                 name
             ))?;
-        Ok(WindowStyle::new(name, pane_style, stool_style))
+        let glass_style = match &self.glass_style {
+            None => None,
+            Some(glass_style) => Some(glass_style.convert("glass_style", factor).context(
+                format!("Failed to convert 'glass_style' of the window '{}'", name),
+            )?),
+        };
+        Ok(WindowStyle::with_glass(
+            name,
+            pane_style,
+            stool_style,
+            glass_style,
+        ))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use texture_generation::math::color::BLUE;
     use tilemap::rendering::style::edge::EdgeStyle;
 
     #[test]
@@ -45,9 +80,33 @@ mod tests {
             tile_size: 200,
             pane_style: EdgeDefinition::Mock(10),
             stool_style: EdgeDefinition::Mock(30),
+            glass_style: None,
         };
         let style = WindowStyle::new("window0", EdgeStyle::Mock(30), EdgeStyle::Mock(90));
 
         assert_eq!(style, definition.convert("window0", 600).unwrap())
     }
+
+    #[test]
+    fn test_convert_with_glass() {
+        let definition = WindowDefinition {
+            tile_size: 200,
+            pane_style: EdgeDefinition::Mock(10),
+            stool_style: EdgeDefinition::Mock(30),
+            glass_style: Some(GlassDefinition {
+                glass_color: "#0000FF".to_string(),
+                sill_depth: 5,
+                frame_thickness: 2,
+            }),
+        };
+        let glass_style = GlassStyle::new(BLUE, 5, 6).unwrap();
+        let style = WindowStyle::with_glass(
+            "window0",
+            EdgeStyle::Mock(30),
+            EdgeStyle::Mock(90),
+            Some(glass_style),
+        );
+
+        assert_eq!(style, definition.convert("window0", 600).unwrap())
+    }
 }
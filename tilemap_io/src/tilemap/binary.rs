@@ -0,0 +1,322 @@
+use anyhow::{Context, Result};
+use std::convert::TryInto;
+use std::fs;
+use std::path::Path;
+use texture_generation::math::side::Side;
+use texture_generation::math::size::Size;
+use tilemap::tilemap::border::{get_horizontal_borders_size, get_vertical_borders_size, Border};
+use tilemap::tilemap::tile::Tile;
+use tilemap::tilemap::tilemap2d::Tilemap2d;
+
+pub const BINARY_TILEMAP_FILE_ENDING: &str = "otmb";
+
+const MAGIC: &[u8; 4] = b"OTMB";
+const VERSION: u8 = 2;
+/// The first version, whose doors have no width.
+const VERSION_WITHOUT_DOOR_WIDTH: u8 = 1;
+
+const EMPTY: u8 = 0;
+const FLOOR: u8 = 1;
+const SOLID: u8 = 2;
+const RAMP: u8 = 3;
+const WALL: u8 = 1;
+const DOOR: u8 = 2;
+const WINDOW: u8 = 3;
+const RAILING: u8 = 4;
+
+pub fn load_binary(path: &Path) -> Result<Tilemap2d> {
+    info!("Load binary tilemap from {:?}", path);
+
+    let bytes = fs::read(path).context(format!("Unable to read {:?}", path))?;
+    load_from_bytes(&bytes)
+}
+
+/// Loads a [`Tilemap2d`] from the compact little-endian encoding.
+pub fn load_from_bytes(bytes: &[u8]) -> Result<Tilemap2d> {
+    let mut reader = Reader { bytes, index: 0 };
+
+    if reader.read_bytes(MAGIC.len(), "magic header")? != MAGIC {
+        return Err(anyhow!("Invalid magic header"));
+    }
+
+    let version = reader.read_u8("version")?;
+
+    if version != VERSION && version != VERSION_WITHOUT_DOOR_WIDTH {
+        return Err(anyhow!("Unsupported version {}", version));
+    }
+
+    let width = reader.read_u32("width")?;
+    let height = reader.read_u32("height")?;
+    let size = Size::new(width, height);
+    let tiles = (0..size.len())
+        .map(|i| read_tile(&mut reader).context(format!("Unable to read the {}.tile", i + 1)))
+        .collect::<Result<Vec<Tile>>>()?;
+    let horizontal_borders = read_borders(&mut reader, get_horizontal_borders_size(size), version)?;
+    let vertical_borders = read_borders(&mut reader, get_vertical_borders_size(size), version)?;
+
+    if reader.index != bytes.len() {
+        return Err(anyhow!(
+            "{} unexpected bytes at the end",
+            bytes.len() - reader.index
+        ));
+    }
+
+    Tilemap2d::with_borders(size, tiles, horizontal_borders, vertical_borders)
+        .ok_or_else(|| anyhow!("Could not create tilemap"))
+}
+
+fn read_borders(reader: &mut Reader, size: Size, version: u8) -> Result<Vec<Border>> {
+    (0..size.len())
+        .map(|i| {
+            read_border(reader, version).context(format!("Unable to read the {}.border", i + 1))
+        })
+        .collect()
+}
+
+fn read_tile(reader: &mut Reader) -> Result<Tile> {
+    match reader.read_u8("tile type")? {
+        EMPTY => Ok(Tile::Empty),
+        FLOOR => Ok(Tile::Floor(reader.read_id("Tile Id")?)),
+        SOLID => Ok(Tile::Solid(reader.read_id("Tile Id")?)),
+        RAMP => Ok(Tile::Ramp {
+            id: reader.read_id("Tile Id")?,
+            direction: read_side(reader)?,
+        }),
+        tag => Err(anyhow!("Unknown tile type {}", tag)),
+    }
+}
+
+fn read_side(reader: &mut Reader) -> Result<Side> {
+    let value = reader.read_u8("Direction")?;
+
+    Side::iterator()
+        .find(|side| **side as u8 == value)
+        .copied()
+        .ok_or_else(|| anyhow!("Unknown direction {}", value))
+}
+
+fn read_border(reader: &mut Reader, version: u8) -> Result<Border> {
+    match reader.read_u8("border type")? {
+        EMPTY => Ok(Border::Empty),
+        WALL => Ok(Border::Wall(reader.read_id("Wall Id")?)),
+        RAILING => Ok(Border::Railing(reader.read_id("Wall Id")?)),
+        DOOR => {
+            let wall_id = reader.read_id("Wall Id")?;
+            let door_id = reader.read_id("Door Id")?;
+            let is_front = reader.read_u8("Is Front")? == 1;
+            let width = if version == VERSION_WITHOUT_DOOR_WIDTH {
+                1
+            } else {
+                reader.read_u32("Width")?
+            };
+
+            Ok(Border::new_wide_door(wall_id, door_id, is_front, width))
+        }
+        WINDOW => Ok(Border::new_window(
+            reader.read_id("Wall Id")?,
+            reader.read_id("Window Id")?,
+        )),
+        tag => Err(anyhow!("Unknown border type {}", tag)),
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    index: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read_bytes(&mut self, n: usize, name: &str) -> Result<&'a [u8]> {
+        let end = self.index + n;
+
+        if end > self.bytes.len() {
+            return Err(anyhow!("{} is missing", name));
+        }
+
+        let bytes = &self.bytes[self.index..end];
+        self.index = end;
+
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self, name: &str) -> Result<u8> {
+        Ok(self.read_bytes(1, name)?[0])
+    }
+
+    fn read_u32(&mut self, name: &str) -> Result<u32> {
+        let bytes = self.read_bytes(4, name)?;
+        Ok(u32::from_le_bytes(bytes.try_into()?))
+    }
+
+    fn read_id(&mut self, name: &str) -> Result<usize> {
+        Ok(self.read_u32(name)? as usize)
+    }
+}
+
+pub fn save_binary(tilemap: &Tilemap2d, path: &Path) -> Result<()> {
+    info!("Save binary tilemap to {:?}", path);
+
+    let bytes = save_to_bytes(tilemap)?;
+
+    fs::write(path, bytes).context(format!("Unable to write {:?}", path))
+}
+
+/// Saves a [`Tilemap2d`] with a compact little-endian encoding.
+pub fn save_to_bytes(tilemap: &Tilemap2d) -> Result<Vec<u8>> {
+    let size = tilemap.get_size();
+    let mut bytes = Vec::with_capacity(13 + size.len() * 5);
+
+    bytes.extend_from_slice(MAGIC);
+    bytes.push(VERSION);
+    bytes.extend_from_slice(&size.width().to_le_bytes());
+    bytes.extend_from_slice(&size.height().to_le_bytes());
+
+    for tile in tilemap.get_tiles() {
+        write_tile(&mut bytes, tile)?;
+    }
+
+    for border in tilemap.get_horizontal_borders() {
+        write_border(&mut bytes, border)?;
+    }
+
+    for border in tilemap.get_vertical_borders() {
+        write_border(&mut bytes, border)?;
+    }
+
+    Ok(bytes)
+}
+
+fn write_tile(bytes: &mut Vec<u8>, tile: &Tile) -> Result<()> {
+    match tile {
+        Tile::Empty => bytes.push(EMPTY),
+        Tile::Floor(id) => {
+            bytes.push(FLOOR);
+            write_id(bytes, *id)?;
+        }
+        Tile::Solid(id) => {
+            bytes.push(SOLID);
+            write_id(bytes, *id)?;
+        }
+        Tile::Ramp { id, direction } => {
+            bytes.push(RAMP);
+            write_id(bytes, *id)?;
+            bytes.push(*direction as u8);
+        }
+    }
+
+    Ok(())
+}
+
+fn write_border(bytes: &mut Vec<u8>, border: &Border) -> Result<()> {
+    match border {
+        Border::Empty => bytes.push(EMPTY),
+        Border::Wall(id) => {
+            bytes.push(WALL);
+            write_id(bytes, *id)?;
+        }
+        Border::Door {
+            wall_id,
+            door_id,
+            is_front,
+            width,
+        } => {
+            bytes.push(DOOR);
+            write_id(bytes, *wall_id)?;
+            write_id(bytes, *door_id)?;
+            bytes.push(*is_front as u8);
+            bytes.extend_from_slice(&width.to_le_bytes());
+        }
+        Border::Window { wall_id, window_id } => {
+            bytes.push(WINDOW);
+            write_id(bytes, *wall_id)?;
+            write_id(bytes, *window_id)?;
+        }
+        Border::Railing(id) => {
+            bytes.push(RAILING);
+            write_id(bytes, *id)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_id(bytes: &mut Vec<u8>, id: usize) -> Result<()> {
+    let id: u32 = id
+        .try_into()
+        .context(format!("Id {} is too big for the binary format", id))?;
+    bytes.extend_from_slice(&id.to_le_bytes());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use texture_generation::math::side::Side::*;
+
+    #[test]
+    fn test_save_and_load() {
+        let size = Size::new(2, 3);
+        let mut tilemap = Tilemap2d::default(size, Tile::Empty);
+
+        tilemap.set_tile(0, Tile::Solid(1));
+        tilemap.set_tile(2, Tile::Floor(2));
+        tilemap.set_tile(4, Tile::Floor(3));
+        tilemap.set_tile(
+            5,
+            Tile::Ramp {
+                id: 4,
+                direction: Bottom,
+            },
+        );
+        tilemap.set_border(2, Bottom, Border::Wall(1));
+        tilemap.set_border(2, Left, Border::new_window(2, 1));
+        tilemap.set_border(2, Right, Border::new_door(3, 2, false));
+        tilemap.set_border(2, Top, Border::new_wide_door(4, 5, true, 2));
+        tilemap.set_border(4, Bottom, Border::Railing(6));
+
+        let bytes = save_to_bytes(&tilemap).unwrap();
+        let new_tilemap = load_from_bytes(&bytes).unwrap();
+
+        assert_eq!(tilemap, new_tilemap);
+    }
+
+    #[test]
+    fn test_load_version_1() {
+        let mut bytes = b"OTMB\x01".to_vec();
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.push(EMPTY);
+        bytes.push(DOOR);
+        bytes.extend_from_slice(&3u32.to_le_bytes());
+        bytes.extend_from_slice(&2u32.to_le_bytes());
+        bytes.push(1);
+        bytes.extend_from_slice(&[EMPTY; 3]);
+        let mut tilemap = Tilemap2d::default(Size::square(1), Tile::Empty);
+        tilemap.set_border(0, Top, Border::new_door(3, 2, true));
+
+        assert_eq!(load_from_bytes(&bytes).unwrap(), tilemap);
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let mut tilemap = Tilemap2d::default(Size::square(1), Tile::Empty);
+        tilemap.set_border(0, Top, Border::new_door(3, 2, true));
+        let mut bytes = save_to_bytes(&tilemap).unwrap();
+        bytes[MAGIC.len()] = 3;
+
+        assert!(load_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_invalid_magic_header() {
+        assert!(load_from_bytes(b"OTMX\x01").is_err());
+    }
+
+    #[test]
+    fn test_truncated_data() {
+        let tilemap = Tilemap2d::default(Size::new(2, 3), Tile::Floor(1));
+        let bytes = save_to_bytes(&tilemap).unwrap();
+
+        assert!(load_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
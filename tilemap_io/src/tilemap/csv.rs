@@ -0,0 +1,281 @@
+use super::{format_border, format_tile, parse_border, parse_tile};
+use anyhow::{Context, Result};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use texture_generation::math::size::Size;
+use tilemap::tilemap::border::{get_horizontal_borders_size, get_vertical_borders_size};
+use tilemap::tilemap::tilemap2d::Tilemap2d;
+
+pub const CSV_TILEMAP_FILE_ENDING: &str = "csv";
+
+const TILES: &str = "tiles";
+const HORIZONTAL_BORDERS: &str = "horizontal borders";
+const VERTICAL_BORDERS: &str = "vertical borders";
+
+pub fn load_csv(path: &Path) -> Result<Tilemap2d> {
+    info!("Load csv tilemap from {:?}", path);
+
+    let string = fs::read_to_string(path).context(format!("Unable to read {:?}", path))?;
+    load_from_csv(&string)
+}
+
+/// Loads a [`Tilemap2d`] from sections of tiles & borders.
+/// Each section is a grid of codes with the coordinates as header row & column.
+pub fn load_from_csv(string: &str) -> Result<Tilemap2d> {
+    let mut lines = string
+        .lines()
+        .map(|line| line.trim_end())
+        .filter(|line| !line.is_empty());
+
+    let width = parse_value(lines.next(), "width")?;
+    let height = parse_value(lines.next(), "height")?;
+    let size = Size::new(width, height);
+    let tiles = load_section(&mut lines, TILES, size, parse_tile)?;
+    let horizontal_borders = load_section(
+        &mut lines,
+        HORIZONTAL_BORDERS,
+        get_horizontal_borders_size(size),
+        parse_border,
+    )?;
+    let vertical_borders = load_section(
+        &mut lines,
+        VERTICAL_BORDERS,
+        get_vertical_borders_size(size),
+        parse_border,
+    )?;
+
+    if let Some(line) = lines.next() {
+        return Err(anyhow!("Unexpected line '{}' at the end", line));
+    }
+
+    Tilemap2d::with_borders(size, tiles, horizontal_borders, vertical_borders)
+        .ok_or_else(|| anyhow!("Could not create tilemap"))
+}
+
+fn parse_value(line: Option<&str>, name: &str) -> Result<u32> {
+    let line = line.ok_or_else(|| anyhow!("Line for '{}' is missing", name))?;
+    let cells = split_line(line);
+
+    if cells.len() == 2 && cells[0] == name {
+        cells[1]
+            .parse()
+            .context(format!("Unable to parse '{}'", name))
+    } else {
+        Err(anyhow!("Line doesn't start with {}", name))
+    }
+}
+
+fn load_section<'a, T, I: Iterator<Item = &'a str>>(
+    lines: &mut I,
+    name: &str,
+    size: Size,
+    parse: fn(&str) -> Result<T>,
+) -> Result<Vec<T>> {
+    let header = lines
+        .next()
+        .ok_or_else(|| anyhow!("Section '{}' is missing", name))?;
+
+    if split_line(header) != create_header(name, size.width()) {
+        return Err(anyhow!(
+            "Invalid header of section '{}': '{}'",
+            name,
+            header
+        ));
+    }
+
+    let mut elements = Vec::with_capacity(size.len());
+
+    for y in 0..size.height() {
+        let line = lines
+            .next()
+            .ok_or_else(|| anyhow!("{}.row of {} is missing", y + 1, name))?;
+        let cells = split_line(line);
+
+        if cells.len() != size.width() as usize + 1 {
+            return Err(anyhow!(
+                "{}.row of {} has {} instead of {} elements: '{}'",
+                y + 1,
+                name,
+                cells.len() - 1,
+                size.width(),
+                line
+            ));
+        } else if cells[0] != y.to_string() {
+            return Err(anyhow!(
+                "{}.row of {} starts with '{}' instead of {}",
+                y + 1,
+                name,
+                cells[0],
+                y
+            ));
+        }
+
+        for (x, cell) in cells[1..].iter().enumerate() {
+            let element = parse(cell).context(format!(
+                "Unable to read the {}.element of {}.row of {} from '{}'",
+                x + 1,
+                y + 1,
+                name,
+                cell
+            ))?;
+            elements.push(element);
+        }
+    }
+
+    Ok(elements)
+}
+
+/// Splits a line at commas, which are not inside quotes.
+fn split_line(line: &str) -> Vec<String> {
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut is_quoted = false;
+
+    for c in line.chars() {
+        match c {
+            '"' => is_quoted = !is_quoted,
+            ',' if !is_quoted => {
+                cells.push(cell.trim().to_string());
+                cell.clear();
+            }
+            _ => cell.push(c),
+        }
+    }
+
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+fn create_header(name: &str, width: u32) -> Vec<String> {
+    let mut header = vec![name.to_string()];
+    header.extend((0..width).map(|x| x.to_string()));
+    header
+}
+
+pub fn save_csv(tilemap: &Tilemap2d, path: &Path) -> Result<()> {
+    info!("Save csv tilemap to {:?}", path);
+
+    let string = save_to_csv(tilemap)?;
+    fs::write(path, string).context(format!("Unable to write {:?}", path))
+}
+
+/// Saves a [`Tilemap2d`] like [`load_from_csv()`] expects it.
+pub fn save_to_csv(tilemap: &Tilemap2d) -> Result<String> {
+    let mut string = String::new();
+    let size = tilemap.get_size();
+
+    writeln!(&mut string, "width,{}", size.width())?;
+    writeln!(&mut string, "height,{}", size.height())?;
+
+    let tiles: Vec<String> = tilemap.get_tiles().iter().map(format_tile).collect();
+    save_section(&mut string, TILES, size, &tiles)?;
+
+    let borders: Vec<String> = tilemap
+        .get_horizontal_borders()
+        .iter()
+        .map(format_border)
+        .collect();
+    save_section(
+        &mut string,
+        HORIZONTAL_BORDERS,
+        get_horizontal_borders_size(size),
+        &borders,
+    )?;
+
+    let borders: Vec<String> = tilemap
+        .get_vertical_borders()
+        .iter()
+        .map(format_border)
+        .collect();
+    save_section(
+        &mut string,
+        VERTICAL_BORDERS,
+        get_vertical_borders_size(size),
+        &borders,
+    )?;
+
+    Ok(string)
+}
+
+fn save_section(string: &mut String, name: &str, size: Size, cells: &[String]) -> Result<()> {
+    writeln!(string)?;
+    writeln!(string, "{}", create_header(name, size.width()).join(","))?;
+
+    for y in 0..size.height() {
+        write!(string, "{}", y)?;
+
+        for x in 0..size.width() {
+            let cell = cells[size.convert_x_y(x, y)].trim();
+
+            if cell.contains(',') {
+                write!(string, ",\"{}\"", cell)?;
+            } else {
+                write!(string, ",{}", cell)?;
+            }
+        }
+
+        writeln!(string)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use texture_generation::math::side::Side::*;
+    use tilemap::tilemap::border::Border;
+    use tilemap::tilemap::tile::Tile;
+
+    #[test]
+    fn test_save_and_load() {
+        let size = Size::new(2, 3);
+        let mut tilemap = Tilemap2d::default(size, Tile::Empty);
+
+        tilemap.set_tile(0, Tile::Solid(1));
+        tilemap.set_tile(2, Tile::Floor(2));
+        tilemap.set_tile(4, Tile::Floor(3));
+        tilemap.set_tile(
+            5,
+            Tile::Ramp {
+                id: 4,
+                direction: Right,
+            },
+        );
+        tilemap.set_border(2, Bottom, Border::Wall(1));
+        tilemap.set_border(2, Left, Border::new_window(2, 1));
+        tilemap.set_border(2, Right, Border::new_door(3, 2, false));
+        tilemap.set_border(2, Top, Border::new_wide_door(4, 5, true, 2));
+        tilemap.set_border(4, Bottom, Border::Railing(6));
+
+        let string = save_to_csv(&tilemap).unwrap();
+        let new_tilemap = load_from_csv(&string).unwrap();
+
+        assert_eq!(tilemap, new_tilemap);
+    }
+
+    #[test]
+    fn test_save_format() {
+        let tilemap = Tilemap2d::default(Size::new(1, 1), Tile::Floor(3));
+
+        assert_eq!(
+            save_to_csv(&tilemap).unwrap(),
+            "width,1\nheight,1\n\ntiles,0\n0,\"F,3\"\n\nhorizontal borders,0\n0,E\n1,E\n\nvertical borders,0,1\n0,E,E\n"
+        );
+    }
+
+    #[test]
+    fn test_load_with_wrong_row_coordinate() {
+        let string = "width,1\nheight,1\ntiles,0\n1,E\nhorizontal borders,0\n0,E\n1,E\nvertical borders,0,1\n0,E,E\n";
+
+        assert!(load_from_csv(string).is_err());
+    }
+
+    #[test]
+    fn test_load_with_too_short_row() {
+        let string = "width,2\nheight,1\ntiles,0,1\n0,E\nhorizontal borders,0,1\n0,E,E\n1,E,E\nvertical borders,0,1,2\n0,E,E,E\n";
+
+        assert!(load_from_csv(string).is_err());
+    }
+}
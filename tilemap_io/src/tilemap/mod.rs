@@ -5,14 +5,21 @@ use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
 use std::str::Split;
 use std::{fs, io};
+use texture_generation::math::side::Side;
 use texture_generation::math::size::Size;
 use tilemap::tilemap::border::{get_horizontal_borders_size, get_vertical_borders_size, Border};
 use tilemap::tilemap::tile::Tile;
 use tilemap::tilemap::tilemap2d::Tilemap2d;
 
+pub mod binary;
+pub mod csv;
 pub mod furniture;
+pub mod svg;
 
 pub const TILEMAP_FILE_ENDING: &str = "otm";
+/// The version of the text format written by [`save_to_string`].
+/// Files without a version line are treated as version 0.
+pub const TILEMAP_VERSION: u32 = 1;
 
 pub fn load_tilemap(path: &Path) -> Result<Tilemap2d> {
     info!("Load tilemap from {:?}", path);
@@ -24,6 +31,20 @@ pub fn load_tilemap(path: &Path) -> Result<Tilemap2d> {
 pub fn load_from_string(string: String) -> Result<Tilemap2d> {
     let mut reader = io::BufReader::new(string.as_bytes());
 
+    let version = if string.starts_with("version=") {
+        parse_u32(&mut reader, "version")?
+    } else {
+        0
+    };
+
+    if version > TILEMAP_VERSION {
+        return Err(anyhow!(
+            "Unsupported tilemap version {}, because the newest supported version is {}",
+            version,
+            TILEMAP_VERSION
+        ));
+    }
+
     let width = parse_u32(&mut reader, "width")?;
     let height = parse_u32(&mut reader, "height")?;
     let size = Size::new(width, height);
@@ -36,73 +57,72 @@ pub fn load_from_string(string: String) -> Result<Tilemap2d> {
 }
 
 fn load_tiles(reader: &mut BufReader<&[u8]>, size: Size) -> Result<Vec<Tile>> {
-    let mut tiles = Vec::with_capacity(size.len());
-
-    for y in 0..size.height() {
-        let mut line = String::new();
-        reader
-            .read_line(&mut line)
-            .context(format!("Unable to read {}.row of tiles", y + 1))?;
-
-        let mut x = 0;
-
-        for split in line.split(';') {
-            let tile = parse_tile(split).context(format!(
-                "Unable to read the {}.tile of {}.row from '{}'",
-                x + 1,
-                y + 1,
-                split,
-            ))?;
-            tiles.push(tile);
-            x += 1;
-        }
-
-        if x > size.width() {
-            return Err(anyhow!(
-                "{}.row of tiles is too long with {} elements: '{}'",
-                y + 1,
-                x,
-                line
-            ));
-        }
-    }
-
-    Ok(tiles)
+    load_rows(reader, size, "tile", parse_tile)
 }
 
 fn load_borders(reader: &mut BufReader<&[u8]>, size: Size) -> Result<Vec<Border>> {
-    let mut borders = Vec::with_capacity(size.len());
+    load_rows(reader, size, "border", parse_border)
+}
+
+/// Loads a row per line with exactly `size.width()` elements separated by ';'.
+fn load_rows<T>(
+    reader: &mut BufReader<&[u8]>,
+    size: Size,
+    name: &str,
+    parse: fn(&str) -> Result<T>,
+) -> Result<Vec<T>> {
+    let mut elements = Vec::with_capacity(size.len());
 
     for y in 0..size.height() {
         let mut line = String::new();
-        reader
-            .read_line(&mut line)
-            .context(format!("Unable to read {}.row of borders", y + 1))?;
+        reader.read_line(&mut line).context(format!(
+            "Unable to read {}.row of {}s",
+            y + 1,
+            name
+        ))?;
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            return Err(anyhow!("{}.row of {}s is missing", y + 1, name));
+        }
 
         let mut x = 0;
 
         for split in line.split(';') {
-            let border = parse_border(split).context(format!(
-                "Unable to read the {}.border of {}.row from '{}'",
+            if x >= size.width() {
+                return Err(anyhow!(
+                    "{}.row of {}s is too long with more than {} elements: '{}'",
+                    y + 1,
+                    name,
+                    size.width(),
+                    line
+                ));
+            }
+
+            let element = parse(split).context(format!(
+                "Unable to read the {}.{} of {}.row from '{}'",
                 x + 1,
+                name,
                 y + 1,
                 split,
             ))?;
-            borders.push(border);
+            elements.push(element);
             x += 1;
         }
 
-        if x > size.width() {
+        if x < size.width() {
             return Err(anyhow!(
-                "{}.row of borders is too long with {} elements: '{}'",
+                "{}.row of {}s is too short with {} instead of {} elements: '{}'",
                 y + 1,
+                name,
                 x,
+                size.width(),
                 line
             ));
         }
     }
 
-    Ok(borders)
+    Ok(elements)
 }
 
 pub fn save_tilemap(tilemap: &Tilemap2d, path: &Path) -> Result<()> {
@@ -121,6 +141,7 @@ pub fn save_to_string(tilemap: &Tilemap2d) -> Result<String> {
     let mut string = String::new();
     let size = tilemap.get_size();
 
+    writeln!(&mut string, "version={}", TILEMAP_VERSION)?;
     writeln!(&mut string, "width={}", size.width())?;
     writeln!(&mut string, "height={}", size.height())?;
 
@@ -141,21 +162,19 @@ pub fn save_to_string(tilemap: &Tilemap2d) -> Result<String> {
 
 fn save_tiles(tilemap: &Tilemap2d, string: &mut String) -> Result<()> {
     let size = tilemap.get_size();
-    let tiles = tilemap.get_tiles();
     let capacity = (size.width() * 4) as usize;
-    let mut index = 0;
 
-    for _y in 0..size.height() {
+    for y in 0..size.height() {
         let mut line = String::with_capacity(capacity);
 
         for x in 0..size.width() {
-            line.push_str(&format_tile(&tiles[index]));
+            if let Some(index) = tilemap.tile_index(x, y) {
+                line.push_str(&format_tile(&tilemap.get_tile(index)));
+            }
 
             if x < size.width() - 1 {
                 line.push(';');
             }
-
-            index += 1;
         }
 
         writeln!(string, "{}", line)?;
@@ -208,6 +227,10 @@ fn parse_tile(string: &str) -> Result<Tile> {
     match parts.next() {
         Some("F") => Ok(Tile::Floor(parse_usize("Tile Id", &mut parts)?)),
         Some("S") => Ok(Tile::Solid(parse_usize("Tile Id", &mut parts)?)),
+        Some("R") => Ok(Tile::Ramp {
+            id: parse_usize("Tile Id", &mut parts)?,
+            direction: parse_side("Direction", &mut parts)?,
+        }),
         Some("E") => Ok(Tile::Empty),
         _ => Err(anyhow!("Unable to parse tile from '{}'", string)),
     }
@@ -219,10 +242,18 @@ fn parse_border(string: &str) -> Result<Border> {
     match parts.next() {
         Some("E") => Ok(Border::Empty),
         Some("Wa") => Ok(Border::Wall(parse_usize("Wall Id", &mut parts)?)),
-        Some("D") => Ok(Border::new_door(
+        Some("Ra") => Ok(Border::Railing(parse_usize("Wall Id", &mut parts)?)),
+        Some("D") => Ok(Border::new_wide_door(
             parse_usize("Wall Id", &mut parts)?,
             parse_usize("Door Id", &mut parts)?,
             parse_usize("Is Front", &mut parts)? == 1,
+            // Older files don't store the width of doors
+            match parts.next() {
+                Some(string) => string
+                    .parse()
+                    .context(format!("Unable to parse Width from '{}'", string))?,
+                None => 1,
+            },
         )),
         Some("Wi") => Ok(Border::new_window(
             parse_usize("Wall Id", &mut parts)?,
@@ -242,11 +273,23 @@ fn parse_usize(name: &str, parts: &mut Split<char>) -> Result<usize> {
     }
 }
 
+fn parse_side(name: &str, parts: &mut Split<char>) -> Result<Side> {
+    match parts.next() {
+        Some("Top") => Ok(Side::Top),
+        Some("Left") => Ok(Side::Left),
+        Some("Bottom") => Ok(Side::Bottom),
+        Some("Right") => Ok(Side::Right),
+        Some(string) => Err(anyhow!("Unable to parse {} from '{}'", name, string)),
+        None => Err(anyhow!("{} is missing", name)),
+    }
+}
+
 fn format_tile(tile: &Tile) -> String {
     match tile {
         Tile::Empty => "E  ".to_string(),
         Tile::Floor(id) => format!("F,{}", *id),
         Tile::Solid(id) => format!("S,{}", *id),
+        Tile::Ramp { id, direction } => format!("R,{},{}", *id, direction),
     }
 }
 
@@ -254,11 +297,16 @@ fn format_border(border: &Border) -> String {
     match border {
         Border::Empty => "E      ".to_string(),
         Border::Wall(id) => format!("Wa,{}   ", *id),
+        Border::Railing(id) => format!("Ra,{}   ", *id),
         Border::Door {
             wall_id,
             door_id,
             is_front,
-        } => format!("D,{},{},{}", *wall_id, *door_id, *is_front as usize),
+            width,
+        } => format!(
+            "D,{},{},{},{}",
+            *wall_id, *door_id, *is_front as usize, *width
+        ),
         Border::Window { wall_id, window_id } => format!("Wi,{},{} ", *wall_id, *window_id),
     }
 }
@@ -276,14 +324,97 @@ mod tests {
         tilemap.set_tile(0, Tile::Solid(1));
         tilemap.set_tile(2, Tile::Floor(2));
         tilemap.set_tile(4, Tile::Floor(3));
+        tilemap.set_tile(
+            5,
+            Tile::Ramp {
+                id: 4,
+                direction: Left,
+            },
+        );
         tilemap.set_border(2, Bottom, Border::Wall(1));
         tilemap.set_border(2, Left, Border::new_window(2, 1));
         tilemap.set_border(2, Right, Border::new_door(3, 2, false));
-        tilemap.set_border(2, Top, Border::new_door(4, 5, true));
+        tilemap.set_border(2, Top, Border::new_wide_door(4, 5, true, 2));
+        tilemap.set_border(4, Bottom, Border::Railing(6));
 
         let string = save_to_string(&tilemap).unwrap();
         let new_tilemap = load_from_string(string).unwrap();
 
         assert_eq!(tilemap, new_tilemap);
     }
+
+    #[test]
+    fn test_load_door_without_width() {
+        assert_eq!(
+            parse_border("D,1,2,1").unwrap(),
+            Border::new_door(1, 2, true)
+        );
+    }
+
+    #[test]
+    fn test_save_writes_version() {
+        let tilemap = Tilemap2d::default(Size::new(1, 1), Tile::Empty);
+        let string = save_to_string(&tilemap).unwrap();
+
+        assert!(string.starts_with(&format!("version={}\n", TILEMAP_VERSION)));
+    }
+
+    #[test]
+    fn test_load_version_0() {
+        let string = "width=1\nheight=1\nF,3\nE\nE\nE;E\n".to_string();
+        let tilemap = load_from_string(string).unwrap();
+
+        assert_eq!(tilemap.get_size(), Size::new(1, 1));
+        assert_eq!(tilemap.get_tile(0), Tile::Floor(3));
+    }
+
+    #[test]
+    fn test_load_current_version() {
+        let string = "version=1\nwidth=1\nheight=1\nS,2\nE\nE\nE;E\n".to_string();
+        let tilemap = load_from_string(string).unwrap();
+
+        assert_eq!(tilemap.get_tile(0), Tile::Solid(2));
+    }
+
+    #[test]
+    fn test_load_too_new_version() {
+        let string = format!(
+            "version={}\nwidth=1\nheight=1\nS,2\nE\nE\nE;E\n",
+            TILEMAP_VERSION + 1
+        );
+
+        assert!(load_from_string(string).is_err());
+    }
+
+    #[test]
+    fn test_load_too_short_row() {
+        let string = "width=2\nheight=1\nF,1\nE;E\nE;E\nE;E;E\n".to_string();
+        let error = load_from_string(string).unwrap_err();
+
+        assert!(format!("{:?}", error).contains("1.row of tiles is too short"));
+    }
+
+    #[test]
+    fn test_load_too_long_row() {
+        let string = "width=2\nheight=1\nF,1;F,2\nE;E\nE;E\nE;E;E;E\n".to_string();
+        let error = load_from_string(string).unwrap_err();
+
+        assert!(format!("{:?}", error).contains("1.row of borders is too long"));
+    }
+
+    #[test]
+    fn test_load_missing_row() {
+        let string = "width=1\nheight=2\nF,1\n".to_string();
+        let error = load_from_string(string).unwrap_err();
+
+        assert!(format!("{:?}", error).contains("2.row of tiles is missing"));
+    }
+
+    #[test]
+    fn test_load_with_trailing_whitespace_and_lines() {
+        let string = "width=1\nheight=1\nF,3  \nE \nE\t\nE;E   \n\n\n".to_string();
+        let tilemap = load_from_string(string).unwrap();
+
+        assert_eq!(tilemap.get_tile(0), Tile::Floor(3));
+    }
 }
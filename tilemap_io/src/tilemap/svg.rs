@@ -0,0 +1,253 @@
+use anyhow::{Context, Result};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use texture_generation::math::color::Color;
+use tilemap::rendering::resource::Resources;
+use tilemap::tilemap::border::{get_horizontal_borders_size, get_vertical_borders_size, Border};
+use tilemap::tilemap::tile::Tile;
+use tilemap::tilemap::tilemap2d::Tilemap2d;
+
+pub const SVG_FILE_ENDING: &str = "svg";
+
+const BORDER_COLOR: &str = "black";
+
+/// A straight segment of borders in the coordinates of the svg.
+struct Segment {
+    x: i32,
+    y: i32,
+    length: i32,
+    is_horizontal: bool,
+}
+
+impl Segment {
+    /// Returns the start & end moved orthogonal to the segment.
+    fn get_points(&self, offset: i32) -> (i32, i32, i32, i32) {
+        if self.is_horizontal {
+            (
+                self.x,
+                self.y + offset,
+                self.x + self.length,
+                self.y + offset,
+            )
+        } else {
+            (
+                self.x + offset,
+                self.y,
+                self.x + offset,
+                self.y + self.length,
+            )
+        }
+    }
+}
+
+/// Exports the [`Tilemap2d`] as a floor plan, which uses the resources converted for `tile_size`.
+pub fn export_svg(
+    tilemap: &Tilemap2d,
+    resources: &Resources,
+    tile_size: u32,
+    path: &Path,
+) -> Result<()> {
+    info!("Export tilemap as svg to {:?}", path);
+
+    let svg = create_svg(tilemap, resources, tile_size)?;
+    fs::write(path, svg).context(format!("Unable to write {:?}", path))
+}
+
+/// Creates a floor plan with a filled rectangle per tile & lines for the borders.
+/// Doors are gaps with the arc of the door leaf & windows are double lines.
+pub fn create_svg(tilemap: &Tilemap2d, resources: &Resources, tile_size: u32) -> Result<String> {
+    let size = tilemap.get_size();
+    let width = size.width() * tile_size;
+    let height = size.height() * tile_size;
+    let tile_size = tile_size as i32;
+    let mut svg = String::new();
+
+    writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">",
+        width, height, width, height
+    )?;
+
+    for (index, tile) in tilemap.get_tiles().iter().enumerate() {
+        let id = match tile {
+            Tile::Empty => continue,
+            Tile::Floor(id) | Tile::Solid(id) | Tile::Ramp { id, .. } => *id,
+        };
+        let x = (index as u32 % size.width()) as i32 * tile_size;
+        let y = (index as u32 / size.width()) as i32 * tile_size;
+        let color = resources.textures.get(id).get_background();
+
+        writeln!(
+            svg,
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+            x,
+            y,
+            tile_size,
+            tile_size,
+            to_hex(color)
+        )?;
+    }
+
+    let horizontal_size = get_horizontal_borders_size(size);
+    let mut is_spanned = vec![false; horizontal_size.len()];
+
+    for (index, border) in tilemap.get_horizontal_borders().iter().enumerate() {
+        if is_spanned[index] {
+            continue;
+        }
+
+        let x = index as u32 % horizontal_size.width();
+        let y = index as u32 / horizontal_size.width();
+        let segments = get_segments(border, horizontal_size.width() - x);
+
+        for i in 1..segments {
+            is_spanned[index + i as usize] = true;
+        }
+
+        let segment = Segment {
+            x: x as i32 * tile_size,
+            y: y as i32 * tile_size,
+            length: segments as i32 * tile_size,
+            is_horizontal: true,
+        };
+        write_border(&mut svg, resources, border, &segment)?;
+    }
+
+    let vertical_size = get_vertical_borders_size(size);
+    let mut is_spanned = vec![false; vertical_size.len()];
+
+    for (index, border) in tilemap.get_vertical_borders().iter().enumerate() {
+        if is_spanned[index] {
+            continue;
+        }
+
+        let x = index as u32 % vertical_size.width();
+        let y = index as u32 / vertical_size.width();
+        let segments = get_segments(border, vertical_size.height() - y);
+
+        for i in 1..segments {
+            is_spanned[index + (i * vertical_size.width()) as usize] = true;
+        }
+
+        let segment = Segment {
+            x: x as i32 * tile_size,
+            y: y as i32 * tile_size,
+            length: segments as i32 * tile_size,
+            is_horizontal: false,
+        };
+        write_border(&mut svg, resources, border, &segment)?;
+    }
+
+    writeln!(svg, "</svg>")?;
+
+    Ok(svg)
+}
+
+/// Returns the number of segments covered by the border, which is only more than 1 for wide doors.
+fn get_segments(border: &Border, remaining: u32) -> u32 {
+    match border {
+        Border::Door { width, .. } => (*width).min(remaining).max(1),
+        _ => 1,
+    }
+}
+
+fn write_border(
+    svg: &mut String,
+    resources: &Resources,
+    border: &Border,
+    segment: &Segment,
+) -> Result<()> {
+    match border {
+        Border::Empty => Ok(()),
+        Border::Wall(id) => {
+            let thickness = get_wall_thickness(resources, *id);
+            write_line(svg, segment, 0, thickness)
+        }
+        Border::Railing(id) => {
+            let thickness = (get_wall_thickness(resources, *id) / 2).max(1);
+            write_line(svg, segment, 0, thickness)
+        }
+        Border::Window { wall_id, .. } => {
+            let half = (get_wall_thickness(resources, *wall_id) / 2).max(1) as i32;
+            write_line(svg, segment, -half, 1)?;
+            write_line(svg, segment, half, 1)
+        }
+        Border::Door { is_front, .. } => write_door_arc(svg, segment, *is_front),
+    }
+}
+
+fn get_wall_thickness(resources: &Resources, id: usize) -> u32 {
+    resources
+        .wall_styles
+        .get(id)
+        .get_edge_style()
+        .get_thickness()
+}
+
+fn write_line(svg: &mut String, segment: &Segment, offset: i32, thickness: u32) -> Result<()> {
+    let (x1, y1, x2, y2) = segment.get_points(offset);
+
+    writeln!(
+        svg,
+        "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\" stroke-width=\"{}\"/>",
+        x1, y1, x2, y2, BORDER_COLOR, thickness
+    )?;
+
+    Ok(())
+}
+
+/// Draws the door leaf opened by 90 degrees & the arc of its swing.
+/// The hinge is at the start of the segment & the door opens to the front.
+fn write_door_arc(svg: &mut String, segment: &Segment, is_front: bool) -> Result<()> {
+    let radius = segment.length;
+    let offset = if is_front { radius } else { -radius };
+    let (x1, y1, x2, y2) = segment.get_points(0);
+    let (x3, y3) = if segment.is_horizontal {
+        (x1, y1 + offset)
+    } else {
+        (x1 + offset, y1)
+    };
+    let sweep = (segment.is_horizontal == is_front) as u8;
+
+    writeln!(
+        svg,
+        "<path d=\"M {} {} A {} {} 0 0 {} {} {} L {} {}\" fill=\"none\" stroke=\"{}\"/>",
+        x2, y2, radius, radius, sweep, x3, y3, x1, y1, BORDER_COLOR
+    )?;
+
+    Ok(())
+}
+
+fn to_hex(color: &Color) -> String {
+    format!("#{:02X}{:02X}{:02X}", color.r(), color.g(), color.b())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use texture_generation::math::side::Side::*;
+    use texture_generation::math::size::Size;
+
+    #[test]
+    fn test_create_svg() {
+        let mut tilemap = Tilemap2d::default(Size::new(3, 2), Tile::Empty);
+        tilemap.set_tile(0, Tile::Floor(0));
+        tilemap.set_tile(1, Tile::Floor(0));
+        tilemap.set_tile(2, Tile::Solid(1));
+        tilemap.set_border(0, Top, Border::Wall(0));
+        tilemap.set_border(0, Left, Border::new_window(0, 0));
+        tilemap.set_border(1, Bottom, Border::new_wide_door(0, 0, true, 2));
+        tilemap.set_border(2, Bottom, Border::Wall(0));
+        tilemap.set_border(4, Right, Border::Railing(0));
+
+        let svg = create_svg(&tilemap, &Resources::empty(), 10).unwrap();
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<rect ").count(), 3);
+        assert_eq!(svg.matches("<line ").count(), 4);
+        assert_eq!(svg.matches("<path ").count(), 1);
+        assert!(svg.contains("<path d=\"M 30 10 A 20 20 0 0 1 10 30 L 10 10\""));
+    }
+}